@@ -1,5 +1,8 @@
 use crate::{A11yId, A11yNode, IdEq};
 
+use std::collections::HashMap;
+use std::fmt;
+
 #[derive(Debug, Clone, Default)]
 /// Accessible tree of nodes
 pub struct A11yTree {
@@ -67,6 +70,105 @@ impl A11yTree {
         self.root.iter().any(|n| IdEq::eq(n.id(), id))
             || self.children.iter().any(|n| IdEq::eq(n.id(), id))
     }
+
+    /// Returns an iterator over all the nodes of the tree
+    pub fn nodes(&self) -> impl Iterator<Item = &A11yNode> {
+        self.root.iter().chain(self.children.iter())
+    }
+
+    /// Finds the first node with the given role and name
+    pub fn find(&self, role: accesskit::Role, name: &str) -> Option<&A11yNode> {
+        self.nodes()
+            .find(|n| n.node().role() == role && n.node().name() == Some(name))
+    }
+}
+
+/// Writes the tree as indented text, one node per line
+impl fmt::Display for A11yTree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn write_node(
+            f: &mut fmt::Formatter<'_>,
+            nodes: &HashMap<accesskit::NodeId, &A11yNode>,
+            node: &A11yNode,
+            depth: usize,
+        ) -> fmt::Result {
+            use accesskit::Action;
+
+            const ACTIONS: [Action; 10] = [
+                Action::Default,
+                Action::Focus,
+                Action::Blur,
+                Action::Expand,
+                Action::Collapse,
+                Action::Increment,
+                Action::Decrement,
+                Action::SetValue,
+                Action::SetTextSelection,
+                Action::ScrollIntoView,
+            ];
+
+            let built = node.node().clone().build();
+
+            write!(f, "{:indent$}{:?}", "", built.role(), indent = depth * 2)?;
+
+            if let Some(name) = built.name() {
+                write!(f, " {name:?}")?;
+            }
+
+            if let Some(value) = built.value() {
+                write!(f, " value={value:?}")?;
+            }
+
+            if let Some(value) = built.numeric_value() {
+                write!(f, " value={value}")?;
+            }
+
+            if built.is_disabled() {
+                write!(f, " disabled")?;
+            }
+
+            if built.is_selected() == Some(true) {
+                write!(f, " selected")?;
+            }
+
+            match built.is_expanded() {
+                Some(true) => write!(f, " expanded")?,
+                Some(false) => write!(f, " collapsed")?,
+                None => {}
+            }
+
+            let actions: Vec<String> = ACTIONS
+                .into_iter()
+                .filter(|action| built.supports_action(*action))
+                .map(|action| format!("{action:?}"))
+                .collect();
+
+            if !actions.is_empty() {
+                write!(f, " ({})", actions.join(", "))?;
+            }
+
+            writeln!(f)?;
+
+            for child in built.children() {
+                if let Some(child) = nodes.get(child) {
+                    write_node(f, nodes, child, depth + 1)?;
+                }
+            }
+
+            Ok(())
+        }
+
+        let nodes: HashMap<accesskit::NodeId, &A11yNode> = self
+            .nodes()
+            .map(|node| (node.id().clone().into(), node))
+            .collect();
+
+        for node in &self.root {
+            write_node(f, &nodes, node, 0)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl From<A11yTree> for Vec<(accesskit::NodeId, accesskit::Node)> {
@@ -78,3 +180,33 @@ impl From<A11yTree> for Vec<(accesskit::NodeId, accesskit::Node)> {
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Id;
+
+    use accesskit::{Action, NodeBuilder, Role};
+
+    #[test]
+    fn display() {
+        let mut button = NodeBuilder::new(Role::Button);
+        button.set_name("Save");
+        button.add_action(Action::Default);
+
+        let mut label = NodeBuilder::new(Role::Label);
+        label.set_name("Save");
+
+        let tree = A11yTree::node_with_child_tree(
+            A11yNode::new(button, Id::unique()),
+            A11yTree::leaf(label, Id::unique()),
+        );
+
+        assert_eq!(
+            tree.to_string(),
+            "Button \"Save\" (Default)\n  Label \"Save\"\n"
+        );
+        assert!(tree.find(Role::Label, "Save").is_some());
+        assert!(tree.find(Role::Button, "Open").is_none());
+    }
+}
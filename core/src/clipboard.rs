@@ -75,6 +75,12 @@ pub trait Clipboard {
         None
     }
 
+    /// Returns the MIME types offered by the current content of the
+    /// [`Clipboard`], without reading it.
+    fn read_mime_types(&self, _kind: Kind) -> Vec<String> {
+        Vec::new()
+    }
+
     /// Writes the given contents to the [`Clipboard`].
     fn write_data(
         &mut self,
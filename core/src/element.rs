@@ -439,6 +439,14 @@ where
         self.widget
             .drag_destinations(state, layout, renderer, dnd_rectangles);
     }
+
+    fn inspect(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+    ) -> widget::inspect::Node {
+        self.widget.inspect(state, layout)
+    }
 }
 
 struct Explain<'a, Message, Theme, Renderer: crate::Renderer> {
@@ -611,5 +619,13 @@ where
             dnd_rectangles,
         );
     }
+
+    fn inspect(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+    ) -> widget::inspect::Node {
+        self.element.widget.inspect(state, layout)
+    }
     // TODO maybe a11y_nodes
 }
@@ -112,3 +112,64 @@ pub enum Style {
     Italic,
     Oblique,
 }
+
+/// A font family available in the system.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FamilyInfo {
+    /// The name of the family.
+    pub name: String,
+    /// The faces of the family.
+    pub faces: Vec<Face>,
+}
+
+/// A face of a [`FamilyInfo`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Face {
+    /// The [`Weight`] of the [`Face`].
+    pub weight: Weight,
+    /// The [`Stretch`] of the [`Face`].
+    pub stretch: Stretch,
+    /// The [`Style`] of the [`Face`].
+    pub style: Style,
+    /// Whether all the glyphs of the [`Face`] have the same width.
+    pub monospaced: bool,
+}
+
+/// The script of some text, used to pick fallback fonts.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Script {
+    /// Characters shared by many scripts; like digits, punctuation, and
+    /// emoji.
+    ///
+    /// The fallback fonts of this script are used for every other script,
+    /// after their own.
+    Common,
+    Arabic,
+    Armenian,
+    Bengali,
+    Cyrillic,
+    Devanagari,
+    Ethiopic,
+    Georgian,
+    Greek,
+    Gujarati,
+    Gurmukhi,
+    Han,
+    Hangul,
+    Hebrew,
+    Hiragana,
+    Kannada,
+    Katakana,
+    Khmer,
+    Lao,
+    Latin,
+    Malayalam,
+    Myanmar,
+    Sinhala,
+    Tamil,
+    Telugu,
+    Thai,
+    Tibetan,
+}
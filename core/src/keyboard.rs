@@ -1,5 +1,6 @@
 //! Listen to keyboard events.
 pub mod key;
+pub mod shortcut;
 
 mod event;
 mod location;
@@ -9,3 +10,4 @@ pub use event::Event;
 pub use key::Key;
 pub use location::Location;
 pub use modifiers::Modifiers;
+pub use shortcut::Shortcut;
@@ -33,6 +33,18 @@ impl Key {
     }
 }
 
+impl From<Named> for Key {
+    fn from(named: Named) -> Self {
+        Self::Named(named)
+    }
+}
+
+impl From<&str> for Key {
+    fn from(c: &str) -> Self {
+        Self::Character(SmolStr::new(c))
+    }
+}
+
 /// A named key.
 ///
 /// This is mostly the `NamedKey` type found in [`winit`].
@@ -0,0 +1,139 @@
+//! Describe keyboard shortcuts.
+use crate::keyboard::{key, Key, Modifiers};
+use crate::SmolStr;
+
+use std::fmt;
+
+/// A keyboard shortcut; a sequence of one or more [`Stroke`]s.
+///
+/// Multiple strokes form a chord, like <kbd>Ctrl</kbd>+<kbd>K</kbd>
+/// <kbd>Ctrl</kbd>+<kbd>S</kbd>.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Shortcut {
+    strokes: Vec<Stroke>,
+}
+
+impl Shortcut {
+    /// Creates a [`Shortcut`] with a single [`Stroke`].
+    pub fn new(modifiers: Modifiers, key: impl Into<Key>) -> Self {
+        Self {
+            strokes: vec![Stroke::new(modifiers, key)],
+        }
+    }
+
+    /// Adds a [`Stroke`] to the chord of the [`Shortcut`].
+    pub fn then(mut self, modifiers: Modifiers, key: impl Into<Key>) -> Self {
+        self.strokes.push(Stroke::new(modifiers, key));
+        self
+    }
+
+    /// Returns the [`Stroke`]s of the [`Shortcut`].
+    pub fn strokes(&self) -> &[Stroke] {
+        &self.strokes
+    }
+
+    /// Returns true if the [`Stroke`]s of the [`Shortcut`] start with the
+    /// given ones.
+    pub fn starts_with(&self, strokes: &[Stroke]) -> bool {
+        self.strokes.starts_with(strokes)
+    }
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, stroke) in self.strokes.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+
+            write!(f, "{stroke}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A key press with some [`Modifiers`], part of a [`Shortcut`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Stroke {
+    modifiers: Modifiers,
+    key: Key,
+}
+
+impl Stroke {
+    /// The [`Modifiers`] that are taken into account by a [`Stroke`].
+    const MODIFIERS: Modifiers = Modifiers::SHIFT
+        .union(Modifiers::CTRL)
+        .union(Modifiers::ALT)
+        .union(Modifiers::LOGO);
+
+    /// Creates a new [`Stroke`].
+    ///
+    /// Character keys are compared case-insensitively and Caps Lock is
+    /// ignored.
+    pub fn new(modifiers: Modifiers, key: impl Into<Key>) -> Self {
+        let key = match key.into() {
+            Key::Character(c) => Key::Character(SmolStr::new(c.to_lowercase())),
+            key => key,
+        };
+
+        Self {
+            modifiers: modifiers.intersection(Self::MODIFIERS),
+            key,
+        }
+    }
+
+    /// Returns the [`Modifiers`] of the [`Stroke`].
+    pub fn modifiers(&self) -> Modifiers {
+        self.modifiers
+    }
+
+    /// Returns the [`Key`] of the [`Stroke`].
+    pub fn key(&self) -> &Key {
+        &self.key
+    }
+
+    /// Returns true if the [`Key`] of the [`Stroke`] is a modifier key.
+    pub fn is_modifier(&self) -> bool {
+        matches!(
+            self.key,
+            Key::Named(
+                key::Named::Shift
+                    | key::Named::Control
+                    | key::Named::Alt
+                    | key::Named::AltGraph
+                    | key::Named::Super
+                    | key::Named::Meta
+                    | key::Named::Hyper
+                    | key::Named::CapsLock
+            )
+        )
+    }
+}
+
+impl fmt::Display for Stroke {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let logo = if cfg!(target_os = "macos") {
+            "Cmd"
+        } else {
+            "Super"
+        };
+
+        for (modifier, name) in [
+            (Modifiers::CTRL, "Ctrl"),
+            (Modifiers::ALT, "Alt"),
+            (Modifiers::SHIFT, "Shift"),
+            (Modifiers::LOGO, logo),
+        ] {
+            if self.modifiers.contains(modifier) {
+                write!(f, "{name}+")?;
+            }
+        }
+
+        match &self.key {
+            Key::Character(c) => write!(f, "{}", c.to_uppercase()),
+            Key::Named(named) => write!(f, "{named:?}"),
+            Key::Unidentified => write!(f, "?"),
+        }
+    }
+}
@@ -0,0 +1,158 @@
+//! Use custom images as mouse cursors.
+use crate::image::Bytes;
+use crate::Point;
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::atomic::{self, AtomicU64};
+use std::sync::{Arc, Mutex, Weak};
+
+/// The custom cursors that are alive, by id.
+static CURSORS: Mutex<BTreeMap<u64, Weak<Image>>> = Mutex::new(BTreeMap::new());
+
+/// A mouse cursor made of custom RGBA pixels.
+///
+/// A [`CustomCursor`] is shown by using its [`Id`] as a
+/// [`mouse::Interaction`]. The [`Id`] is only valid while the
+/// [`CustomCursor`] (or any of its clones) is alive, so keep it in the state
+/// of your application instead of creating it again every frame. Shells
+/// cache the native cursor of every [`Id`], and drop it once the
+/// [`CustomCursor`] is dropped.
+///
+/// [`mouse::Interaction`]: crate::mouse::Interaction
+#[derive(Clone)]
+pub struct CustomCursor {
+    image: Arc<Image>,
+}
+
+struct Image {
+    id: Id,
+    width: u16,
+    height: u16,
+    pixels: Bytes,
+    hotspot: Point<u16>,
+}
+
+impl CustomCursor {
+    /// Creates a new [`CustomCursor`] from its decoded RGBA pixels and the
+    /// hotspot of the cursor; that is, the exact pixel that points.
+    ///
+    /// The length of the pixel data should always be `width * height * 4`.
+    pub fn from_rgba(
+        width: u16,
+        height: u16,
+        pixels: impl Into<Bytes>,
+        hotspot: Point<u16>,
+    ) -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        let id = Id(NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed));
+
+        let image = Arc::new(Image {
+            id,
+            width,
+            height,
+            pixels: pixels.into(),
+            hotspot,
+        });
+
+        let _ = CURSORS
+            .lock()
+            .expect("Lock custom cursors")
+            .insert(id.0, Arc::downgrade(&image));
+
+        Self { image }
+    }
+
+    /// Returns the unique [`Id`] of the [`CustomCursor`].
+    pub fn id(&self) -> Id {
+        self.image.id
+    }
+
+    /// Returns the width of the [`CustomCursor`].
+    pub fn width(&self) -> u16 {
+        self.image.width
+    }
+
+    /// Returns the height of the [`CustomCursor`].
+    pub fn height(&self) -> u16 {
+        self.image.height
+    }
+
+    /// Returns the RGBA pixels of the [`CustomCursor`].
+    pub fn pixels(&self) -> &Bytes {
+        &self.image.pixels
+    }
+
+    /// Returns the hotspot of the [`CustomCursor`].
+    pub fn hotspot(&self) -> Point<u16> {
+        self.image.hotspot
+    }
+}
+
+impl fmt::Debug for CustomCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CustomCursor")
+            .field("id", &self.image.id)
+            .field("width", &self.image.width)
+            .field("height", &self.image.height)
+            .field("hotspot", &self.image.hotspot)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for CustomCursor {
+    fn eq(&self, other: &Self) -> bool {
+        self.image.id == other.image.id
+    }
+}
+
+impl Eq for CustomCursor {}
+
+impl Drop for Image {
+    fn drop(&mut self) {
+        if let Ok(mut cursors) = CURSORS.lock() {
+            let _ = cursors.remove(&self.id.0);
+        }
+    }
+}
+
+/// The unique identifier of a [`CustomCursor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Id(u64);
+
+impl Id {
+    /// Returns the [`CustomCursor`] with this [`Id`], if it is still alive.
+    pub fn cursor(self) -> Option<CustomCursor> {
+        let image = CURSORS
+            .lock()
+            .expect("Lock custom cursors")
+            .get(&self.0)
+            .and_then(Weak::upgrade)?;
+
+        Some(CustomCursor { image })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn id_is_valid_while_cursor_is_alive() {
+        let cursor =
+            CustomCursor::from_rgba(1, 1, vec![0, 0, 0, 255], Point::new(0, 0));
+        let id = cursor.id();
+
+        assert_eq!(id.cursor(), Some(cursor.clone()));
+
+        let clone = cursor.clone();
+        drop(cursor);
+
+        assert_eq!(id.cursor(), Some(clone.clone()));
+
+        drop(clone);
+
+        assert_eq!(id.cursor(), None);
+    }
+}
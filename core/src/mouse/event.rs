@@ -1,4 +1,4 @@
-use crate::Point;
+use crate::{Point, Vector};
 
 use super::Button;
 
@@ -22,6 +22,17 @@ pub enum Event {
         position: Point,
     },
 
+    /// The mouse moved by some raw, unaccelerated amount.
+    ///
+    /// This is only produced while the cursor is grabbed by the window.
+    /// See [`window::CursorGrab`].
+    ///
+    /// [`window::CursorGrab`]: crate::window::CursorGrab
+    RawMotion {
+        /// The movement of the mouse, in physical units.
+        delta: Vector,
+    },
+
     /// A mouse button was pressed.
     ButtonPressed(Button),
 
@@ -1,3 +1,5 @@
+use crate::mouse::custom_cursor;
+
 /// The interaction of a mouse cursor.
 #[derive(Debug, Eq, PartialEq, Clone, Copy, PartialOrd, Ord, Default)]
 #[allow(missing_docs)]
@@ -23,4 +25,8 @@ pub enum Interaction {
     Copy,
     Help,
     Hide,
+    /// The image of the [`CustomCursor`] with the given id.
+    ///
+    /// [`CustomCursor`]: crate::mouse::CustomCursor
+    Custom(custom_cursor::Id),
 }
@@ -5,6 +5,7 @@ use std::fmt;
 
 /// A 2D point.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Point<T = f32> {
     /// The X coordinate.
     pub x: T,
@@ -1,7 +1,12 @@
 //! Write your own renderer.
+pub mod stats;
+
 #[cfg(debug_assertions)]
 mod null;
 
+pub use stats::Stats;
+
+use crate::border;
 use crate::{
     Background, Border, Color, Rectangle, Shadow, Size, Transformation, Vector,
 };
@@ -25,6 +30,39 @@ pub trait Renderer {
         self.end_layer();
     }
 
+    /// Starts recording a new layer with rounded corners.
+    ///
+    /// The new layer will clip its contents to the rounded rectangle
+    /// defined by the provided `bounds` and `border_radius`.
+    ///
+    /// By default, the `border_radius` is ignored and the layer is clipped
+    /// to its rectangular `bounds`.
+    fn start_rounded_layer(
+        &mut self,
+        bounds: Rectangle,
+        border_radius: border::Radius,
+    ) {
+        let _ = border_radius;
+
+        self.start_layer(bounds);
+    }
+
+    /// Draws the primitives recorded in the given closure in a new layer
+    /// with rounded corners.
+    ///
+    /// The layer will clip its contents to the rounded rectangle defined by
+    /// the provided `bounds` and `border_radius`.
+    fn with_rounded_layer(
+        &mut self,
+        bounds: Rectangle,
+        border_radius: border::Radius,
+        f: impl FnOnce(&mut Self),
+    ) {
+        self.start_rounded_layer(bounds, border_radius);
+        f(self);
+        self.end_layer();
+    }
+
     /// Starts recording with a new [`Transformation`].
     fn start_transformation(&mut self, transformation: Transformation);
 
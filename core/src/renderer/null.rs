@@ -117,6 +117,10 @@ impl text::Paragraph for () {
     fn span_bounds(&self, _index: usize) -> Vec<Rectangle> {
         vec![]
     }
+
+    fn range_bounds(&self, _range: std::ops::Range<usize>) -> Vec<Rectangle> {
+        vec![]
+    }
 }
 
 impl text::Editor for () {
@@ -136,6 +140,10 @@ impl text::Editor for () {
         (0, 0)
     }
 
+    fn hit_test(&self, _point: Point) -> Option<Point> {
+        None
+    }
+
     fn selection(&self) -> Option<String> {
         None
     }
@@ -0,0 +1,109 @@
+//! Inspect the work done by a renderer to draw a frame.
+use crate::Rectangle;
+
+/// Statistics about the last frame presented by a renderer.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Stats {
+    /// The statistics of every [`Layer`] of the frame, in drawing order.
+    pub layers: Vec<Layer>,
+
+    /// The occupancy of the image [`Atlas`], if the renderer uses one.
+    pub atlas: Option<Atlas>,
+
+    /// The amount of shaped text buffers cached by the renderer.
+    pub text_cache: usize,
+
+    /// The amount of rasterized glyphs cached by the renderer, if the
+    /// renderer keeps track of them.
+    pub glyph_cache: Option<usize>,
+}
+
+impl Stats {
+    /// Returns the total amount of quads drawn in the frame.
+    pub fn quads(&self) -> usize {
+        self.layers.iter().map(|layer| layer.quads).sum()
+    }
+
+    /// Returns the total amount of meshes drawn in the frame.
+    pub fn meshes(&self) -> usize {
+        self.layers.iter().map(|layer| layer.meshes).sum()
+    }
+
+    /// Returns the total amount of paths filled or stroked in the frame.
+    pub fn paths(&self) -> usize {
+        self.layers.iter().map(|layer| layer.paths).sum()
+    }
+
+    /// Returns the total amount of text runs drawn in the frame.
+    pub fn text(&self) -> usize {
+        self.layers.iter().map(|layer| layer.text).sum()
+    }
+
+    /// Returns the total amount of images drawn in the frame.
+    pub fn images(&self) -> usize {
+        self.layers.iter().map(|layer| layer.images).sum()
+    }
+}
+
+/// Statistics about a layer of a frame.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Layer {
+    /// The bounds of the layer.
+    pub bounds: Rectangle,
+
+    /// The amount of quads drawn in the layer.
+    pub quads: usize,
+
+    /// The amount of triangle meshes drawn in the layer.
+    ///
+    /// Only renderers that tessellate geometry, like `iced_wgpu`, draw
+    /// meshes.
+    pub meshes: usize,
+
+    /// The amount of paths filled or stroked in the layer.
+    ///
+    /// Only renderers that rasterize geometry directly, like `iced_tiny_skia`,
+    /// draw paths; every fill and every stroke counts as one.
+    pub paths: usize,
+
+    /// The amount of text runs drawn in the layer.
+    pub text: usize,
+
+    /// The amount of raster images and vector graphics drawn in the layer.
+    pub images: usize,
+
+    /// The amount of custom primitives drawn in the layer.
+    ///
+    /// Only renderers supporting custom primitives, like `iced_wgpu`, draw
+    /// them.
+    pub primitives: usize,
+}
+
+/// Statistics about the texture atlas where a renderer stores images.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Atlas {
+    /// The amount of layers of the atlas.
+    pub layers: usize,
+
+    /// The side length of every layer of the atlas, in pixels.
+    pub size: u32,
+
+    /// The amount of images allocated in the atlas.
+    pub allocations: usize,
+
+    /// The amount of pixels allocated in the atlas.
+    pub allocated: u64,
+}
+
+impl Atlas {
+    /// Returns the fraction of the atlas that is allocated, between 0 and 1.
+    pub fn occupancy(&self) -> f32 {
+        let capacity = self.layers as u64 * u64::from(self.size).pow(2);
+
+        if capacity == 0 {
+            return 0.0;
+        }
+
+        (self.allocated as f64 / capacity as f64) as f32
+    }
+}
@@ -12,6 +12,7 @@ pub struct Shell<'a, Message> {
     redraw_request: Option<window::RedrawRequest>,
     is_layout_invalid: bool,
     are_widgets_invalid: bool,
+    is_auto_scroll_requested: bool,
 }
 
 impl<'a, Message> Shell<'a, Message> {
@@ -22,6 +23,7 @@ impl<'a, Message> Shell<'a, Message> {
             redraw_request: None,
             is_layout_invalid: false,
             are_widgets_invalid: false,
+            is_auto_scroll_requested: false,
         }
     }
 
@@ -88,6 +90,20 @@ impl<'a, Message> Shell<'a, Message> {
         self.are_widgets_invalid = true;
     }
 
+    /// Requests the closest scrollable ancestor to scroll towards the cursor
+    /// when it is near its edges, because something is being dragged.
+    ///
+    /// The request only lasts for the current event.
+    pub fn request_auto_scroll(&mut self) {
+        self.is_auto_scroll_requested = true;
+    }
+
+    /// Takes the auto scroll request of the [`Shell`], if any; clearing it in
+    /// the process.
+    pub fn take_auto_scroll_request(&mut self) -> bool {
+        std::mem::take(&mut self.is_auto_scroll_requested)
+    }
+
     /// Merges the current [`Shell`] with another one by applying the given
     /// function to the messages of the latter.
     ///
@@ -104,5 +120,8 @@ impl<'a, Message> Shell<'a, Message> {
 
         self.are_widgets_invalid =
             self.are_widgets_invalid || other.are_widgets_invalid;
+
+        self.is_auto_scroll_requested =
+            self.is_auto_scroll_requested || other.is_auto_scroll_requested;
     }
 }
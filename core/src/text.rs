@@ -44,6 +44,9 @@ pub struct Text<Content = String, Font = crate::Font> {
 
     /// The [`Wrapping`] strategy of the [`Text`].
     pub wrapping: Wrapping,
+
+    /// The [`Ellipsize`] strategy of the [`Text`].
+    pub ellipsize: Ellipsize,
 }
 
 /// The shaping strategy of some text.
@@ -86,6 +89,40 @@ pub enum Wrapping {
     WordOrGlyph,
 }
 
+/// The ellipsizing strategy of some text.
+///
+/// Ellipsizing truncates the text that overflows its bounds with an
+/// ellipsis (…).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Ellipsize {
+    /// No ellipsizing.
+    ///
+    /// This is the default.
+    #[default]
+    None,
+    /// Ellipsizes the start of the last visible line.
+    Start(EllipsizeHeightLimit),
+    /// Ellipsizes the middle of the last visible line.
+    Middle(EllipsizeHeightLimit),
+    /// Ellipsizes the end of the last visible line.
+    End(EllipsizeHeightLimit),
+}
+
+/// The amount of lines visible before ellipsizing some text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum EllipsizeHeightLimit {
+    /// Shows, at most, the given amount of lines.
+    ///
+    /// The text is ellipsized on its first line if its [`Wrapping`]
+    /// strategy is [`Wrapping::None`].
+    Lines(usize),
+    /// Shows as many lines as fit in the bounds of the text.
+    ///
+    /// This is the default.
+    #[default]
+    Bounds,
+}
+
 /// The height of a line of text in a paragraph.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum LineHeight {
@@ -270,10 +307,45 @@ pub struct Span<'a, Link = (), Font = crate::Font> {
     ///
     /// Currently, it only affects the bounds of the [`Highlight`].
     pub padding: Padding,
-    /// Whether the [`Span`] should be underlined or not.
-    pub underline: bool,
-    /// Whether the [`Span`] should be struck through or not.
-    pub strikethrough: bool,
+    /// The underline [`Decoration`] of the [`Span`], if any.
+    pub underline: Option<Decoration>,
+    /// The strikethrough [`Decoration`] of the [`Span`], if any.
+    pub strikethrough: Option<Decoration>,
+    /// The overline [`Decoration`] of the [`Span`], if any.
+    pub overline: Option<Decoration>,
+}
+
+/// A line drawn along a [`Span`] of text; like an underline.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Decoration {
+    /// The [`Color`] of the line.
+    ///
+    /// If `None`, the [`Color`] of the text will be used.
+    pub color: Option<Color>,
+    /// The thickness of the line.
+    ///
+    /// If `None`, the thickness recommended by the font will be used.
+    pub thickness: Option<Pixels>,
+}
+
+impl Decoration {
+    /// Sets the [`Color`] of the [`Decoration`].
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the thickness of the [`Decoration`].
+    pub fn thickness(mut self, thickness: impl Into<Pixels>) -> Self {
+        self.thickness = Some(thickness.into());
+        self
+    }
+}
+
+impl From<Color> for Decoration {
+    fn from(color: Color) -> Self {
+        Self::default().color(color)
+    }
 }
 
 /// A text highlight.
@@ -297,8 +369,9 @@ impl<'a, Link, Font> Span<'a, Link, Font> {
             highlight: None,
             link: None,
             padding: Padding::ZERO,
-            underline: false,
-            strikethrough: false,
+            underline: None,
+            strikethrough: None,
+            overline: None,
         }
     }
 
@@ -419,16 +492,50 @@ impl<'a, Link, Font> Span<'a, Link, Font> {
 
     /// Sets whether the [`Span`] should be underlined or not.
     pub fn underline(mut self, underline: bool) -> Self {
-        self.underline = underline;
+        self.underline = underline.then(Decoration::default);
+        self
+    }
+
+    /// Underlines the [`Span`] with the given [`Decoration`].
+    pub fn underline_with(mut self, decoration: impl Into<Decoration>) -> Self {
+        self.underline = Some(decoration.into());
         self
     }
 
     /// Sets whether the [`Span`] should be struck through or not.
     pub fn strikethrough(mut self, strikethrough: bool) -> Self {
-        self.strikethrough = strikethrough;
+        self.strikethrough = strikethrough.then(Decoration::default);
         self
     }
 
+    /// Strikes through the [`Span`] with the given [`Decoration`].
+    pub fn strikethrough_with(
+        mut self,
+        decoration: impl Into<Decoration>,
+    ) -> Self {
+        self.strikethrough = Some(decoration.into());
+        self
+    }
+
+    /// Sets whether the [`Span`] should be overlined or not.
+    pub fn overline(mut self, overline: bool) -> Self {
+        self.overline = overline.then(Decoration::default);
+        self
+    }
+
+    /// Overlines the [`Span`] with the given [`Decoration`].
+    pub fn overline_with(mut self, decoration: impl Into<Decoration>) -> Self {
+        self.overline = Some(decoration.into());
+        self
+    }
+
+    /// Returns true if the [`Span`] has any [`Decoration`].
+    pub fn is_decorated(&self) -> bool {
+        self.underline.is_some()
+            || self.strikethrough.is_some()
+            || self.overline.is_some()
+    }
+
     /// Turns the [`Span`] into a static one.
     pub fn to_static(self) -> Span<'static, Link, Font> {
         Span {
@@ -442,6 +549,7 @@ impl<'a, Link, Font> Span<'a, Link, Font> {
             padding: self.padding,
             underline: self.underline,
             strikethrough: self.strikethrough,
+            overline: self.overline,
         }
     }
 }
@@ -459,6 +567,9 @@ impl<'a, Link, Font: PartialEq> PartialEq for Span<'a, Link, Font> {
             && self.line_height == other.line_height
             && self.font == other.font
             && self.color == other.color
+            && self.underline == other.underline
+            && self.strikethrough == other.strikethrough
+            && self.overline == other.overline
     }
 }
 
@@ -24,6 +24,26 @@ pub trait Editor: Sized + Default {
     /// Line and column, respectively.
     fn cursor_position(&self) -> (usize, usize);
 
+    /// Returns the position where the current selection of the [`Editor`]
+    /// started, if any.
+    ///
+    /// Line and column, respectively. The other end of the selection is the
+    /// cursor position.
+    ///
+    /// By default, there is never a selection anchor.
+    fn selection_anchor(&self) -> Option<(usize, usize)> {
+        None
+    }
+
+    /// Returns the position of the caret closest to the given point, if any.
+    ///
+    /// Both positions are relative to the top-left corner of the [`Editor`].
+    ///
+    /// By default, no position is ever hit.
+    fn hit_test(&self, _point: Point) -> Option<Point> {
+        None
+    }
+
     /// Returns the current selected text of the [`Editor`].
     fn selection(&self) -> Option<String>;
 
@@ -76,6 +96,16 @@ pub enum Action {
     SelectLine,
     /// Select the entire buffer.
     SelectAll,
+    /// Select the text between two positions, given as line and column.
+    ///
+    /// The cursor is placed at the `focus`. If both positions are the same,
+    /// the selection is cleared.
+    SelectRange {
+        /// The position where the selection starts.
+        anchor: (usize, usize),
+        /// The position where the selection ends.
+        focus: (usize, usize),
+    },
     /// Perform an [`Edit`].
     Edit(Edit),
     /// Click the [`Editor`] at the given [`Point`].
@@ -3,6 +3,8 @@ use crate::alignment;
 use crate::text::{Difference, Hit, Span, Text};
 use crate::{Point, Rectangle, Size};
 
+use std::ops::Range;
+
 /// A text paragraph.
 pub trait Paragraph: Sized + Default {
     /// The font of this [`Paragraph`].
@@ -35,6 +37,9 @@ pub trait Paragraph: Sized + Default {
 
     /// Tests whether the provided point is within the boundaries of the
     /// [`Paragraph`], returning information about the nearest character.
+    ///
+    /// The offset of the [`Hit`] is a byte offset into the whole contents of
+    /// the [`Paragraph`].
     fn hit_test(&self, point: Point) -> Option<Hit>;
 
     /// Tests whether the provided point is within the boundaries of a
@@ -46,6 +51,11 @@ pub trait Paragraph: Sized + Default {
     /// A [`Span`] can have multiple bounds for each line it's on.
     fn span_bounds(&self, index: usize) -> Vec<Rectangle>;
 
+    /// Returns all bounds for the provided range of bytes of the contents of
+    /// the [`Paragraph`]. A range can have multiple bounds for each line
+    /// it's on.
+    fn range_bounds(&self, range: Range<usize>) -> Vec<Rectangle>;
+
     /// Returns the distance to the given grapheme index in the [`Paragraph`].
     fn grapheme_position(&self, line: usize, index: usize) -> Option<Point>;
 
@@ -96,6 +106,7 @@ impl<P: Paragraph> Plain<P> {
             vertical_alignment: text.vertical_alignment,
             shaping: text.shaping,
             wrapping: text.wrapping,
+            ellipsize: text.ellipsize,
         }) {
             Difference::None => {}
             Difference::Bounds => {
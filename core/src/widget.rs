@@ -1,4 +1,5 @@
 //! Create custom widgets and operate on them.
+pub mod inspect;
 pub mod operation;
 pub mod text;
 pub mod tree;
@@ -179,4 +180,12 @@ where
         _dnd_rectangles: &mut crate::clipboard::DndDestinationRectangles,
     ) {
     }
+
+    /// Inspects the widget and its children for debugging purposes.
+    ///
+    /// By default, the widget is inspected as a leaf. Widgets with children
+    /// should override this to inspect them too.
+    fn inspect(&self, _state: &Tree, layout: Layout<'_>) -> inspect::Node {
+        inspect::Node::new::<Self>(self.id(), self.size(), layout)
+    }
 }
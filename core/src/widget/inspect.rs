@@ -0,0 +1,113 @@
+//! Inspect the widgets of a user interface for debugging purposes.
+use crate::widget::Id;
+use crate::{Layout, Length, Padding, Point, Rectangle, Size, Vector};
+
+/// A widget of a user interface, as seen by an inspector.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node {
+    /// The name of the widget type.
+    pub name: &'static str,
+    /// The [`Id`] of the widget, if any.
+    pub id: Option<Id>,
+    /// The [`Size`] requested by the widget.
+    pub size: Size<Length>,
+    /// The bounds of the widget, as laid out.
+    pub bounds: Rectangle,
+    /// The [`Padding`] declared by the widget, if it has any.
+    pub padding: Padding,
+    /// The inspected children of the widget.
+    pub children: Vec<Node>,
+}
+
+impl Node {
+    /// Creates a new leaf [`Node`] for a widget of type `W` with the given
+    /// [`Layout`].
+    pub fn new<W: ?Sized>(
+        id: Option<Id>,
+        size: Size<Length>,
+        layout: Layout<'_>,
+    ) -> Self {
+        Self {
+            name: short_name(std::any::type_name::<W>()),
+            id,
+            size,
+            bounds: layout.bounds(),
+            padding: Padding::ZERO,
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the [`Padding`] declared by the widget of the [`Node`].
+    pub fn with_padding(mut self, padding: Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the inspected children of the [`Node`].
+    pub fn with_children(
+        mut self,
+        children: impl IntoIterator<Item = Node>,
+    ) -> Self {
+        self.children = children.into_iter().collect();
+        self
+    }
+
+    /// Translates the bounds of the [`Node`] and all of its descendants.
+    ///
+    /// This is useful for widgets that draw their children translated,
+    /// like a scrollable.
+    pub fn translate(mut self, translation: Vector) -> Self {
+        self.bounds = self.bounds + translation;
+        self.children = self
+            .children
+            .into_iter()
+            .map(|child| child.translate(translation))
+            .collect();
+        self
+    }
+
+    /// Returns the path of nodes leading to the deepest [`Node`] under the
+    /// given point, starting with this one.
+    pub fn path_to(&self, point: Point) -> Vec<&Node> {
+        let mut path = Vec::new();
+        let mut current = Some(self).filter(|node| node.bounds.contains(point));
+
+        while let Some(node) = current {
+            path.push(node);
+
+            // The last child is drawn on top
+            current = node
+                .children
+                .iter()
+                .rev()
+                .find(|child| child.bounds.contains(point));
+        }
+
+        path
+    }
+
+    /// Visits the [`Node`] and all of its descendants in depth-first order,
+    /// along with their depth.
+    pub fn visit(&self, f: &mut impl FnMut(&Node, usize)) {
+        fn visit_at(
+            node: &Node,
+            depth: usize,
+            f: &mut impl FnMut(&Node, usize),
+        ) {
+            f(node, depth);
+
+            for child in &node.children {
+                visit_at(child, depth + 1, f);
+            }
+        }
+
+        visit_at(self, 0, f);
+    }
+}
+
+/// Strips the module path and the generic parameters from a type name.
+fn short_name(name: &'static str) -> &'static str {
+    let name = name.split('<').next().unwrap_or(name);
+
+    name.rsplit("::").next().unwrap_or(name)
+}
@@ -21,6 +21,9 @@
 //! }
 //! ```
 use crate::alignment;
+use crate::clipboard;
+use crate::event::{self, Event};
+use crate::keyboard;
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
@@ -28,11 +31,15 @@ use crate::text::paragraph::{self, Paragraph};
 use crate::text::{self, Fragment};
 use crate::widget::tree::{self, Tree};
 use crate::{
-    Color, Element, Layout, Length, Pixels, Point, Rectangle, Size, Theme,
-    Widget,
+    Clipboard, Color, Element, Layout, Length, Pixels, Point, Rectangle, Shell,
+    Size, Theme, Widget,
 };
 
-pub use text::{LineHeight, Shaping, Wrapping};
+use std::ops::Range;
+
+pub use text::{
+    Ellipsize, EllipsizeHeightLimit, LineHeight, Shaping, Wrapping,
+};
 
 /// A bunch of text.
 ///
@@ -73,6 +80,8 @@ where
     font: Option<Renderer::Font>,
     shaping: Shaping,
     wrapping: Wrapping,
+    ellipsize: Ellipsize,
+    selectable: bool,
     class: Theme::Class<'a>,
 }
 
@@ -95,6 +104,8 @@ where
             vertical_alignment: alignment::Vertical::Top,
             shaping: Shaping::default(),
             wrapping: Wrapping::default(),
+            ellipsize: Ellipsize::default(),
+            selectable: false,
             class: Theme::default(),
         }
     }
@@ -167,6 +178,26 @@ where
         self
     }
 
+    /// Sets the [`Ellipsize`] strategy of the [`Text`].
+    ///
+    /// An ellipsized [`Text`] truncates the contents overflowing its bounds
+    /// with an ellipsis (…), clamping them to the given amount of lines.
+    pub fn ellipsize(mut self, ellipsize: Ellipsize) -> Self {
+        self.ellipsize = ellipsize;
+        self
+    }
+
+    /// Sets whether the contents of the [`Text`] can be selected and copied
+    /// by the user.
+    ///
+    /// A selectable [`Text`] supports dragging, double-clicking a word and
+    /// triple-clicking a line. The selection can be copied to the clipboard
+    /// with `Ctrl+C`, as long as the [`Text`] was the last one clicked.
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = selectable;
+        self
+    }
+
     /// Sets the style of the [`Text`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
@@ -206,7 +237,7 @@ where
 
 /// The internal state of a [`Text`] widget.
 #[derive(Debug, Default)]
-pub struct State<P: Paragraph>(pub paragraph::Plain<P>);
+pub struct State<P: Paragraph>(pub paragraph::Plain<P>, Selection);
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Text<'a, Theme, Renderer>
@@ -219,9 +250,7 @@ where
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State::<Renderer::Paragraph>(
-            paragraph::Plain::default(),
-        ))
+        tree::State::new(State::<Renderer::Paragraph>::default())
     }
 
     fn size(&self) -> Size<Length> {
@@ -251,6 +280,7 @@ where
             self.vertical_alignment,
             self.shaping,
             self.wrapping,
+            self.ellipsize,
         )
     }
 
@@ -267,9 +297,61 @@ where
         let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
         let style = theme.style(&self.class);
 
+        if self.selectable {
+            state.1.draw(
+                renderer,
+                state.0.raw(),
+                layout.bounds(),
+                style.color.unwrap_or(defaults.text_color),
+            );
+        }
+
         draw(renderer, defaults, layout, state.0.raw(), style, viewport);
     }
 
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        if !self.selectable {
+            return event::Status::Ignored;
+        }
+
+        let State(paragraph, selection) =
+            tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        selection.update(
+            &event,
+            layout.bounds(),
+            cursor,
+            paragraph.raw(),
+            &self.fragment,
+            clipboard,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.selectable && cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
     #[cfg(feature = "a11y")]
     fn a11y_nodes(
         &self,
@@ -330,6 +412,7 @@ pub fn layout<Renderer>(
     vertical_alignment: alignment::Vertical,
     shaping: Shaping,
     wrapping: Wrapping,
+    ellipsize: Ellipsize,
 ) -> layout::Node
 where
     Renderer: text::Renderer,
@@ -340,7 +423,7 @@ where
         let size = size.unwrap_or_else(|| renderer.default_size());
         let font = font.unwrap_or_else(|| renderer.default_font());
 
-        let State(ref mut paragraph) = state;
+        let State(ref mut paragraph, _) = state;
 
         paragraph.update(text::Text {
             content,
@@ -352,12 +435,277 @@ where
             vertical_alignment,
             shaping,
             wrapping,
+            ellipsize,
         });
 
         paragraph.min_bounds()
     })
 }
 
+/// The selection of some selectable text.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Selection {
+    anchor: usize,
+    focus: usize,
+    is_dragging: bool,
+    is_focused: bool,
+    last_click: Option<mouse::Click>,
+}
+
+impl Selection {
+    /// Returns the selected range of bytes of the text, if any.
+    pub fn range(&self) -> Option<Range<usize>> {
+        (self.anchor != self.focus)
+            .then(|| self.anchor.min(self.focus)..self.anchor.max(self.focus))
+    }
+
+    /// Processes an [`Event`] and updates the [`Selection`] of the given
+    /// [`Paragraph`] and its contents, laid out in the given bounds.
+    ///
+    /// The selection is copied to the [`Clipboard`] when `Ctrl+C` is
+    /// pressed, as long as the last click was inside the given bounds.
+    pub fn update<P: Paragraph>(
+        &mut self,
+        event: &Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+        paragraph: &P,
+        content: &str,
+        clipboard: &mut dyn Clipboard,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    self.anchor = 0;
+                    self.focus = 0;
+                    self.is_dragging = false;
+                    self.is_focused = false;
+
+                    return event::Status::Ignored;
+                };
+
+                let Some(offset) = hit(paragraph, bounds, position, content)
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let click = mouse::Click::new(
+                    position,
+                    mouse::Button::Left,
+                    self.last_click,
+                );
+
+                let range = match click.kind() {
+                    mouse::click::Kind::Single => offset..offset,
+                    mouse::click::Kind::Double => word(content, offset),
+                    mouse::click::Kind::Triple => {
+                        visual_line(paragraph, bounds, position, content)
+                            .unwrap_or_else(|| line(content, offset))
+                    }
+                };
+
+                self.anchor = range.start;
+                self.focus = range.end;
+                self.is_dragging =
+                    matches!(click.kind(), mouse::click::Kind::Single);
+                self.is_focused = true;
+                self.last_click = Some(click);
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+                if self.is_dragging =>
+            {
+                if let Some(offset) = cursor.position().and_then(|position| {
+                    hit(paragraph, bounds, position, content)
+                }) {
+                    self.focus = offset;
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if self.is_dragging =>
+            {
+                self.is_dragging = false;
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Character(c),
+                modifiers,
+                ..
+            }) if c.as_str() == "c"
+                && modifiers.command()
+                && self.is_focused =>
+            {
+                let Some(range) = self.range() else {
+                    return event::Status::Ignored;
+                };
+
+                let Some(selection) = content.get(range) else {
+                    return event::Status::Ignored;
+                };
+
+                clipboard
+                    .write(clipboard::Kind::Standard, selection.to_owned());
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    /// Draws the [`Selection`] of the given [`Paragraph`], laid out in the
+    /// given bounds, with a translucent version of the given text [`Color`].
+    pub fn draw<Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        paragraph: &Renderer::Paragraph,
+        bounds: Rectangle,
+        color: Color,
+    ) where
+        Renderer: text::Renderer,
+    {
+        let Some(range) = self.range() else {
+            return;
+        };
+
+        let translation = origin(paragraph, bounds) - Point::ORIGIN;
+
+        for bounds in paragraph.range_bounds(range) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: bounds + translation,
+                    ..renderer::Quad::default()
+                },
+                Color {
+                    a: color.a * 0.3,
+                    ..color
+                },
+            );
+        }
+    }
+}
+
+/// Returns the position where the given [`Paragraph`] is drawn when laid out
+/// in the given bounds.
+fn origin<P: Paragraph>(paragraph: &P, bounds: Rectangle) -> Point {
+    let min_bounds = paragraph.min_bounds();
+
+    let x = match paragraph.horizontal_alignment() {
+        alignment::Horizontal::Left => bounds.x,
+        alignment::Horizontal::Center => {
+            bounds.center_x() - min_bounds.width / 2.0
+        }
+        alignment::Horizontal::Right => {
+            bounds.x + bounds.width - min_bounds.width
+        }
+    };
+
+    let y = match paragraph.vertical_alignment() {
+        alignment::Vertical::Top => bounds.y,
+        alignment::Vertical::Center => {
+            bounds.center_y() - min_bounds.height / 2.0
+        }
+        alignment::Vertical::Bottom => {
+            bounds.y + bounds.height - min_bounds.height
+        }
+    };
+
+    Point::new(x, y)
+}
+
+/// Returns the byte offset of the contents of the [`Paragraph`] closest to
+/// the given position.
+fn hit<P: Paragraph>(
+    paragraph: &P,
+    bounds: Rectangle,
+    position: Point,
+    content: &str,
+) -> Option<usize> {
+    let origin = origin(paragraph, bounds);
+
+    let hit = paragraph.hit_test(Point::ORIGIN + (position - origin))?;
+
+    Some(char_boundary(content, hit.cursor()))
+}
+
+/// Returns the closest character boundary of the content before the given
+/// byte offset.
+fn char_boundary(content: &str, offset: usize) -> usize {
+    let mut offset = offset.min(content.len());
+
+    while !content.is_char_boundary(offset) {
+        offset -= 1;
+    }
+
+    offset
+}
+
+/// Returns the range of the visual line of the [`Paragraph`] at the given
+/// position; that is, the line as wrapped.
+fn visual_line<P: Paragraph>(
+    paragraph: &P,
+    bounds: Rectangle,
+    position: Point,
+    content: &str,
+) -> Option<Range<usize>> {
+    let y = position.y - origin(paragraph, bounds).y;
+
+    let start = paragraph.hit_test(Point::new(0.0, y))?.cursor();
+    let end = paragraph
+        .hit_test(Point::new(paragraph.min_bounds().width, y))?
+        .cursor();
+
+    let start = char_boundary(content, start);
+    let end = char_boundary(content, end);
+
+    // A visual line never spans multiple lines of the content
+    let line = line(content, start);
+
+    Some(start.max(line.start)..end.min(line.end).max(start))
+}
+
+/// Returns the range of the word at the given byte offset of the content.
+///
+/// If the offset is not part of a word, the range of the character at the
+/// offset is returned instead.
+fn word(content: &str, offset: usize) -> Range<usize> {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+
+    let start = content[..offset]
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| is_word(*c))
+        .last()
+        .map_or(offset, |(i, _)| i);
+
+    let end = content[offset..]
+        .char_indices()
+        .take_while(|(_, c)| is_word(*c))
+        .last()
+        .map_or(offset, |(i, c)| offset + i + c.len_utf8());
+
+    if start == end {
+        offset
+            ..offset
+                + content[offset..].chars().next().map_or(0, char::len_utf8)
+    } else {
+        start..end
+    }
+}
+
+/// Returns the range of the line at the given byte offset of the content.
+fn line(content: &str, offset: usize) -> Range<usize> {
+    let start = content[..offset].rfind('\n').map_or(0, |i| i + 1);
+    let end = content[offset..]
+        .find('\n')
+        .map_or(content.len(), |i| offset + i);
+
+    start..end
+}
+
 /// Draws text using the same logic as the [`Text`] widget.
 ///
 /// Specifically:
@@ -534,3 +882,84 @@ pub fn danger(theme: &Theme) -> Style {
         color: Some(theme.palette().danger),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn word_selection() {
+        let content = "Hello, wörld_2!";
+
+        assert_eq!(word(content, 2), 0..5);
+        assert_eq!(word(content, 5), 0..5);
+        assert_eq!(word(content, 6), 6..7);
+        assert_eq!(word(content, 8), 7..15);
+        assert_eq!(word(content, content.len()), content.len()..content.len());
+    }
+
+    #[test]
+    fn line_selection() {
+        let content = "first\nsecond\nthird";
+
+        assert_eq!(line(content, 2), 0..5);
+        assert_eq!(line(content, 6), 6..12);
+        assert_eq!(line(content, content.len()), 13..18);
+    }
+
+    #[derive(Default)]
+    struct Recorder(Vec<String>);
+
+    impl Clipboard for Recorder {
+        fn read(&self, _kind: clipboard::Kind) -> Option<String> {
+            None
+        }
+
+        fn write(&mut self, _kind: clipboard::Kind, contents: String) {
+            self.0.push(contents);
+        }
+    }
+
+    fn copy(selection: &mut Selection, clipboard: &mut Recorder) {
+        let _ = selection.update(
+            &Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Character("c".into()),
+                modified_key: keyboard::Key::Character("c".into()),
+                physical_key: keyboard::key::Physical::Unidentified(
+                    keyboard::key::NativeCode::Unidentified,
+                ),
+                location: keyboard::Location::Standard,
+                modifiers: keyboard::Modifiers::COMMAND,
+                text: None,
+            }),
+            Rectangle::default(),
+            mouse::Cursor::Unavailable,
+            &(),
+            "Hello, world!",
+            clipboard,
+        );
+    }
+
+    #[test]
+    fn only_focused_selections_are_copied() {
+        let mut clipboard = Recorder::default();
+
+        let mut unfocused = Selection {
+            anchor: 0,
+            focus: 5,
+            ..Selection::default()
+        };
+
+        let mut focused = Selection {
+            anchor: 7,
+            focus: 12,
+            is_focused: true,
+            ..Selection::default()
+        };
+
+        copy(&mut unfocused, &mut clipboard);
+        copy(&mut focused, &mut clipboard);
+
+        assert_eq!(clipboard.0, vec![String::from("world")]);
+    }
+}
@@ -2,19 +2,25 @@
 pub mod icon;
 pub mod settings;
 
+mod cursor_grab;
 mod event;
+mod geometry;
 mod id;
 mod level;
 mod mode;
+mod monitor;
 mod position;
 mod redraw_request;
 mod user_attention;
 
+pub use cursor_grab::CursorGrab;
 pub use event::Event;
+pub use geometry::Geometry;
 pub use icon::Icon;
 pub use id::Id;
 pub use level::Level;
 pub use mode::Mode;
+pub use monitor::Monitor;
 pub use position::Position;
 pub use redraw_request::RedrawRequest;
 pub use settings::Settings;
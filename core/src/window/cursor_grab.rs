@@ -0,0 +1,17 @@
+/// The way the mouse cursor is grabbed by a window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CursorGrab {
+    /// The cursor can move freely.
+    #[default]
+    None,
+
+    /// The cursor is confined to the bounds of the window.
+    Confined,
+
+    /// The cursor is locked in place.
+    ///
+    /// Use [`mouse::Event::RawMotion`] to track its movement.
+    ///
+    /// [`mouse::Event::RawMotion`]: crate::mouse::Event::RawMotion
+    Locked,
+}
@@ -1,4 +1,5 @@
 use crate::time::Instant;
+use crate::window::Monitor;
 use crate::{Point, Size};
 
 use std::path::PathBuf;
@@ -28,6 +29,10 @@ pub enum Event {
     /// A window was resized.
     Resized(Size),
 
+    /// A window was moved to a different [`Monitor`], or the properties of
+    /// its current [`Monitor`] changed.
+    MonitorChanged(Monitor),
+
     /// A window redraw was requested.
     ///
     /// The [`Instant`] contains the current time.
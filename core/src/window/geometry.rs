@@ -0,0 +1,136 @@
+use crate::window::Monitor;
+use crate::{Point, Rectangle, Size};
+
+/// A snapshot of the size and placement of a window.
+///
+/// It can be obtained with `window::get_geometry`, persisted, and later
+/// used to restore a window through [`Settings::geometry`].
+///
+/// [`Settings::geometry`]: crate::window::Settings::geometry
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Geometry {
+    /// The logical size of the window, when neither maximized nor in
+    /// fullscreen.
+    pub size: Size,
+
+    /// The position of the top-left corner of the window in the desktop, in
+    /// physical pixels, if known.
+    ///
+    /// Physical pixels are used because every monitor may have a different
+    /// scale factor, and so there is no single logical coordinate space.
+    pub position: Option<Point<i32>>,
+
+    /// Whether the window was maximized.
+    pub maximized: bool,
+
+    /// The name of the [`Monitor`] the window was on, if known.
+    pub monitor: Option<String>,
+}
+
+impl Geometry {
+    /// The minimum logical area of a window that must lie on a [`Monitor`]
+    /// for its saved position to be considered visible.
+    const MIN_VISIBLE: Size = Size::new(64.0, 32.0);
+
+    /// Returns the saved position of the [`Geometry`] if the window would
+    /// still be visible on one of the given monitors.
+    ///
+    /// Returns `None` if the window would be placed off-screen; for instance,
+    /// because the monitor it was on has been disconnected.
+    pub fn position_on(&self, monitors: &[Monitor]) -> Option<Point<i32>> {
+        let position = self.position?;
+
+        let is_visible = monitors.iter().any(|monitor| {
+            // The window takes the scale factor of the monitor it lands on
+            let scale_factor = monitor.scale_factor as f32;
+            let size = self.size * scale_factor;
+            let min_visible = Self::MIN_VISIBLE * scale_factor;
+
+            let bounds = Rectangle::new(
+                Point::new(position.x as f32, position.y as f32),
+                size,
+            );
+
+            let monitor_bounds = Rectangle::new(
+                Point::new(
+                    monitor.position.x as f32,
+                    monitor.position.y as f32,
+                ),
+                Size::new(
+                    monitor.size.width as f32,
+                    monitor.size.height as f32,
+                ),
+            );
+
+            bounds.intersection(&monitor_bounds).is_some_and(|visible| {
+                visible.width >= min_visible.width.min(size.width)
+                    && visible.height >= min_visible.height.min(size.height)
+            })
+        });
+
+        is_visible.then_some(position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monitor(x: i32, scale_factor: f64) -> Monitor {
+        Monitor {
+            name: None,
+            position: Point::new(x, 0),
+            size: Size::new(1920, 1080),
+            work_area: Rectangle {
+                x,
+                y: 0,
+                width: 1920,
+                height: 1040,
+            },
+            scale_factor,
+            refresh_rate: None,
+        }
+    }
+
+    fn geometry(x: i32, y: i32) -> Geometry {
+        Geometry {
+            size: Size::new(800.0, 600.0),
+            position: Some(Point::new(x, y)),
+            maximized: false,
+            monitor: None,
+        }
+    }
+
+    #[test]
+    fn position_on() {
+        let monitors = [monitor(0, 1.0), monitor(1920, 2.0)];
+
+        assert_eq!(
+            geometry(100, 100).position_on(&monitors),
+            Some(Point::new(100, 100))
+        );
+        assert_eq!(
+            geometry(1000, 100).position_on(&monitors),
+            Some(Point::new(1000, 100))
+        );
+        assert_eq!(geometry(-790, 100).position_on(&monitors), None);
+        assert_eq!(geometry(100, 1060).position_on(&monitors), None);
+        assert_eq!(geometry(100, 100).position_on(&[]), None);
+    }
+
+    #[test]
+    fn position_on_mixed_scale_factors() {
+        let monitors = [monitor(0, 1.0), monitor(1920, 2.0)];
+
+        // On the second monitor, in physical pixels
+        assert_eq!(
+            geometry(3000, 0).position_on(&monitors),
+            Some(Point::new(3000, 0))
+        );
+
+        // The minimum visible area is scaled too
+        assert_eq!(geometry(3760, 0).position_on(&monitors), None);
+        assert_eq!(geometry(4000, 0).position_on(&monitors), None);
+    }
+}
@@ -35,7 +35,7 @@ pub fn from_rgba(
 }
 
 /// An window icon normally used for the titlebar or taskbar.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Icon {
     rgba: Vec<u8>,
     size: Size<u32>,
@@ -0,0 +1,39 @@
+use crate::{Point, Rectangle, Size};
+
+/// A display connected to the system.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Monitor {
+    /// The human-readable name of the [`Monitor`], if known.
+    pub name: Option<String>,
+
+    /// The position of the top-left corner of the [`Monitor`] in the
+    /// desktop, in physical pixels.
+    pub position: Point<i32>,
+
+    /// The resolution of the [`Monitor`], in physical pixels.
+    pub size: Size<u32>,
+
+    /// The area of the [`Monitor`] that is not covered by panels, docks or
+    /// taskbars, in physical pixels.
+    ///
+    /// It spans the whole [`Monitor`] if the platform does not report it.
+    pub work_area: Rectangle<i32>,
+
+    /// The scale factor of the [`Monitor`].
+    pub scale_factor: f64,
+
+    /// The refresh rate of the [`Monitor`] in millihertz, if known.
+    pub refresh_rate: Option<u32>,
+}
+
+impl Monitor {
+    /// Returns the resolution of the [`Monitor`], in logical pixels.
+    pub fn logical_size(&self) -> Size {
+        let scale_factor = self.scale_factor as f32;
+
+        Size::new(
+            self.size.width as f32 / scale_factor,
+            self.size.height as f32 / scale_factor,
+        )
+    }
+}
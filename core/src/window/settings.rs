@@ -24,7 +24,7 @@ mod platform;
 #[path = "settings/other.rs"]
 mod platform;
 
-use crate::window::{Icon, Level, Position};
+use crate::window::{Geometry, Icon, Level, Position};
 use crate::Size;
 
 pub use platform::PlatformSpecific;
@@ -40,6 +40,13 @@ pub struct Settings {
     /// The initial position of the window.
     pub position: Position,
 
+    /// A saved [`Geometry`] to restore the window from.
+    ///
+    /// When present, it takes precedence over [`size`](Self::size) and
+    /// [`position`](Self::position). If the saved position is no longer
+    /// visible on any connected monitor, the window will be centered.
+    pub geometry: Option<Geometry>,
+
     /// The minimum size of the window.
     pub min_size: Option<Size>,
 
@@ -84,6 +91,7 @@ impl Default for Settings {
             size: Size::new(1024.0, 768.0),
             resize_border: 8,
             position: Position::default(),
+            geometry: None,
             min_size: None,
             max_size: None,
             visible: true,
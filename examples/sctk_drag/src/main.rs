@@ -1,22 +1,14 @@
-mod dnd_destination;
-mod dnd_source;
-
 use std::{borrow::Cow, convert::Infallible};
 
-use dnd_destination::dnd_destination;
 use iced::{
     clipboard::mime::{AllowedMimeTypes, AsMimeTypes},
     platform_specific::{
         runtime::wayland::layer_surface::SctkLayerSurfaceSettings,
         shell::commands::layer_surface::get_layer_surface,
     },
-    widget::{column, container, text},
+    widget::{column, container, dnd_destination_for_data, dnd_source, text},
     window, Element, Length, Task,
 };
-use iced_core::{
-    widget::{tree, Text},
-    Widget,
-};
 
 fn main() -> iced::Result {
     iced::daemon(DndTest::title, DndTest::update, DndTest::view)
@@ -135,7 +127,7 @@ impl DndTest {
         let s = self.current_text.chars().rev().collect::<String>();
         let s2 = s.clone();
         column![
-            dnd_destination::dnd_destination_for_data::<MyDndString, Message>(
+            dnd_destination_for_data::<MyDndString, Message, _, _>(
                 container(text(format!(
                     "Drag text here: {}",
                     &self.current_text
@@ -149,7 +141,7 @@ impl DndTest {
                 }
             )
             .drag_id(1)
-            .on_enter(|_, _, m| {
+            .on_enter(|_, m| {
                 dbg!(m);
                 Message::Drag
             })
@@ -157,15 +149,15 @@ impl DndTest {
                 dbg!(a);
                 Message::Drag
             })
-            .on_drop(|_, _| {
+            .on_drop(|_| {
                 dbg!("drop");
                 Message::Drag
             })
-            .on_motion(|x, y| {
-                dbg!(x, y);
+            .on_motion(|position| {
+                dbg!(position);
                 Message::Drag
             }),
-            dnd_source::dnd_source(
+            dnd_source(
                 container(text(format!(
                     "Drag me: {}",
                     &self.current_text.chars().rev().collect::<String>()
@@ -175,23 +167,7 @@ impl DndTest {
                 .padding(20)
             )
             .drag_threshold(5.0)
-            .drag_icon(move || {
-                let t: Text<'static, iced::Theme, iced::Renderer> =
-                    text(s.clone());
-                let state = <iced_core::widget::Text<
-                    'static,
-                    iced::Theme,
-                    iced::Renderer,
-                > as iced_core::Widget<(), iced::Theme, iced::Renderer>>::state(
-                    &t,
-                );
-                (
-                    Element::<'static, (), iced::Theme, iced::Renderer>::from(
-                        t,
-                    ),
-                    state,
-                )
-            })
+            .drag_icon(move || text(s.clone()).into())
             .drag_content(move || { MyDndString(s2.clone()) })
         ]
         .width(Length::Fill)
@@ -120,6 +120,18 @@ where
         }
     }
 
+    /// Returns the identifiers and names of the subscriptions currently
+    /// alive in the [`Runtime`].
+    ///
+    /// See [`Tracker::subscriptions`] to learn more.
+    ///
+    /// [`Tracker::subscriptions`]: subscription::Tracker::subscriptions
+    pub fn subscriptions(
+        &self,
+    ) -> impl Iterator<Item = (u64, &'static str)> + '_ {
+        self.subscriptions.subscriptions()
+    }
+
     /// Broadcasts an event to all the subscriptions currently alive in the
     /// [`Runtime`].
     ///
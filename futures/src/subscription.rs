@@ -317,6 +317,14 @@ pub trait Recipe {
     /// Executes the [`Recipe`] and produces the stream of events of its
     /// [`Subscription`].
     fn stream(self: Box<Self>, input: EventStream) -> BoxStream<Self::Output>;
+
+    /// Returns a human-readable name of the [`Recipe`].
+    ///
+    /// This is used by runtimes to describe a [`Subscription`] when
+    /// debugging. By default, it is the type name of the [`Recipe`].
+    fn name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
 }
 
 struct Map<A, B, F>
@@ -361,6 +369,10 @@ where
 
         Box::pin(self.recipe.stream(input).map(mapper))
     }
+
+    fn name(&self) -> &'static str {
+        self.recipe.name()
+    }
 }
 
 struct With<A, B> {
@@ -398,6 +410,10 @@ where
                 .map(move |element| (value.clone(), element)),
         )
     }
+
+    fn name(&self) -> &'static str {
+        self.recipe.name()
+    }
 }
 
 pub(crate) fn filter_map<I, F, T>(id: I, f: F) -> Subscription<T>
@@ -442,4 +458,27 @@ where
     fn stream(self: Box<Self>, input: EventStream) -> BoxStream<Self::Output> {
         crate::boxed_stream((self.spawn)(input))
     }
+
+    fn name(&self) -> &'static str {
+        std::any::type_name::<S>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mapped_recipes_keep_the_name_of_their_source() {
+        let subscription =
+            Subscription::run_with_id("ticks", futures::stream::iter([1, 2]))
+                .map(|tick: i32| tick * 2)
+                .with("window");
+
+        type Ticks = futures::stream::Iter<std::array::IntoIter<i32, 2>>;
+
+        let recipes = into_recipes(subscription);
+
+        assert_eq!(recipes[0].name(), std::any::type_name::<Ticks>());
+    }
 }
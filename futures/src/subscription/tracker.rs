@@ -21,6 +21,7 @@ pub struct Tracker {
 
 #[derive(Debug)]
 pub struct Execution {
+    name: &'static str,
     _cancel: futures::channel::oneshot::Sender<()>,
     listener: Option<futures::channel::mpsc::Sender<Event>>,
 }
@@ -91,6 +92,7 @@ impl Tracker {
                 futures::channel::mpsc::channel(100);
 
             let mut receiver = receiver.clone();
+            let name = recipe.name();
             let mut stream = recipe.stream(event_receiver.boxed());
 
             let future = async move {
@@ -111,6 +113,7 @@ impl Tracker {
             let _ = self.subscriptions.insert(
                 id,
                 Execution {
+                    name,
                     _cancel: cancel,
                     listener: if event_sender.is_closed() {
                         None
@@ -128,6 +131,22 @@ impl Tracker {
         futures
     }
 
+    /// Returns the identifiers and names of the subscriptions currently
+    /// alive.
+    ///
+    /// An identifier is the hash of the [`Recipe`] of a subscription, and a
+    /// name is given by [`Recipe::name`].
+    ///
+    /// [`Recipe`]: crate::subscription::Recipe
+    /// [`Recipe::name`]: crate::subscription::Recipe::name
+    pub fn subscriptions(
+        &self,
+    ) -> impl Iterator<Item = (u64, &'static str)> + '_ {
+        self.subscriptions
+            .iter()
+            .map(|(id, execution)| (*id, execution.name))
+    }
+
     /// Broadcasts an event to the subscriptions currently alive.
     ///
     /// A subscription's [`Recipe::stream`] always receives a stream of events
@@ -1,5 +1,6 @@
 //! A compositor is responsible for initializing a renderer and managing window
 //! surfaces.
+use crate::core::renderer;
 use crate::core::Color;
 use crate::futures::{MaybeSend, MaybeSync};
 use crate::{Error, Settings, Viewport};
@@ -83,6 +84,15 @@ pub trait Compositor: Sized {
         overlay: &[T],
     ) -> Result<(), SurfaceError>;
 
+    /// Returns the [`Stats`] of the last frame presented by the given
+    /// [`Renderer`].
+    ///
+    /// [`Renderer`]: Self::Renderer
+    /// [`Stats`]: renderer::Stats
+    fn stats(&self, _renderer: &Self::Renderer) -> renderer::Stats {
+        renderer::Stats::default()
+    }
+
     /// Screenshots the current [`Renderer`] primitives to an offscreen texture, and returns the bytes of
     /// the texture ordered as `RGBA` in the `sRGB` color space.
     ///
@@ -144,6 +144,33 @@ where
         result
     }
 
+    /// Executes the given drawing operations clipped by the given [`Path`],
+    /// hiding any geometry that falls outside of it. Any transformations
+    /// performed are local to the provided closure.
+    ///
+    /// Unlike [`with_clip`], the coordinate system of the [`Frame`] is kept
+    /// as is, including its current transform.
+    ///
+    /// __Warning:__ The clipped geometry will be drawn on top of any other
+    /// geometry of the [`Frame`].
+    ///
+    /// [`with_clip`]: Self::with_clip
+    #[inline]
+    pub fn with_clip_path<R>(
+        &mut self,
+        path: &Path,
+        f: impl FnOnce(&mut Self) -> R,
+    ) -> R {
+        let mut frame = Self {
+            raw: self.raw.draft_with_mask(path),
+        };
+
+        let result = f(&mut frame);
+        self.paste(frame);
+
+        result
+    }
+
     /// Creates a new [`Frame`] with the given [`Size`].
     ///
     /// Draw its contents back to this [`Frame`] with [`paste`].
@@ -208,6 +235,7 @@ pub trait Backend: Sized {
     fn scale_nonuniform(&mut self, scale: impl Into<Vector>);
 
     fn draft(&mut self, clip_bounds: Rectangle) -> Self;
+    fn draft_with_mask(&mut self, mask: &Path) -> Self;
     fn paste(&mut self, frame: Self);
 
     fn stroke<'a>(&mut self, path: &Path, stroke: impl Into<Stroke<'a>>);
@@ -262,6 +290,7 @@ impl Backend for () {
     fn scale_nonuniform(&mut self, _scale: impl Into<Vector>) {}
 
     fn draft(&mut self, _clip_bounds: Rectangle) -> Self {}
+    fn draft_with_mask(&mut self, _mask: &Path) -> Self {}
     fn paste(&mut self, _frame: Self) {}
 
     fn stroke<'a>(&mut self, _path: &Path, _stroke: impl Into<Stroke<'a>>) {}
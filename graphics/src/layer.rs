@@ -6,9 +6,18 @@ use crate::core::{Rectangle, Transformation};
 /// Layers normally dictate a set of primitives that are
 /// rendered in a specific order.
 pub trait Layer: Default {
+    /// The clipping mask of a [`Layer`], applied on top of its bounds.
+    type Mask: Clone + std::fmt::Debug;
+
     /// Creates a new [`Layer`] with the given bounds.
     fn with_bounds(bounds: Rectangle) -> Self;
 
+    /// Sets the clipping masks of the [`Layer`].
+    ///
+    /// Every mask clips the [`Layer`], so only the intersection of all of
+    /// them is visible. They are sorted from outermost to innermost.
+    fn set_masks(&mut self, masks: &[Self::Mask]);
+
     /// Flushes and settles any pending group of primitives in the [`Layer`].
     ///
     /// This will be called when a [`Layer`] is finished. It allows layers to efficiently
@@ -27,6 +36,8 @@ pub trait Layer: Default {
 pub struct Stack<T: Layer> {
     layers: Vec<T>,
     transformations: Vec<Transformation>,
+    masks: Vec<T::Mask>,
+    mask_counts: Vec<usize>,
     previous: Vec<usize>,
     current: usize,
     active_count: usize,
@@ -38,6 +49,8 @@ impl<T: Layer> Stack<T> {
         Self {
             layers: vec![T::default()],
             transformations: vec![Transformation::IDENTITY],
+            masks: vec![],
+            mask_counts: vec![],
             previous: vec![],
             current: 0,
             active_count: 1,
@@ -59,10 +72,35 @@ impl<T: Layer> Stack<T> {
         self.transformations.last().copied().unwrap()
     }
 
+    /// Returns the current clipping masks of the [`Stack`], from outermost
+    /// to innermost.
+    #[inline]
+    pub fn masks(&self) -> &[T::Mask] {
+        &self.masks
+    }
+
     /// Pushes a new clipping region in the [`Stack`]; creating a new layer in the
     /// process.
+    ///
+    /// The new layer inherits the current clipping masks of the [`Stack`].
     pub fn push_clip(&mut self, bounds: Rectangle) {
+        self.push(bounds, None);
+    }
+
+    /// Pushes a new clipping region with the given mask in the [`Stack`];
+    /// creating a new layer in the process.
+    ///
+    /// The mask must already be transformed with the current [`Transformation`]
+    /// of the [`Stack`]. It is applied together with any mask inherited from
+    /// previous clipping regions.
+    pub fn push_mask(&mut self, bounds: Rectangle, mask: T::Mask) {
+        self.push(bounds, Some(mask));
+    }
+
+    fn push(&mut self, bounds: Rectangle, mask: Option<T::Mask>) {
         self.previous.push(self.current);
+        self.mask_counts.push(self.masks.len());
+        self.masks.extend(mask);
 
         self.current = self.active_count;
         self.active_count += 1;
@@ -74,6 +112,8 @@ impl<T: Layer> Stack<T> {
         } else {
             self.layers[self.current].resize(bounds);
         }
+
+        self.layers[self.current].set_masks(&self.masks);
     }
 
     /// Pops the current clipping region from the [`Stack`] and restores the previous one.
@@ -83,6 +123,7 @@ impl<T: Layer> Stack<T> {
         self.flush();
 
         self.current = self.previous.pop().unwrap();
+        self.masks.truncate(self.mask_counts.pop().unwrap());
     }
 
     /// Pushes a new [`Transformation`] in the [`Stack`].
@@ -133,6 +174,8 @@ impl<T: Layer> Stack<T> {
 
         self.current = 0;
         self.active_count = 1;
+        self.masks.clear();
+        self.mask_counts.clear();
         self.previous.clear();
     }
 }
@@ -142,3 +185,89 @@ impl<T: Layer> Default for Stack<T> {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    struct Masked {
+        bounds: Rectangle,
+        masks: Vec<u32>,
+    }
+
+    impl Layer for Masked {
+        type Mask = u32;
+
+        fn with_bounds(bounds: Rectangle) -> Self {
+            Self {
+                bounds,
+                masks: Vec::new(),
+            }
+        }
+
+        fn set_masks(&mut self, masks: &[u32]) {
+            self.masks = masks.to_vec();
+        }
+
+        fn flush(&mut self) {}
+
+        fn resize(&mut self, bounds: Rectangle) {
+            self.bounds = bounds;
+        }
+
+        fn reset(&mut self) {
+            self.bounds = Rectangle::INFINITE;
+            self.masks.clear();
+        }
+    }
+
+    fn masks(stack: &Stack<Masked>) -> Vec<Vec<u32>> {
+        stack.iter().map(|layer| layer.masks.clone()).collect()
+    }
+
+    #[test]
+    fn nested_masks_are_chained() {
+        let mut stack = Stack::<Masked>::new();
+        let bounds = Rectangle::with_size(crate::core::Size::new(10.0, 10.0));
+
+        stack.push_mask(bounds, 1);
+        stack.push_clip(bounds);
+        stack.push_mask(bounds, 2);
+
+        assert_eq!(stack.masks(), [1, 2]);
+
+        stack.pop_clip();
+        assert_eq!(stack.masks(), [1]);
+
+        stack.pop_clip();
+        assert_eq!(stack.masks(), [1]);
+
+        stack.pop_clip();
+        assert!(stack.masks().is_empty());
+
+        stack.push_clip(bounds);
+        stack.pop_clip();
+
+        assert_eq!(
+            masks(&stack),
+            [vec![], vec![1], vec![1], vec![1, 2], vec![]]
+        );
+    }
+
+    #[test]
+    fn clear_resets_masks() {
+        let mut stack = Stack::<Masked>::new();
+        let bounds = Rectangle::with_size(crate::core::Size::new(10.0, 10.0));
+
+        stack.push_mask(bounds, 1);
+        stack.push_mask(bounds, 2);
+        stack.clear();
+
+        assert!(stack.masks().is_empty());
+
+        stack.push_clip(bounds);
+
+        assert_eq!(masks(&stack), [vec![], vec![]]);
+    }
+}
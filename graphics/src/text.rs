@@ -11,12 +11,17 @@ pub use cosmic_text;
 
 use crate::core::alignment;
 use crate::core::font::{self, Font};
-use crate::core::text::{Shaping, Wrapping};
+use crate::core::text::{Ellipsize, EllipsizeHeightLimit, Shaping, Wrapping};
 use crate::core::{Color, Pixels, Point, Rectangle, Size, Transformation};
 
+use cosmic_text::Fallback as _;
 use once_cell::sync::OnceCell;
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::borrow::Cow;
-use std::sync::{Arc, RwLock, Weak};
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+use std::sync::{Arc, Mutex, RwLock, Weak};
 
 /// A text primitive.
 #[derive(Debug, Clone, PartialEq)]
@@ -59,6 +64,8 @@ pub enum Text {
         vertical_alignment: alignment::Vertical,
         /// The shaping strategy of the text.
         shaping: Shaping,
+        /// The ellipsizing strategy of the text.
+        ellipsize: Ellipsize,
         /// The clip bounds of the text.
         clip_bounds: Rectangle,
     },
@@ -169,6 +176,7 @@ pub fn font_system() -> &'static RwLock<FontSystem> {
                 )),
             ]),
             version: Version::default(),
+            fallback: Fallback::default(),
         })
     })
 }
@@ -178,6 +186,7 @@ pub fn font_system() -> &'static RwLock<FontSystem> {
 pub struct FontSystem {
     raw: cosmic_text::FontSystem,
     version: Version,
+    fallback: Fallback,
 }
 
 impl FontSystem {
@@ -195,6 +204,94 @@ impl FontSystem {
         self.version = Version(self.version.0 + 1);
     }
 
+    /// Loads all the fonts in the file at the given path.
+    pub fn load_font_file(&mut self, path: &Path) -> io::Result<()> {
+        self.raw.db_mut().load_font_file(path)?;
+
+        self.version = Version(self.version.0 + 1);
+
+        Ok(())
+    }
+
+    /// Returns the font families available in the [`FontSystem`], sorted by
+    /// name.
+    pub fn families(&self) -> Vec<font::FamilyInfo> {
+        let mut families = BTreeMap::<&str, Vec<font::Face>>::new();
+
+        for face in self.raw.db().faces() {
+            let Some((name, _)) = face.families.first() else {
+                continue;
+            };
+
+            let faces = families.entry(name).or_default();
+
+            let face = font::Face {
+                weight: from_weight(face.weight),
+                stretch: from_stretch(face.stretch),
+                style: from_style(face.style),
+                monospaced: face.monospaced,
+            };
+
+            if !faces.contains(&face) {
+                faces.push(face);
+            }
+        }
+
+        families
+            .into_iter()
+            .map(|(name, faces)| font::FamilyInfo {
+                name: name.to_owned(),
+                faces,
+            })
+            .collect()
+    }
+
+    /// Returns whether a font family with the given name is available in the
+    /// [`FontSystem`].
+    ///
+    /// The name is compared ignoring ASCII case.
+    pub fn has_family(&self, name: &str) -> bool {
+        self.raw.db().faces().any(|face| {
+            face.families
+                .iter()
+                .any(|(family, _)| family.eq_ignore_ascii_case(name))
+        })
+    }
+
+    /// Sets the ordered list of font families used as fallback for the
+    /// given [`font::Script`], replacing the platform defaults.
+    ///
+    /// An empty list restores the platform defaults.
+    pub fn set_fallback(
+        &mut self,
+        script: font::Script,
+        families: Vec<String>,
+    ) {
+        let families: Vec<_> = families.into_iter().map(intern).collect();
+
+        if families.is_empty() {
+            let _ = self.fallback.scripts.remove(&script);
+        } else {
+            let _ = self.fallback.scripts.insert(script, families);
+        }
+
+        // The fallback lists of a `cosmic_text::FontSystem` are fixed on
+        // creation; so we need to rebuild it.
+        let locale = self.raw.locale().to_owned();
+        let db = std::mem::replace(
+            self.raw.db_mut(),
+            cosmic_text::fontdb::Database::new(),
+        );
+
+        self.raw = cosmic_text::FontSystem::new_with_locale_and_db_and_fallback(
+            locale,
+            db,
+            self.fallback.clone(),
+        );
+
+        self.version = Version(self.version.0 + 1);
+    }
+
     /// Returns the current [`Version`] of the [`FontSystem`].
     ///
     /// Loading a font will increase the version of a [`FontSystem`].
@@ -203,6 +300,65 @@ impl FontSystem {
     }
 }
 
+/// Interns a font family name for the whole process.
+///
+/// Fallback lists must be `'static`; so each distinct name is leaked exactly
+/// once, no matter how many times the fallbacks are changed.
+fn intern(name: String) -> &'static str {
+    static NAMES: OnceCell<Mutex<FxHashSet<&'static str>>> = OnceCell::new();
+
+    let mut names = NAMES
+        .get_or_init(Mutex::default)
+        .lock()
+        .expect("Lock interned font names");
+
+    if let Some(&name) = names.get(name.as_str()) {
+        return name;
+    }
+
+    let name: &'static str = Box::leak(name.into_boxed_str());
+    let _ = names.insert(name);
+
+    name
+}
+
+/// The fallback lists of a [`FontSystem`], falling back to the
+/// platform defaults.
+#[derive(Debug, Clone, Default)]
+struct Fallback {
+    scripts: FxHashMap<font::Script, Vec<&'static str>>,
+}
+
+impl cosmic_text::Fallback for Fallback {
+    fn common_fallback(&self) -> &[&'static str] {
+        match self.scripts.get(&font::Script::Common) {
+            Some(families) => families,
+            None => PLATFORM_FALLBACK.common_fallback(),
+        }
+    }
+
+    fn forbidden_fallback(&self) -> &[&'static str] {
+        PLATFORM_FALLBACK.forbidden_fallback()
+    }
+
+    fn script_fallback(
+        &self,
+        script: unicode_script::Script,
+        locale: &str,
+    ) -> &[&'static str] {
+        let families =
+            from_script(script).and_then(|script| self.scripts.get(&script));
+
+        match families {
+            Some(families) => families,
+            None => PLATFORM_FALLBACK.script_fallback(script, locale),
+        }
+    }
+}
+
+static PLATFORM_FALLBACK: cosmic_text::PlatformFallback =
+    cosmic_text::PlatformFallback;
+
 /// A version number.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct Version(u32);
@@ -297,6 +453,76 @@ fn to_stretch(stretch: font::Stretch) -> cosmic_text::Stretch {
     }
 }
 
+fn from_weight(weight: cosmic_text::Weight) -> font::Weight {
+    match weight.0 {
+        0..=149 => font::Weight::Thin,
+        150..=249 => font::Weight::ExtraLight,
+        250..=349 => font::Weight::Light,
+        350..=449 => font::Weight::Normal,
+        450..=549 => font::Weight::Medium,
+        550..=649 => font::Weight::Semibold,
+        650..=749 => font::Weight::Bold,
+        750..=849 => font::Weight::ExtraBold,
+        _ => font::Weight::Black,
+    }
+}
+
+fn from_stretch(stretch: cosmic_text::Stretch) -> font::Stretch {
+    match stretch {
+        cosmic_text::Stretch::UltraCondensed => font::Stretch::UltraCondensed,
+        cosmic_text::Stretch::ExtraCondensed => font::Stretch::ExtraCondensed,
+        cosmic_text::Stretch::Condensed => font::Stretch::Condensed,
+        cosmic_text::Stretch::SemiCondensed => font::Stretch::SemiCondensed,
+        cosmic_text::Stretch::Normal => font::Stretch::Normal,
+        cosmic_text::Stretch::SemiExpanded => font::Stretch::SemiExpanded,
+        cosmic_text::Stretch::Expanded => font::Stretch::Expanded,
+        cosmic_text::Stretch::ExtraExpanded => font::Stretch::ExtraExpanded,
+        cosmic_text::Stretch::UltraExpanded => font::Stretch::UltraExpanded,
+    }
+}
+
+fn from_style(style: cosmic_text::Style) -> font::Style {
+    match style {
+        cosmic_text::Style::Normal => font::Style::Normal,
+        cosmic_text::Style::Italic => font::Style::Italic,
+        cosmic_text::Style::Oblique => font::Style::Oblique,
+    }
+}
+
+fn from_script(script: unicode_script::Script) -> Option<font::Script> {
+    use unicode_script::Script;
+
+    Some(match script {
+        Script::Arabic => font::Script::Arabic,
+        Script::Armenian => font::Script::Armenian,
+        Script::Bengali => font::Script::Bengali,
+        Script::Cyrillic => font::Script::Cyrillic,
+        Script::Devanagari => font::Script::Devanagari,
+        Script::Ethiopic => font::Script::Ethiopic,
+        Script::Georgian => font::Script::Georgian,
+        Script::Greek => font::Script::Greek,
+        Script::Gujarati => font::Script::Gujarati,
+        Script::Gurmukhi => font::Script::Gurmukhi,
+        Script::Han => font::Script::Han,
+        Script::Hangul => font::Script::Hangul,
+        Script::Hebrew => font::Script::Hebrew,
+        Script::Hiragana => font::Script::Hiragana,
+        Script::Kannada => font::Script::Kannada,
+        Script::Katakana => font::Script::Katakana,
+        Script::Khmer => font::Script::Khmer,
+        Script::Lao => font::Script::Lao,
+        Script::Latin => font::Script::Latin,
+        Script::Malayalam => font::Script::Malayalam,
+        Script::Myanmar => font::Script::Myanmar,
+        Script::Sinhala => font::Script::Sinhala,
+        Script::Tamil => font::Script::Tamil,
+        Script::Telugu => font::Script::Telugu,
+        Script::Thai => font::Script::Thai,
+        Script::Tibetan => font::Script::Tibetan,
+        _ => return None,
+    })
+}
+
 fn to_style(style: font::Style) -> cosmic_text::Style {
     match style {
         font::Style::Normal => cosmic_text::Style::Normal,
@@ -323,9 +549,111 @@ pub fn to_wrap(wrapping: Wrapping) -> cosmic_text::Wrap {
     }
 }
 
+/// Converts some [`Ellipsize`] strategy to a [`cosmic_text::Ellipsize`]
+/// strategy, given the height of the bounds of the text.
+pub fn to_ellipsize(
+    ellipsize: Ellipsize,
+    height: f32,
+) -> cosmic_text::Ellipsize {
+    let limit = |limit| match limit {
+        EllipsizeHeightLimit::Lines(lines) => {
+            cosmic_text::EllipsizeHeightLimit::Lines(lines)
+        }
+        EllipsizeHeightLimit::Bounds => {
+            cosmic_text::EllipsizeHeightLimit::Height(height)
+        }
+    };
+
+    match ellipsize {
+        Ellipsize::None => cosmic_text::Ellipsize::None,
+        Ellipsize::Start(height_limit) => {
+            cosmic_text::Ellipsize::Start(limit(height_limit))
+        }
+        Ellipsize::Middle(height_limit) => {
+            cosmic_text::Ellipsize::Middle(limit(height_limit))
+        }
+        Ellipsize::End(height_limit) => {
+            cosmic_text::Ellipsize::End(limit(height_limit))
+        }
+    }
+}
+
 /// Converts some [`Color`] to a [`cosmic_text::Color`].
 pub fn to_color(color: Color) -> cosmic_text::Color {
     let [r, g, b, a] = color.into_rgba8();
 
     cosmic_text::Color::rgba(r, g, b, a)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_are_interned_once() {
+        let a = intern(String::from("Interned Sans"));
+        let b = intern(String::from("Interned Sans"));
+        let c = intern(String::from("Interned Serif"));
+
+        assert!(std::ptr::eq(a, b));
+        assert!(!std::ptr::eq(a, c));
+    }
+
+    #[test]
+    fn configured_fallbacks_replace_platform_defaults() {
+        let mut fallback = Fallback::default();
+
+        let _ = fallback
+            .scripts
+            .insert(font::Script::Common, vec![intern("Common".into())]);
+
+        assert_eq!(fallback.common_fallback(), ["Common"]);
+        assert_eq!(
+            fallback.script_fallback(unicode_script::Script::Latin, "en-US"),
+            PLATFORM_FALLBACK
+                .script_fallback(unicode_script::Script::Latin, "en-US")
+        );
+    }
+
+    #[test]
+    fn missing_font_files_are_reported() {
+        let mut font_system = font_system().write().expect("Write font system");
+        let version = font_system.version();
+
+        let result =
+            font_system.load_font_file(Path::new("/nonexistent/font.ttf"));
+
+        assert_eq!(
+            result.map_err(|error| error.kind()),
+            Err(io::ErrorKind::NotFound)
+        );
+        assert_eq!(font_system.version(), version);
+    }
+
+    #[test]
+    fn ellipsize_limits_map_to_cosmic_text() {
+        use cosmic_text::EllipsizeHeightLimit as Limit;
+
+        assert!(matches!(
+            to_ellipsize(Ellipsize::None, 40.0),
+            cosmic_text::Ellipsize::None
+        ));
+
+        assert!(matches!(
+            to_ellipsize(Ellipsize::End(EllipsizeHeightLimit::Lines(2)), 40.0),
+            cosmic_text::Ellipsize::End(Limit::Lines(2))
+        ));
+
+        assert!(matches!(
+            to_ellipsize(Ellipsize::Start(EllipsizeHeightLimit::Bounds), 40.0),
+            cosmic_text::Ellipsize::Start(Limit::Height(height))
+                if height.to_bits() == 40.0f32.to_bits()
+        ));
+
+        assert!(matches!(
+            to_ellipsize(Ellipsize::Middle(EllipsizeHeightLimit::Bounds), 12.5),
+            cosmic_text::Ellipsize::Middle(Limit::Height(height))
+                if height.to_bits() == 12.5f32.to_bits()
+        ));
+    }
+}
@@ -51,6 +51,13 @@ impl Cache {
                 Some(key.bounds.width),
                 Some(key.bounds.height.max(key.line_height)),
             );
+            buffer.set_ellipsize(
+                font_system,
+                text::to_ellipsize(
+                    key.ellipsize,
+                    key.bounds.height.max(key.line_height),
+                ),
+            );
             buffer.set_text(
                 font_system,
                 key.content,
@@ -72,7 +79,9 @@ impl Cache {
                     ..bounds
                 },
             ] {
-                if key.bounds != bounds {
+                if key.bounds != bounds
+                    && key.ellipsize == text::Ellipsize::None
+                {
                     let _ = self.aliases.insert(
                         Key { bounds, ..key }.hash(FxHasher::default()),
                         hash,
@@ -86,6 +95,16 @@ impl Cache {
         (hash, self.entries.get_mut(&hash).unwrap())
     }
 
+    /// Returns the amount of text buffers in the [`Cache`].
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the [`Cache`] contains no text buffers.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
     /// Trims the [`Cache`].
     ///
     /// This will clear the sections of text that have not been used since the last `trim`.
@@ -115,6 +134,8 @@ pub struct Key<'a> {
     pub bounds: Size,
     /// The shaping strategy of the text.
     pub shaping: text::Shaping,
+    /// The ellipsizing strategy of the text.
+    pub ellipsize: text::Ellipsize,
 }
 
 impl Key<'_> {
@@ -126,6 +147,7 @@ impl Key<'_> {
         self.bounds.width.to_bits().hash(&mut hasher);
         self.bounds.height.to_bits().hash(&mut hasher);
         self.shaping.hash(&mut hasher);
+        self.ellipsize.hash(&mut hasher);
 
         hasher.finish()
     }
@@ -142,3 +164,52 @@ pub struct Entry {
     /// The minimum bounds of the text.
     pub min_bounds: Size,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(ellipsize: text::Ellipsize) -> Key<'static> {
+        Key {
+            content: "An ellipsized section of text",
+            size: 16.0,
+            line_height: 20.0,
+            font: Font::default(),
+            bounds: Size::new(60.0, 20.0),
+            shaping: text::Shaping::Basic,
+            ellipsize,
+        }
+    }
+
+    #[test]
+    fn ellipsizing_is_part_of_the_key() {
+        let end = text::Ellipsize::End(text::EllipsizeHeightLimit::Bounds);
+
+        assert_ne!(
+            key(text::Ellipsize::None).hash(FxHasher::default()),
+            key(end).hash(FxHasher::default())
+        );
+    }
+
+    #[test]
+    fn ellipsized_entries_are_not_aliased() {
+        let mut font_system =
+            text::font_system().write().expect("Write font system");
+
+        let mut cache = Cache::new();
+
+        let (none, _) =
+            cache.allocate(font_system.raw(), key(text::Ellipsize::None));
+
+        let aliases = cache.aliases.len();
+
+        let (end, _) = cache.allocate(
+            font_system.raw(),
+            key(text::Ellipsize::End(text::EllipsizeHeightLimit::Bounds)),
+        );
+
+        assert_ne!(none, end);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.aliases.len(), aliases);
+    }
+}
@@ -156,79 +156,7 @@ impl editor::Editor for Editor {
 
                 Cursor::Selection(regions)
             }
-            _ => {
-                let line_height = buffer.metrics().line_height;
-
-                let visual_lines_offset =
-                    visual_lines_offset(cursor.line, buffer);
-
-                let line = buffer
-                    .lines
-                    .get(cursor.line)
-                    .expect("Cursor line should be present");
-
-                let layout =
-                    line.layout_opt().expect("Line layout should be cached");
-
-                let mut lines = layout.iter().enumerate();
-
-                let (visual_line, offset) = lines
-                    .find_map(|(i, line)| {
-                        let start = line
-                            .glyphs
-                            .first()
-                            .map(|glyph| glyph.start)
-                            .unwrap_or(0);
-                        let end = line
-                            .glyphs
-                            .last()
-                            .map(|glyph| glyph.end)
-                            .unwrap_or(0);
-
-                        let is_cursor_before_start = start > cursor.index;
-
-                        let is_cursor_before_end = match cursor.affinity {
-                            cosmic_text::Affinity::Before => {
-                                cursor.index <= end
-                            }
-                            cosmic_text::Affinity::After => cursor.index < end,
-                        };
-
-                        if is_cursor_before_start {
-                            // Sometimes, the glyph we are looking for is right
-                            // between lines. This can happen when a line wraps
-                            // on a space.
-                            // In that case, we can assume the cursor is at the
-                            // end of the previous line.
-                            // i is guaranteed to be > 0 because `start` is always
-                            // 0 for the first line, so there is no way for the
-                            // cursor to be before it.
-                            Some((i - 1, layout[i - 1].w))
-                        } else if is_cursor_before_end {
-                            let offset = line
-                                .glyphs
-                                .iter()
-                                .take_while(|glyph| cursor.index > glyph.start)
-                                .map(|glyph| glyph.w)
-                                .sum();
-
-                            Some((i, offset))
-                        } else {
-                            None
-                        }
-                    })
-                    .unwrap_or((
-                        layout.len().saturating_sub(1),
-                        layout.last().map(|line| line.w).unwrap_or(0.0),
-                    ));
-
-                Cursor::Caret(Point::new(
-                    offset,
-                    (visual_lines_offset + visual_line as i32) as f32
-                        * line_height
-                        - buffer.scroll().vertical,
-                ))
-            }
+            _ => Cursor::Caret(caret_position(buffer, cursor)),
         }
     }
 
@@ -238,6 +166,29 @@ impl editor::Editor for Editor {
         (cursor.line, cursor.index)
     }
 
+    fn selection_anchor(&self) -> Option<(usize, usize)> {
+        let internal = self.internal();
+
+        let cursor = internal.editor.cursor();
+        let (start, end) = internal.editor.selection_bounds()?;
+
+        let anchor = if start.line == cursor.line && start.index == cursor.index
+        {
+            end
+        } else {
+            start
+        };
+
+        Some((anchor.line, anchor.index))
+    }
+
+    fn hit_test(&self, point: Point) -> Option<Point> {
+        let buffer = self.buffer();
+        let cursor = buffer.hit(point.x, point.y)?;
+
+        Some(caret_position(buffer, cursor))
+    }
+
     fn perform(&mut self, action: Action) {
         let mut font_system =
             text::font_system().write().expect("Write font system");
@@ -342,6 +293,37 @@ impl editor::Editor for Editor {
                 }
             }
 
+            Action::SelectRange { anchor, focus } => {
+                let buffer = buffer_from_editor(editor);
+
+                let to_cursor = |(line, index): (usize, usize)| {
+                    let line = line.min(buffer.lines.len().saturating_sub(1));
+                    let text = buffer
+                        .lines
+                        .get(line)
+                        .map(cosmic_text::BufferLine::text)
+                        .unwrap_or_default();
+
+                    let mut index = index.min(text.len());
+
+                    while !text.is_char_boundary(index) {
+                        index -= 1;
+                    }
+
+                    cosmic_text::Cursor::new(line, index)
+                };
+
+                let anchor = to_cursor(anchor);
+                let focus = to_cursor(focus);
+
+                editor.set_cursor(focus);
+                editor.set_selection(if anchor == focus {
+                    cosmic_text::Selection::None
+                } else {
+                    cosmic_text::Selection::Normal(anchor)
+                });
+            }
+
             // Editing events
             Action::Edit(edit) => {
                 match edit {
@@ -715,6 +697,71 @@ fn highlight_line(
     })
 }
 
+fn caret_position(
+    buffer: &cosmic_text::Buffer,
+    cursor: cosmic_text::Cursor,
+) -> Point {
+    let line_height = buffer.metrics().line_height;
+
+    let visual_lines_offset = visual_lines_offset(cursor.line, buffer);
+
+    let line = buffer
+        .lines
+        .get(cursor.line)
+        .expect("Cursor line should be present");
+
+    let layout = line.layout_opt().expect("Line layout should be cached");
+
+    let mut lines = layout.iter().enumerate();
+
+    let (visual_line, offset) = lines
+        .find_map(|(i, line)| {
+            let start =
+                line.glyphs.first().map(|glyph| glyph.start).unwrap_or(0);
+            let end = line.glyphs.last().map(|glyph| glyph.end).unwrap_or(0);
+
+            let is_cursor_before_start = start > cursor.index;
+
+            let is_cursor_before_end = match cursor.affinity {
+                cosmic_text::Affinity::Before => cursor.index <= end,
+                cosmic_text::Affinity::After => cursor.index < end,
+            };
+
+            if is_cursor_before_start {
+                // Sometimes, the glyph we are looking for is right
+                // between lines. This can happen when a line wraps
+                // on a space.
+                // In that case, we can assume the cursor is at the
+                // end of the previous line.
+                // i is guaranteed to be > 0 because `start` is always
+                // 0 for the first line, so there is no way for the
+                // cursor to be before it.
+                Some((i - 1, layout[i - 1].w))
+            } else if is_cursor_before_end {
+                let offset = line
+                    .glyphs
+                    .iter()
+                    .take_while(|glyph| cursor.index > glyph.start)
+                    .map(|glyph| glyph.w)
+                    .sum();
+
+                Some((i, offset))
+            } else {
+                None
+            }
+        })
+        .unwrap_or((
+            layout.len().saturating_sub(1),
+            layout.last().map(|line| line.w).unwrap_or(0.0),
+        ));
+
+    Point::new(
+        offset,
+        (visual_lines_offset + visual_line as i32) as f32 * line_height
+            - buffer.scroll().vertical,
+    )
+}
+
 fn visual_lines_offset(line: usize, buffer: &cosmic_text::Buffer) -> i32 {
     let scroll = buffer.scroll();
 
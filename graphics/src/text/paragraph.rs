@@ -1,11 +1,12 @@
 //! Draw paragraphs.
 use crate::core;
 use crate::core::alignment;
-use crate::core::text::{Hit, Shaping, Span, Text, Wrapping};
-use crate::core::{Font, Point, Rectangle, Size};
+use crate::core::text::{Ellipsize, Hit, Shaping, Span, Text, Wrapping};
+use crate::core::{Color, Font, Point, Rectangle, Size};
 use crate::text;
 
 use std::fmt;
+use std::ops::Range;
 use std::sync::{self, Arc};
 
 /// A bunch of text.
@@ -18,13 +19,37 @@ struct Internal {
     font: Font,
     shaping: Shaping,
     wrapping: Wrapping,
+    ellipsize: Ellipsize,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
     bounds: Size,
     min_bounds: Size,
+    spans: Vec<SpanDecorations>,
+    decorations: Vec<Decoration>,
     version: text::Version,
 }
 
+/// A line decorating a [`Paragraph`]; like an underline or a strikethrough.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Decoration {
+    /// The bounds of the line.
+    pub bounds: Rectangle,
+    /// The [`Color`] of the line, if different from the color of the
+    /// [`Paragraph`].
+    pub color: Option<Color>,
+    /// Whether the line crosses the glyphs and must be drawn on top of
+    /// the text or not.
+    pub is_overlay: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+struct SpanDecorations {
+    color: Option<Color>,
+    underline: Option<core::text::Decoration>,
+    strikethrough: Option<core::text::Decoration>,
+    overline: Option<core::text::Decoration>,
+}
+
 impl Paragraph {
     /// Creates a new empty [`Paragraph`].
     pub fn new() -> Self {
@@ -52,6 +77,44 @@ impl Paragraph {
         }
     }
 
+    /// Returns the [`Decoration`] lines of the [`Paragraph`] when drawn at
+    /// the given position, clipped by the given bounds.
+    pub fn decorations(
+        &self,
+        position: Point,
+        clip_bounds: Rectangle,
+    ) -> impl Iterator<Item = Decoration> + '_ {
+        let paragraph = self.internal();
+
+        let x = match paragraph.horizontal_alignment {
+            alignment::Horizontal::Left => position.x,
+            alignment::Horizontal::Center => {
+                position.x - paragraph.min_bounds.width / 2.0
+            }
+            alignment::Horizontal::Right => {
+                position.x - paragraph.min_bounds.width
+            }
+        };
+
+        let y = match paragraph.vertical_alignment {
+            alignment::Vertical::Top => position.y,
+            alignment::Vertical::Center => {
+                position.y - paragraph.min_bounds.height / 2.0
+            }
+            alignment::Vertical::Bottom => {
+                position.y - paragraph.min_bounds.height
+            }
+        };
+
+        paragraph.decorations.iter().filter_map(move |decoration| {
+            Some(Decoration {
+                bounds: (decoration.bounds + core::Vector::new(x, y))
+                    .intersection(&clip_bounds)?,
+                ..*decoration
+            })
+        })
+    }
+
     fn internal(&self) -> &Arc<Internal> {
         &self.0
     }
@@ -82,6 +145,11 @@ impl core::text::Paragraph for Paragraph {
 
         buffer.set_wrap(font_system.raw(), text::to_wrap(text.wrapping));
 
+        buffer.set_ellipsize(
+            font_system.raw(),
+            text::to_ellipsize(text.ellipsize, text.bounds.height),
+        );
+
         buffer.set_text(
             font_system.raw(),
             text.content,
@@ -99,8 +167,11 @@ impl core::text::Paragraph for Paragraph {
             vertical_alignment: text.vertical_alignment,
             shaping: text.shaping,
             wrapping: text.wrapping,
+            ellipsize: text.ellipsize,
             bounds: text.bounds,
             min_bounds,
+            spans: Vec::new(),
+            decorations: Vec::new(),
             version: font_system.version(),
         }))
     }
@@ -125,6 +196,11 @@ impl core::text::Paragraph for Paragraph {
             Some(text.bounds.height),
         );
 
+        buffer.set_ellipsize(
+            font_system.raw(),
+            text::to_ellipsize(text.ellipsize, text.bounds.height),
+        );
+
         buffer.set_rich_text(
             font_system.raw(),
             text.content.iter().enumerate().map(|(i, span)| {
@@ -164,6 +240,22 @@ impl core::text::Paragraph for Paragraph {
 
         let min_bounds = text::measure(&buffer);
 
+        let spans: Vec<_> = if text.content.iter().any(Span::is_decorated) {
+            text.content
+                .iter()
+                .map(|span| SpanDecorations {
+                    color: span.color,
+                    underline: span.underline,
+                    strikethrough: span.strikethrough,
+                    overline: span.overline,
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let decorations = decorate(font_system.raw(), &buffer, &spans);
+
         Self(Arc::new(Internal {
             buffer,
             font: text.font,
@@ -171,8 +263,11 @@ impl core::text::Paragraph for Paragraph {
             vertical_alignment: text.vertical_alignment,
             shaping: text.shaping,
             wrapping: text.wrapping,
+            ellipsize: text.ellipsize,
             bounds: text.bounds,
             min_bounds,
+            spans,
+            decorations,
             version: font_system.version(),
         }))
     }
@@ -189,8 +284,15 @@ impl core::text::Paragraph for Paragraph {
             Some(new_bounds.height),
         );
 
+        paragraph.buffer.set_ellipsize(
+            font_system.raw(),
+            text::to_ellipsize(paragraph.ellipsize, new_bounds.height),
+        );
+
         paragraph.bounds = new_bounds;
         paragraph.min_bounds = text::measure(&paragraph.buffer);
+        paragraph.decorations =
+            decorate(font_system.raw(), &paragraph.buffer, &paragraph.spans);
     }
 
     fn compare(&self, text: Text<()>) -> core::text::Difference {
@@ -204,6 +306,7 @@ impl core::text::Paragraph for Paragraph {
             || paragraph.font != text.font
             || paragraph.shaping != text.shaping
             || paragraph.wrapping != text.wrapping
+            || paragraph.ellipsize != text.ellipsize
             || paragraph.horizontal_alignment != text.horizontal_alignment
             || paragraph.vertical_alignment != text.vertical_alignment
         {
@@ -228,9 +331,10 @@ impl core::text::Paragraph for Paragraph {
     }
 
     fn hit_test(&self, point: Point) -> Option<Hit> {
-        let cursor = self.internal().buffer.hit(point.x, point.y)?;
+        let buffer = &self.internal().buffer;
+        let cursor = buffer.hit(point.x, point.y)?;
 
-        Some(Hit::CharOffset(cursor.index))
+        Some(Hit::CharOffset(to_offset(buffer, cursor)))
     }
 
     fn hit_span(&self, point: Point) -> Option<usize> {
@@ -314,6 +418,27 @@ impl core::text::Paragraph for Paragraph {
         bounds
     }
 
+    fn range_bounds(&self, range: Range<usize>) -> Vec<Rectangle> {
+        let buffer = &self.internal().buffer;
+
+        let start = to_cursor(buffer, range.start);
+        let end = to_cursor(buffer, range.end);
+
+        buffer
+            .layout_runs()
+            .filter_map(|run| {
+                let (x, width) = run.highlight(start, end)?;
+
+                Some(Rectangle {
+                    x,
+                    y: run.line_top,
+                    width,
+                    height: run.line_height,
+                })
+            })
+            .collect()
+    }
+
     fn grapheme_position(&self, line: usize, index: usize) -> Option<Point> {
         use unicode_segmentation::UnicodeSegmentation;
 
@@ -382,11 +507,13 @@ impl PartialEq for Internal {
     fn eq(&self, other: &Self) -> bool {
         self.font == other.font
             && self.shaping == other.shaping
+            && self.ellipsize == other.ellipsize
             && self.horizontal_alignment == other.horizontal_alignment
             && self.vertical_alignment == other.vertical_alignment
             && self.bounds == other.bounds
             && self.min_bounds == other.min_bounds
             && self.buffer.metrics() == other.buffer.metrics()
+            && self.decorations == other.decorations
     }
 }
 
@@ -400,15 +527,155 @@ impl Default for Internal {
             font: Font::default(),
             shaping: Shaping::default(),
             wrapping: Wrapping::default(),
+            ellipsize: Ellipsize::default(),
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
             bounds: Size::ZERO,
             min_bounds: Size::ZERO,
+            spans: Vec::new(),
+            decorations: Vec::new(),
             version: text::Version::default(),
         }
     }
 }
 
+/// Returns the byte offset of the given cursor in the whole text of the
+/// buffer.
+fn to_offset(
+    buffer: &cosmic_text::Buffer,
+    cursor: cosmic_text::Cursor,
+) -> usize {
+    buffer
+        .lines
+        .iter()
+        .take(cursor.line)
+        .map(|line| line.text().len() + line.ending().as_str().len())
+        .sum::<usize>()
+        + cursor.index
+}
+
+/// Returns the cursor of the given byte offset in the whole text of the
+/// buffer.
+fn to_cursor(
+    buffer: &cosmic_text::Buffer,
+    offset: usize,
+) -> cosmic_text::Cursor {
+    let mut offset = offset;
+
+    for (i, line) in buffer.lines.iter().enumerate() {
+        let length = line.text().len();
+
+        if offset <= length {
+            return cosmic_text::Cursor::new(i, offset);
+        }
+
+        offset = offset.saturating_sub(length + line.ending().as_str().len());
+    }
+
+    buffer
+        .lines
+        .last()
+        .map_or_else(cosmic_text::Cursor::default, |line| {
+            cosmic_text::Cursor::new(buffer.lines.len() - 1, line.text().len())
+        })
+}
+
+/// Computes the [`Decoration`] lines of the given buffer using the metrics
+/// of the fonts of its glyph runs.
+fn decorate(
+    font_system: &mut cosmic_text::FontSystem,
+    buffer: &cosmic_text::Buffer,
+    spans: &[SpanDecorations],
+) -> Vec<Decoration> {
+    let mut decorations = Vec::new();
+
+    if spans.is_empty() {
+        return decorations;
+    }
+
+    for run in buffer.layout_runs() {
+        let mut glyphs = run.glyphs.iter().peekable();
+
+        while let Some(first) = glyphs.next() {
+            let mut start = first.x;
+            let mut end = first.x + first.w;
+
+            while let Some(glyph) =
+                glyphs.next_if(|glyph| glyph.metadata == first.metadata)
+            {
+                start = start.min(glyph.x);
+                end = end.max(glyph.x + glyph.w);
+            }
+
+            let Some(span) = spans.get(first.metadata) else {
+                continue;
+            };
+
+            if span.underline.is_none()
+                && span.strikethrough.is_none()
+                && span.overline.is_none()
+            {
+                continue;
+            }
+
+            let size = first.font_size;
+            let baseline = run.line_y + first.y;
+
+            let metrics = font_system
+                .get_font(first.font_id)
+                .map(|font| font.as_swash().metrics(&[]).scale(size))
+                .filter(|metrics| metrics.stroke_size > 0.0);
+
+            let stroke_size =
+                metrics.map_or(size / 14.0, |metrics| metrics.stroke_size);
+
+            let mut push = |decoration: Option<core::text::Decoration>,
+                            offset: f32,
+                            is_overlay: bool| {
+                let Some(decoration) = decoration else {
+                    return;
+                };
+
+                let thickness = decoration
+                    .thickness
+                    .map_or(stroke_size, |thickness| thickness.0)
+                    .max(1.0);
+
+                decorations.push(Decoration {
+                    bounds: Rectangle {
+                        x: start,
+                        y: baseline - offset,
+                        width: end - start,
+                        height: thickness,
+                    },
+                    color: decoration.color.or(span.color),
+                    is_overlay,
+                });
+            };
+
+            push(
+                span.underline,
+                metrics.map_or(-size * 0.1, |metrics| metrics.underline_offset),
+                false,
+            );
+
+            push(
+                span.overline,
+                metrics.map_or(size * 0.8, |metrics| metrics.ascent),
+                true,
+            );
+
+            push(
+                span.strikethrough,
+                metrics.map_or(size * 0.3, |metrics| metrics.strikeout_offset),
+                true,
+            );
+        }
+    }
+
+    decorations
+}
+
 /// A weak reference to a [`Paragraph`].
 #[derive(Debug, Clone)]
 pub struct Weak {
@@ -436,3 +703,49 @@ impl PartialEq for Weak {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::text::{EllipsizeHeightLimit, LineHeight, Paragraph as _};
+    use crate::core::Pixels;
+
+    const CONTENT: &str = "A sentence far too long to fit in its bounds";
+
+    fn paragraph(ellipsize: Ellipsize) -> Paragraph {
+        Paragraph::with_text(Text {
+            content: CONTENT,
+            bounds: Size::new(80.0, f32::INFINITY),
+            size: Pixels(16.0),
+            line_height: LineHeight::default(),
+            font: Font::default(),
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: Shaping::Advanced,
+            wrapping: Wrapping::Word,
+            ellipsize,
+        })
+    }
+
+    #[test]
+    fn ellipsized_paragraphs_are_truncated() {
+        let wrapped = paragraph(Ellipsize::None);
+        let ellipsized =
+            paragraph(Ellipsize::End(EllipsizeHeightLimit::Lines(1)));
+
+        assert!(wrapped.buffer().layout_runs().count() > 1);
+        assert_eq!(ellipsized.buffer().layout_runs().count(), 1);
+
+        assert!(ellipsized.min_bounds().width <= 80.0);
+        assert!(ellipsized.min_bounds().height < wrapped.min_bounds().height);
+
+        let glyphs: usize = ellipsized
+            .buffer()
+            .layout_runs()
+            .map(|run| run.glyphs.len())
+            .sum();
+
+        assert!(glyphs < CONTENT.chars().count());
+    }
+}
@@ -53,6 +53,18 @@ where
         delegate!(self, renderer, renderer.start_layer(bounds));
     }
 
+    fn start_rounded_layer(
+        &mut self,
+        bounds: Rectangle,
+        border_radius: core::border::Radius,
+    ) {
+        delegate!(
+            self,
+            renderer,
+            renderer.start_rounded_layer(bounds, border_radius)
+        );
+    }
+
     fn end_layer(&mut self) {
         delegate!(self, renderer, renderer.end_layer());
     }
@@ -375,6 +387,18 @@ where
         }
     }
 
+    fn stats(&self, renderer: &Self::Renderer) -> renderer::Stats {
+        match (self, renderer) {
+            (Self::Primary(compositor), Renderer::Primary(renderer)) => {
+                compositor.stats(renderer)
+            }
+            (Self::Secondary(compositor), Renderer::Secondary(renderer)) => {
+                compositor.stats(renderer)
+            }
+            _ => unreachable!(),
+        }
+    }
+
     fn screenshot<T: AsRef<str>>(
         &mut self,
         renderer: &mut Self::Renderer,
@@ -598,6 +622,17 @@ mod geometry {
             }
         }
 
+        fn draft_with_mask(&mut self, mask: &Path) -> Self {
+            match self {
+                Self::Primary(frame) => {
+                    Self::Primary(frame.draft_with_mask(mask))
+                }
+                Self::Secondary(frame) => {
+                    Self::Secondary(frame.draft_with_mask(mask))
+                }
+            }
+        }
+
         fn paste(&mut self, frame: Self) {
             match (self, frame) {
                 (Self::Primary(target), Self::Primary(source)) => {
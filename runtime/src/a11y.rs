@@ -0,0 +1,182 @@
+//! Inspect the accessibility tree of a user interface without a window.
+//!
+//! An [`Inspector`] lays out a user interface headlessly, dumps its
+//! accessibility tree as readable text, and dispatches accessibility
+//! actions to it; which makes it useful to test the accessibility of
+//! custom widgets.
+//!
+//! # Example
+//! ```no_run
+//! # #[derive(Debug, Clone, PartialEq)]
+//! # pub enum Message { Increment }
+//! #
+//! # pub struct Counter;
+//! #
+//! # impl Counter {
+//! #     pub fn view(&self) -> iced_core::Element<Message, (), ()> { unimplemented!() }
+//! # }
+//! use iced_accessibility::accesskit::{Action, Role};
+//! use iced_runtime::a11y::Inspector;
+//! use iced_runtime::core::Size;
+//!
+//! let counter = Counter;
+//! let mut inspector =
+//!     Inspector::new(counter.view(), Size::new(800.0, 600.0), ());
+//!
+//! println!("{inspector}");
+//!
+//! let _ = inspector.assert_node(Role::Button, "Increment");
+//!
+//! assert_eq!(
+//!     inspector.perform(Role::Button, "Increment", Action::Default),
+//!     vec![Message::Increment]
+//! );
+//! ```
+use crate::core::clipboard;
+use crate::core::mouse;
+use crate::core::widget;
+use crate::core::{Element, Event, Size};
+use crate::user_interface::{self, UserInterface};
+
+use iced_accessibility::accesskit::{Action, ActionData, ActionRequest, Role};
+use iced_accessibility::{A11yNode, A11yTree};
+
+use std::fmt;
+
+/// A [`UserInterface`] laid out without a window, ready to be inspected.
+#[allow(missing_debug_implementations)]
+pub struct Inspector<'a, Message, Theme, Renderer> {
+    interface: UserInterface<'a, Message, Theme, Renderer>,
+    renderer: Renderer,
+}
+
+impl<'a, Message, Theme, Renderer> Inspector<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    /// Lays out the given root [`Element`] within the given bounds.
+    pub fn new(
+        root: impl Into<Element<'a, Message, Theme, Renderer>>,
+        bounds: Size,
+        renderer: Renderer,
+    ) -> Self {
+        Self::with_cache(root, bounds, user_interface::Cache::new(), renderer)
+    }
+
+    /// Lays out the given root [`Element`] within the given bounds, reusing
+    /// the widget state of a previous [`Inspector`].
+    ///
+    /// This can be used to inspect a user interface again after updating
+    /// the application with the messages produced by [`Inspector::perform`].
+    pub fn with_cache(
+        root: impl Into<Element<'a, Message, Theme, Renderer>>,
+        bounds: Size,
+        cache: user_interface::Cache,
+        mut renderer: Renderer,
+    ) -> Self {
+        let interface =
+            UserInterface::build(root, bounds, cache, &mut renderer);
+
+        Self {
+            interface,
+            renderer,
+        }
+    }
+
+    /// Extracts the widget state of the [`Inspector`].
+    pub fn into_cache(self) -> user_interface::Cache {
+        self.interface.into_cache()
+    }
+
+    /// Returns the accessibility tree of the user interface.
+    pub fn tree(&self) -> A11yTree {
+        self.interface.a11y_nodes(mouse::Cursor::Unavailable)
+    }
+
+    /// Finds the node with the given role and name, if any.
+    pub fn find(&self, role: Role, name: &str) -> Option<A11yNode> {
+        self.tree().find(role, name).cloned()
+    }
+
+    /// Returns the node with the given role and name.
+    ///
+    /// # Panics
+    /// Panics if there is no such node, printing the whole tree.
+    #[track_caller]
+    pub fn assert_node(&self, role: Role, name: &str) -> A11yNode {
+        let tree = self.tree();
+
+        match tree.find(role, name) {
+            Some(node) => node.clone(),
+            None => panic!(
+                "no {role:?} node named {name:?} in the accessibility \
+                tree:\n{tree}"
+            ),
+        }
+    }
+
+    /// Performs the given [`Action`] on the node with the given role and
+    /// name, returning the messages produced by the user interface.
+    ///
+    /// # Panics
+    /// Panics if there is no such node, printing the whole tree.
+    #[track_caller]
+    pub fn perform(
+        &mut self,
+        role: Role,
+        name: &str,
+        action: Action,
+    ) -> Vec<Message> {
+        self.perform_with(role, name, action, None)
+    }
+
+    /// Performs the given [`Action`] with some [`ActionData`] on the node
+    /// with the given role and name, returning the messages produced by the
+    /// user interface.
+    ///
+    /// # Panics
+    /// Panics if there is no such node, printing the whole tree.
+    #[track_caller]
+    pub fn perform_with(
+        &mut self,
+        role: Role,
+        name: &str,
+        action: Action,
+        data: Option<ActionData>,
+    ) -> Vec<Message> {
+        let node = self.assert_node(role, name);
+
+        self.request(ActionRequest {
+            action,
+            target: node.id().clone().into(),
+            data,
+        })
+    }
+
+    /// Dispatches the given [`ActionRequest`] to the user interface as an
+    /// [`Event::A11y`], returning the messages it produces.
+    pub fn request(&mut self, request: ActionRequest) -> Vec<Message> {
+        let id = widget::Id::from(u128::from(request.target.0) as u64);
+        let mut messages = Vec::new();
+
+        let _ = self.interface.update(
+            &[Event::A11y(id, request)],
+            mouse::Cursor::Unavailable,
+            &mut self.renderer,
+            &mut clipboard::Null,
+            &mut messages,
+        );
+
+        messages
+    }
+}
+
+impl<'a, Message, Theme, Renderer> fmt::Display
+    for Inspector<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.tree().fmt(f)
+    }
+}
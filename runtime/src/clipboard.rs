@@ -5,6 +5,20 @@ use crate::core::clipboard::Kind;
 use crate::futures::futures::channel::oneshot;
 use crate::task::{self, Task};
 
+use std::borrow::Cow;
+
+/// The MIME types of plain text, in order of preference.
+const TEXT_MIME_TYPES: [&str; 5] = [
+    "text/plain;charset=utf-8",
+    "text/plain;charset=UTF-8",
+    "UTF8_STRING",
+    "STRING",
+    "text/plain",
+];
+
+/// The MIME type of HTML.
+const HTML_MIME_TYPE: &str = "text/html";
+
 /// A clipboard action to be performed by some [`Task`].
 ///
 /// [`Task`]: crate::Task
@@ -36,6 +50,15 @@ pub enum Action {
         // Box<dyn Fn(Option<(Vec<u8>, String)>) -> T + Send + 'static>,
         Kind,
     ),
+
+    /// List the MIME types offered by the current contents of the
+    /// clipboard.
+    ReadMimeTypes {
+        /// The clipboard target.
+        target: Kind,
+        /// The channel to send the offered MIME types.
+        channel: oneshot::Sender<Vec<String>>,
+    },
 }
 
 impl std::fmt::Debug for Action {
@@ -56,6 +79,9 @@ impl std::fmt::Debug for Action {
             Self::ReadData(_, _, target) => {
                 write!(f, "Action::ReadData({target:?})")
             }
+            Self::ReadMimeTypes { target, channel: _ } => {
+                write!(f, "Action::ReadMimeTypes({target:?})")
+            }
         }
     }
 }
@@ -139,3 +165,206 @@ pub fn write_primary_data<Message>(
         Kind::Primary,
     )))
 }
+
+/// Read the current contents of the clipboard as HTML.
+pub fn read_html() -> Task<Option<String>> {
+    read_raw(&[HTML_MIME_TYPE], Kind::Standard).map(|contents| {
+        contents.map(|(bytes, _)| String::from_utf8_lossy(&bytes).into_owned())
+    })
+}
+
+/// Write the given HTML to the clipboard, along with a plain text
+/// alternative for the applications that do not understand HTML.
+pub fn write_html<Message>(html: String, alt_text: String) -> Task<Message> {
+    write_data(Html { html, alt_text })
+}
+
+/// Write the given HTML to the primary clipboard, along with a plain text
+/// alternative for the applications that do not understand HTML.
+pub fn write_primary_html<Message>(
+    html: String,
+    alt_text: String,
+) -> Task<Message> {
+    write_primary_data(Html { html, alt_text })
+}
+
+/// List the MIME types offered by the current contents of the clipboard.
+///
+/// The contents themselves are not transferred.
+pub fn read_mime_types() -> Task<Vec<String>> {
+    task::oneshot(|channel| {
+        crate::Action::Clipboard(Action::ReadMimeTypes {
+            target: Kind::Standard,
+            channel,
+        })
+    })
+}
+
+/// List the MIME types offered by the current contents of the primary
+/// clipboard.
+///
+/// The contents themselves are not transferred.
+pub fn read_primary_mime_types() -> Task<Vec<String>> {
+    task::oneshot(|channel| {
+        crate::Action::Clipboard(Action::ReadMimeTypes {
+            target: Kind::Primary,
+            channel,
+        })
+    })
+}
+
+fn read_raw(
+    mime_types: &[&str],
+    target: Kind,
+) -> Task<Option<(Vec<u8>, String)>> {
+    let mime_types = mime_types.iter().copied().map(str::to_owned).collect();
+
+    task::oneshot(|channel| {
+        crate::Action::Clipboard(Action::ReadData(mime_types, channel, target))
+    })
+}
+
+/// Some HTML with a plain text alternative.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Html {
+    html: String,
+    alt_text: String,
+}
+
+impl AsMimeTypes for Html {
+    fn available(&self) -> Cow<'static, [String]> {
+        std::iter::once(HTML_MIME_TYPE)
+            .chain(TEXT_MIME_TYPES)
+            .map(str::to_owned)
+            .collect()
+    }
+
+    fn as_bytes(&self, mime_type: &str) -> Option<Cow<'static, [u8]>> {
+        let contents = if mime_type == HTML_MIME_TYPE {
+            &self.html
+        } else {
+            &self.alt_text
+        };
+
+        Some(Cow::Owned(contents.clone().into_bytes()))
+    }
+}
+
+#[cfg(feature = "image")]
+pub use self::image::{
+    read_image, read_primary_image, write_image, write_primary_image, Image,
+};
+
+#[cfg(feature = "image")]
+mod image {
+    use super::{read_raw, write_data, write_primary_data, Kind};
+    use crate::core;
+    use crate::task::Task;
+
+    use ::image::ImageEncoder;
+    use bytes::Bytes;
+    use window_clipboard::mime::AsMimeTypes;
+
+    use std::borrow::Cow;
+    use std::fmt;
+
+    /// The MIME types of the images that can be read.
+    ///
+    /// Only the PNG decoder of `image` is enabled.
+    const MIME_TYPES: [&str; 1] = ["image/png"];
+
+    /// An image in the clipboard.
+    ///
+    /// The `pixels` of an [`Image`] are always ordered as `RGBA`.
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct Image {
+        /// The width of the [`Image`], in pixels.
+        pub width: u32,
+        /// The height of the [`Image`], in pixels.
+        pub height: u32,
+        /// The `RGBA` pixels of the [`Image`].
+        pub pixels: Bytes,
+    }
+
+    impl Image {
+        /// Creates a new [`Image`] with the given size and `RGBA` pixels.
+        pub fn new(width: u32, height: u32, pixels: impl Into<Bytes>) -> Self {
+            Self {
+                width,
+                height,
+                pixels: pixels.into(),
+            }
+        }
+
+        fn decode(bytes: &[u8]) -> Option<Self> {
+            let image = ::image::load_from_memory(bytes).ok()?.into_rgba8();
+
+            Some(Self::new(image.width(), image.height(), image.into_raw()))
+        }
+    }
+
+    impl fmt::Debug for Image {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("Image")
+                .field("width", &self.width)
+                .field("height", &self.height)
+                .field("pixels", &self.pixels.len())
+                .finish()
+        }
+    }
+
+    impl From<Image> for core::image::Handle {
+        fn from(image: Image) -> Self {
+            core::image::Handle::from_rgba(
+                image.width,
+                image.height,
+                image.pixels,
+            )
+        }
+    }
+
+    impl AsMimeTypes for Image {
+        fn available(&self) -> Cow<'static, [String]> {
+            Cow::Owned(vec![MIME_TYPES[0].to_owned()])
+        }
+
+        fn as_bytes(&self, _mime_type: &str) -> Option<Cow<'static, [u8]>> {
+            let mut png = Vec::new();
+
+            ::image::codecs::png::PngEncoder::new(&mut png)
+                .write_image(
+                    &self.pixels,
+                    self.width,
+                    self.height,
+                    ::image::ExtendedColorType::Rgba8,
+                )
+                .ok()?;
+
+            Some(Cow::Owned(png))
+        }
+    }
+
+    /// Read the current contents of the clipboard as an [`Image`].
+    pub fn read_image() -> Task<Option<Image>> {
+        read_raw(&MIME_TYPES, Kind::Standard).map(|contents| {
+            contents.and_then(|(bytes, _)| Image::decode(&bytes))
+        })
+    }
+
+    /// Read the current contents of the primary clipboard as an [`Image`].
+    pub fn read_primary_image() -> Task<Option<Image>> {
+        read_raw(&MIME_TYPES, Kind::Primary).map(|contents| {
+            contents.and_then(|(bytes, _)| Image::decode(&bytes))
+        })
+    }
+
+    /// Write the given [`Image`] to the clipboard, encoded as PNG.
+    pub fn write_image<Message>(image: Image) -> Task<Message> {
+        write_data(image)
+    }
+
+    /// Write the given [`Image`] to the primary clipboard, encoded as PNG.
+    pub fn write_primary_image<Message>(image: Image) -> Task<Message> {
+        write_primary_data(image)
+    }
+}
@@ -1,12 +1,28 @@
 #![allow(missing_docs)]
+mod trace;
+
+use crate::core::alignment;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text;
 use crate::core::time;
+use crate::core::widget::inspect;
+use crate::core::window;
+use crate::core::{
+    Border, Color, Length, Padding, Pixels, Point, Rectangle, Size,
+};
+use crate::{Action, Trace};
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
 
-use std::collections::VecDeque;
+use trace::{Phase, Tracer};
 
 /// A bunch of time measurements for debugging purposes.
 #[derive(Debug)]
 pub struct Debug {
     is_enabled: bool,
+    is_outlining: bool,
 
     startup_start: time::Instant,
     startup_duration: time::Duration,
@@ -31,6 +47,9 @@ pub struct Debug {
 
     message_count: usize,
     last_messages: VecDeque<String>,
+
+    tracer: Option<Tracer>,
+    subscriptions: HashMap<u64, String>,
 }
 
 impl Debug {
@@ -40,6 +59,7 @@ impl Debug {
 
         Self {
             is_enabled: false,
+            is_outlining: false,
             startup_start: now,
             startup_duration: time::Duration::from_secs(0),
 
@@ -63,67 +83,103 @@ impl Debug {
 
             message_count: 0,
             last_messages: VecDeque::new(),
+
+            tracer: None,
+            subscriptions: HashMap::new(),
         }
     }
 
+    /// Starts recording a trace to the given [`Trace`] destination.
+    pub fn trace(&mut self, trace: &Trace) {
+        self.tracer = Tracer::new(trace);
+        self.subscriptions.clear();
+    }
+
     pub fn toggle(&mut self) {
         self.is_enabled = !self.is_enabled;
     }
 
+    pub fn toggle_outlines(&mut self) {
+        self.is_outlining = !self.is_outlining;
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.is_enabled
+    }
+
     pub fn startup_started(&mut self) {
         self.startup_start = time::Instant::now();
+        self.begin(Phase::Startup);
     }
 
     pub fn startup_finished(&mut self) {
         self.startup_duration = self.startup_start.elapsed();
+        self.end(Phase::Startup, self.startup_start);
     }
 
     pub fn update_started(&mut self) {
         self.update_start = time::Instant::now();
+        self.begin(Phase::Update);
     }
 
     pub fn update_finished(&mut self) {
         self.update_durations.push(self.update_start.elapsed());
+
+        let message = self.last_messages.back().map(String::as_str);
+
+        if let Some(tracer) = &mut self.tracer {
+            tracer.end(Phase::Update, self.update_start, message);
+        }
     }
 
     pub fn view_started(&mut self) {
         self.view_start = time::Instant::now();
+        self.begin(Phase::View);
     }
 
     pub fn view_finished(&mut self) {
         self.view_durations.push(self.view_start.elapsed());
+        self.end(Phase::View, self.view_start);
     }
 
     pub fn layout_started(&mut self) {
         self.layout_start = time::Instant::now();
+        self.begin(Phase::Layout);
     }
 
     pub fn layout_finished(&mut self) {
         self.layout_durations.push(self.layout_start.elapsed());
+        self.end(Phase::Layout, self.layout_start);
     }
 
     pub fn event_processing_started(&mut self) {
         self.event_start = time::Instant::now();
+        self.begin(Phase::Event);
     }
 
     pub fn event_processing_finished(&mut self) {
         self.event_durations.push(self.event_start.elapsed());
+        self.end(Phase::Event, self.event_start);
     }
 
     pub fn draw_started(&mut self) {
         self.draw_start = time::Instant::now();
+        self.begin(Phase::Draw);
     }
 
     pub fn draw_finished(&mut self) {
         self.draw_durations.push(self.draw_start.elapsed());
+        self.end(Phase::Draw, self.draw_start);
     }
 
     pub fn render_started(&mut self) {
         self.render_start = time::Instant::now();
+        self.begin(Phase::Render);
     }
 
     pub fn render_finished(&mut self) {
         self.render_durations.push(self.render_start.elapsed());
+        self.end(Phase::Render, self.render_start);
     }
 
     pub fn log_message<Message: std::fmt::Debug>(&mut self, message: &Message) {
@@ -136,6 +192,129 @@ impl Debug {
         self.message_count += 1;
     }
 
+    /// Draws the widget inspector on top of a user interface.
+    ///
+    /// The widget under the cursor is highlighted, and the inspected tree
+    /// is listed in a panel along the right edge of the viewport. When
+    /// outlines are toggled, the bounds of every widget are outlined too.
+    pub fn draw_inspector<Renderer>(
+        &self,
+        root: &inspect::Node,
+        cursor: mouse::Cursor,
+        renderer: &mut Renderer,
+        viewport: Size,
+    ) where
+        Renderer: text::Renderer,
+    {
+        if !self.is_enabled {
+            return;
+        }
+
+        let viewport = Rectangle::with_size(viewport);
+        let path = cursor
+            .position()
+            .map(|position| root.path_to(position))
+            .unwrap_or_default();
+
+        renderer.with_layer(viewport, |renderer| {
+            if self.is_outlining {
+                root.visit(&mut |node, _depth| {
+                    outline(renderer, node.bounds, OUTLINE_COLOR);
+                });
+            }
+
+            if let Some(hovered) = path.last() {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: hovered.bounds,
+                        border: Border {
+                            color: HIGHLIGHT_COLOR,
+                            width: 1.0,
+                            ..Border::default()
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    Color {
+                        a: 0.2,
+                        ..HIGHLIGHT_COLOR
+                    },
+                );
+
+                if hovered.padding != Padding::ZERO {
+                    outline(
+                        renderer,
+                        hovered.bounds.shrink(hovered.padding),
+                        PADDING_COLOR,
+                    );
+                }
+            }
+
+            draw_tree(renderer, root, &path, viewport);
+        });
+    }
+
+    /// Logs a task returned by the last update, linking it to the message
+    /// that produced it.
+    pub fn log_task(&mut self) {
+        if let Some(tracer) = &mut self.tracer {
+            tracer.record(trace::Event::Task {
+                message: self.last_messages.back().map(String::as_str),
+            });
+        }
+    }
+
+    pub fn log_action<T: fmt::Debug>(&mut self, action: &Action<T>) {
+        if let Some(tracer) = &mut self.tracer {
+            tracer.record(trace::Event::Action(&format!("{action:?}")));
+        }
+    }
+
+    pub fn log_subscriptions<'a>(
+        &mut self,
+        alive: impl IntoIterator<Item = (u64, &'a str)>,
+    ) {
+        let Some(tracer) = &mut self.tracer else {
+            return;
+        };
+
+        let alive: HashMap<u64, &str> = alive.into_iter().collect();
+
+        for (&id, &name) in &alive {
+            if !self.subscriptions.contains_key(&id) {
+                tracer.record(trace::Event::SubscriptionStarted { id, name });
+            }
+        }
+
+        for (&id, name) in &self.subscriptions {
+            if !alive.contains_key(&id) {
+                tracer.record(trace::Event::SubscriptionStopped { id, name });
+            }
+        }
+
+        self.subscriptions = alive
+            .into_iter()
+            .map(|(id, name)| (id, name.to_owned()))
+            .collect();
+    }
+
+    pub fn log_redraw(&mut self, window: window::Id, reason: &str) {
+        if let Some(tracer) = &mut self.tracer {
+            tracer.record(trace::Event::Redraw { window, reason });
+        }
+    }
+
+    fn begin(&mut self, phase: Phase) {
+        if let Some(tracer) = &mut self.tracer {
+            tracer.begin(phase);
+        }
+    }
+
+    fn end(&mut self, phase: Phase, start: time::Instant) {
+        if let Some(tracer) = &mut self.tracer {
+            tracer.end(phase, start, None);
+        }
+    }
+
     pub fn overlay(&self) -> Vec<String> {
         if !self.is_enabled {
             return Vec::new();
@@ -180,6 +359,157 @@ impl Debug {
     }
 }
 
+const OUTLINE_COLOR: Color = Color::from_rgba(1.0, 0.0, 1.0, 0.6);
+const HIGHLIGHT_COLOR: Color = Color::from_rgb(0.2, 0.5, 1.0);
+const PADDING_COLOR: Color = Color::from_rgb(0.2, 0.8, 0.3);
+
+const PANEL_WIDTH: f32 = 480.0;
+const LINE_HEIGHT: f32 = 16.0;
+const TEXT_SIZE: f32 = 12.0;
+
+fn outline<Renderer: renderer::Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    color: Color,
+) {
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border: Border {
+                color,
+                width: 1.0,
+                ..Border::default()
+            },
+            ..renderer::Quad::default()
+        },
+        Color::TRANSPARENT,
+    );
+}
+
+fn draw_tree<Renderer: text::Renderer>(
+    renderer: &mut Renderer,
+    root: &inspect::Node,
+    path: &[&inspect::Node],
+    viewport: Rectangle,
+) {
+    let mut lines = Vec::new();
+
+    root.visit(&mut |node, depth| {
+        let is_hovered =
+            path.iter().any(|hovered| std::ptr::eq(*hovered, node));
+
+        lines.push((describe(node, depth), is_hovered));
+    });
+
+    let width = PANEL_WIDTH.min(viewport.width / 2.0);
+    let visible = ((viewport.height / LINE_HEIGHT) as usize).max(1);
+
+    // Keep the hovered widget in view when the tree does not fit
+    let hovered = lines
+        .iter()
+        .rposition(|(_, is_hovered)| *is_hovered)
+        .unwrap_or_default();
+    let start = hovered
+        .saturating_sub(visible / 2)
+        .min(lines.len().saturating_sub(visible));
+
+    let panel = Rectangle {
+        x: viewport.width - width,
+        y: 0.0,
+        width,
+        height: viewport.height,
+    };
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: panel,
+            ..renderer::Quad::default()
+        },
+        Color::from_rgba(0.0, 0.0, 0.0, 0.75),
+    );
+
+    for (i, (line, is_hovered)) in
+        lines.into_iter().skip(start).take(visible).enumerate()
+    {
+        let bounds = Rectangle {
+            y: i as f32 * LINE_HEIGHT,
+            height: LINE_HEIGHT,
+            ..panel
+        };
+
+        if is_hovered {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    ..renderer::Quad::default()
+                },
+                Color {
+                    a: 0.4,
+                    ..HIGHLIGHT_COLOR
+                },
+            );
+        }
+
+        renderer.fill_text(
+            text::Text {
+                content: line,
+                bounds: bounds.size(),
+                size: Pixels(TEXT_SIZE),
+                line_height: text::LineHeight::Absolute(Pixels(LINE_HEIGHT)),
+                font: renderer.default_font(),
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Center,
+                shaping: text::Shaping::Basic,
+                wrapping: text::Wrapping::None,
+                ellipsize: text::Ellipsize::None,
+            },
+            Point::new(bounds.x + 4.0, bounds.center_y()),
+            Color::WHITE,
+            panel,
+        );
+    }
+}
+
+fn describe(node: &inspect::Node, depth: usize) -> String {
+    fn length(length: Length) -> String {
+        match length {
+            Length::Fill => String::from("fill"),
+            Length::FillPortion(portion) => format!("fill({portion})"),
+            Length::Shrink => String::from("shrink"),
+            Length::Fixed(amount) => format!("{amount}"),
+        }
+    }
+
+    let mut line = format!("{:indent$}{}", "", node.name, indent = depth * 2);
+
+    if let Some(id) = &node.id {
+        line.push_str(&format!(" {id:?}"));
+    }
+
+    line.push_str(&format!(
+        " {}x{} @ ({}, {}) {}x{}",
+        length(node.size.width),
+        length(node.size.height),
+        node.bounds.x,
+        node.bounds.y,
+        node.bounds.width,
+        node.bounds.height,
+    ));
+
+    let Padding {
+        top,
+        right,
+        bottom,
+        left,
+    } = node.padding;
+
+    if node.padding != Padding::ZERO {
+        line.push_str(&format!(" pad [{top}, {right}, {bottom}, {left}]"));
+    }
+
+    line
+}
+
 impl Default for Debug {
     fn default() -> Self {
         Self::new()
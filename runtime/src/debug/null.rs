@@ -1,4 +1,11 @@
 #![allow(missing_docs)]
+use crate::core::mouse;
+use crate::core::text;
+use crate::core::widget::inspect;
+use crate::core::window;
+use crate::core::Size;
+use crate::{Action, Trace};
+
 #[derive(Debug, Default)]
 pub struct Debug;
 
@@ -7,6 +14,8 @@ impl Debug {
         Self
     }
 
+    pub fn trace(&mut self, _trace: &Trace) {}
+
     pub fn startup_started(&mut self) {}
 
     pub fn startup_finished(&mut self) {}
@@ -41,6 +50,33 @@ impl Debug {
     ) {
     }
 
+    pub fn log_task(&mut self) {}
+
+    pub fn log_action<T: std::fmt::Debug>(&mut self, _action: &Action<T>) {}
+
+    pub fn log_subscriptions<'a>(
+        &mut self,
+        _alive: impl IntoIterator<Item = (u64, &'a str)>,
+    ) {
+    }
+
+    pub fn log_redraw(&mut self, _window: window::Id, _reason: &str) {}
+
+    pub fn is_enabled(&self) -> bool {
+        false
+    }
+
+    pub fn draw_inspector<Renderer>(
+        &self,
+        _root: &inspect::Node,
+        _cursor: mouse::Cursor,
+        _renderer: &mut Renderer,
+        _viewport: Size,
+    ) where
+        Renderer: text::Renderer,
+    {
+    }
+
     pub fn overlay(&self) -> Vec<String> {
         Vec::new()
     }
@@ -0,0 +1,329 @@
+//! Record the traces of the runtime of an application.
+//!
+//! Tracing is opt-in; see [`Trace`] to learn how to enable it.
+use crate::core::time::{Duration, Instant};
+use crate::core::window;
+use crate::Trace;
+
+use std::fmt::Write as _;
+use std::fs;
+use std::io::{self, Write as _};
+use std::path::Path;
+
+/// The target of the [`tracing`] spans and events of a [`Tracer`].
+const TARGET: &str = "iced::trace";
+
+/// A measured phase of the runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Startup,
+    Update,
+    View,
+    Layout,
+    Event,
+    Draw,
+    Render,
+}
+
+impl Phase {
+    fn name(self) -> &'static str {
+        match self {
+            Phase::Startup => "startup",
+            Phase::Update => "update",
+            Phase::View => "view",
+            Phase::Layout => "layout",
+            Phase::Event => "event",
+            Phase::Draw => "draw",
+            Phase::Render => "render",
+        }
+    }
+}
+
+/// Something that happened at some instant in the runtime.
+#[derive(Debug, Clone, Copy)]
+pub enum Event<'a> {
+    /// A task was returned by `update`, while processing the given message.
+    Task { message: Option<&'a str> },
+    /// An action produced by a task was run.
+    Action(&'a str),
+    /// A subscription was started.
+    SubscriptionStarted { id: u64, name: &'a str },
+    /// A subscription was stopped.
+    SubscriptionStopped { id: u64, name: &'a str },
+    /// A window redraw was requested.
+    Redraw { window: window::Id, reason: &'a str },
+}
+
+impl Event<'_> {
+    fn name(self) -> &'static str {
+        match self {
+            Event::Task { .. } => "task",
+            Event::Action(_) => "action",
+            Event::SubscriptionStarted { .. } => "subscription started",
+            Event::SubscriptionStopped { .. } => "subscription stopped",
+            Event::Redraw { .. } => "redraw",
+        }
+    }
+
+    fn args(self) -> Vec<(&'static str, String)> {
+        match self {
+            Event::Task { message } => message
+                .map(|message| vec![("message", message.to_owned())])
+                .unwrap_or_default(),
+            Event::Action(action) => vec![("action", action.to_owned())],
+            Event::SubscriptionStarted { id, name }
+            | Event::SubscriptionStopped { id, name } => {
+                vec![("id", format!("{id:016x}")), ("name", name.to_owned())]
+            }
+            Event::Redraw { window, reason } => vec![
+                ("window", format!("{window:?}")),
+                ("reason", reason.to_owned()),
+            ],
+        }
+    }
+}
+
+/// A recorder of the phases and events of the runtime.
+#[derive(Debug)]
+pub enum Tracer {
+    /// Emits [`tracing`] spans and events.
+    Tracing { spans: Vec<(Phase, tracing::Span)> },
+    /// Writes a Chrome trace JSON file.
+    Chrome(Chrome),
+}
+
+impl Tracer {
+    /// Creates a [`Tracer`] that records to the given [`Trace`]
+    /// destination.
+    ///
+    /// It returns `None` if the destination could not be opened.
+    pub fn new(trace: &Trace) -> Option<Self> {
+        match trace {
+            Trace::Tracing => Some(Self::Tracing { spans: Vec::new() }),
+            Trace::Chrome(path) => match Chrome::create(path) {
+                Ok(chrome) => Some(Self::Chrome(chrome)),
+                Err(error) => {
+                    tracing::warn!(
+                        "Could not create trace file {path:?}: {error}"
+                    );
+
+                    None
+                }
+            },
+        }
+    }
+
+    /// Records the start of a [`Phase`].
+    pub fn begin(&mut self, phase: Phase) {
+        let Self::Tracing { spans } = self else {
+            return;
+        };
+
+        let span = match phase {
+            Phase::Startup => tracing::debug_span!(target: TARGET, "startup"),
+            Phase::Update => tracing::debug_span!(
+                target: TARGET,
+                "update",
+                message = tracing::field::Empty
+            ),
+            Phase::View => tracing::debug_span!(target: TARGET, "view"),
+            Phase::Layout => tracing::debug_span!(target: TARGET, "layout"),
+            Phase::Event => tracing::debug_span!(target: TARGET, "event"),
+            Phase::Draw => tracing::debug_span!(target: TARGET, "draw"),
+            Phase::Render => tracing::debug_span!(target: TARGET, "render"),
+        };
+
+        let _ = span.with_subscriber(|(id, dispatch)| dispatch.enter(id));
+
+        spans.push((phase, span));
+    }
+
+    /// Records the end of a [`Phase`] that began at the given [`Instant`].
+    ///
+    /// The message being processed is recorded when the [`Phase`] is an
+    /// update.
+    pub fn end(&mut self, phase: Phase, start: Instant, message: Option<&str>) {
+        match self {
+            Self::Tracing { spans } => {
+                let Some(index) =
+                    spans.iter().rposition(|(open, _)| *open == phase)
+                else {
+                    return;
+                };
+
+                let (_, span) = spans.remove(index);
+
+                if let Some(message) = message {
+                    let _ = span.record("message", message);
+                }
+
+                let _ =
+                    span.with_subscriber(|(id, dispatch)| dispatch.exit(id));
+            }
+            Self::Chrome(chrome) => {
+                let args = message
+                    .map(|message| vec![("message", message.to_owned())])
+                    .unwrap_or_default();
+
+                chrome.complete(phase.name(), start, start.elapsed(), &args);
+
+                // Flush once per frame, so the trace survives abrupt exits
+                if phase == Phase::Render {
+                    chrome.flush();
+                }
+            }
+        }
+    }
+
+    /// Records an [`Event`].
+    pub fn record(&mut self, event: Event<'_>) {
+        match self {
+            Self::Tracing { .. } => match event {
+                Event::Task { message } => {
+                    tracing::debug!(
+                        target: TARGET,
+                        produced_by = message,
+                        "task"
+                    );
+                }
+                Event::Action(action) => {
+                    tracing::debug!(target: TARGET, action, "action");
+                }
+                Event::SubscriptionStarted { id, name } => {
+                    tracing::debug!(
+                        target: TARGET,
+                        id,
+                        name,
+                        "subscription started"
+                    );
+                }
+                Event::SubscriptionStopped { id, name } => {
+                    tracing::debug!(
+                        target: TARGET,
+                        id,
+                        name,
+                        "subscription stopped"
+                    );
+                }
+                Event::Redraw { window, reason } => {
+                    tracing::debug!(
+                        target: TARGET,
+                        ?window,
+                        reason,
+                        "redraw"
+                    );
+                }
+            },
+            Self::Chrome(chrome) => {
+                chrome.instant(event.name(), &event.args());
+            }
+        }
+    }
+}
+
+/// A writer of Chrome trace JSON files.
+///
+/// The file is written in the JSON array format, whose closing bracket is
+/// optional; so the trace stays readable even if the application exits
+/// abruptly.
+#[derive(Debug)]
+pub struct Chrome {
+    file: io::BufWriter<fs::File>,
+    origin: Instant,
+    pid: u32,
+}
+
+impl Chrome {
+    fn create(path: &Path) -> io::Result<Self> {
+        let mut file = io::BufWriter::new(fs::File::create(path)?);
+        file.write_all(b"[\n")?;
+
+        Ok(Self {
+            file,
+            origin: Instant::now(),
+            pid: std::process::id(),
+        })
+    }
+
+    fn complete(
+        &mut self,
+        name: &str,
+        start: Instant,
+        duration: Duration,
+        args: &[(&str, String)],
+    ) {
+        let timestamp = start.saturating_duration_since(self.origin);
+
+        self.write(&format!(
+            r#"{{"name":{},"ph":"X","ts":{},"dur":{},"pid":{},"tid":0,"args":{}}}"#,
+            string(name),
+            timestamp.as_micros(),
+            duration.as_micros(),
+            self.pid,
+            object(args),
+        ));
+    }
+
+    fn instant(&mut self, name: &str, args: &[(&str, String)]) {
+        let timestamp = self.origin.elapsed();
+
+        self.write(&format!(
+            r#"{{"name":{},"ph":"i","s":"p","ts":{},"pid":{},"tid":0,"args":{}}}"#,
+            string(name),
+            timestamp.as_micros(),
+            self.pid,
+            object(args),
+        ));
+    }
+
+    fn write(&mut self, event: &str) {
+        if let Err(error) = writeln!(self.file, "{event},") {
+            tracing::warn!("Could not write trace event: {error}");
+        }
+    }
+
+    fn flush(&mut self) {
+        if let Err(error) = self.file.flush() {
+            tracing::warn!("Could not flush trace file: {error}");
+        }
+    }
+}
+
+impl Drop for Chrome {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+/// Encodes the given key-value pairs as a JSON object.
+fn object(pairs: &[(&str, String)]) -> String {
+    let fields: Vec<String> = pairs
+        .iter()
+        .map(|(key, value)| format!("{}:{}", string(key), string(value)))
+        .collect();
+
+    format!("{{{}}}", fields.join(","))
+}
+
+/// Encodes the given text as a JSON string.
+fn string(text: &str) -> String {
+    let mut encoded = String::with_capacity(text.len() + 2);
+    encoded.push('"');
+
+    for c in text.chars() {
+        match c {
+            '"' => encoded.push_str("\\\""),
+            '\\' => encoded.push_str("\\\\"),
+            '\n' => encoded.push_str("\\n"),
+            '\r' => encoded.push_str("\\r"),
+            '\t' => encoded.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(encoded, "\\u{:04x}", c as u32);
+            }
+            c => encoded.push(c),
+        }
+    }
+
+    encoded.push('"');
+    encoded
+}
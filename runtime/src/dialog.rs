@@ -0,0 +1,326 @@
+//! Show native file, folder and message dialogs.
+//!
+//! Dialogs are parented to a window for modality and never block the event
+//! loop. If no native backend is available, the tasks produce
+//! [`Error::Unavailable`] and a fallback built from widgets can be shown
+//! instead.
+use crate::core::window;
+use crate::futures::futures::channel::oneshot;
+use crate::task::{self, Task};
+
+use std::path::PathBuf;
+
+/// A dialog action to be performed by some [`Task`].
+#[derive(Debug)]
+pub enum Action {
+    /// Pick one or more files to open.
+    OpenFile {
+        /// The parent window of the dialog.
+        window: window::Id,
+        /// The [`FileDialog`] to show.
+        dialog: FileDialog,
+        /// The channel to send back the picked files.
+        channel: oneshot::Sender<Result<Vec<PathBuf>, Error>>,
+    },
+
+    /// Pick a file to save to.
+    SaveFile {
+        /// The parent window of the dialog.
+        window: window::Id,
+        /// The [`FileDialog`] to show.
+        dialog: FileDialog,
+        /// The channel to send back the picked file, if any.
+        channel: oneshot::Sender<Result<Option<PathBuf>, Error>>,
+    },
+
+    /// Pick a folder.
+    PickFolder {
+        /// The parent window of the dialog.
+        window: window::Id,
+        /// The [`FileDialog`] to show.
+        dialog: FileDialog,
+        /// The channel to send back the picked folder, if any.
+        channel: oneshot::Sender<Result<Option<PathBuf>, Error>>,
+    },
+
+    /// Show a message.
+    Message {
+        /// The parent window of the dialog.
+        window: window::Id,
+        /// The [`MessageDialog`] to show.
+        dialog: MessageDialog,
+        /// The channel to send back whether the message was accepted.
+        channel: oneshot::Sender<Result<bool, Error>>,
+    },
+}
+
+impl Action {
+    /// Returns the parent window of the dialog.
+    pub fn window(&self) -> window::Id {
+        match self {
+            Action::OpenFile { window, .. }
+            | Action::SaveFile { window, .. }
+            | Action::PickFolder { window, .. }
+            | Action::Message { window, .. } => *window,
+        }
+    }
+}
+
+/// An error produced by a dialog [`Task`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// No native dialog backend is available.
+    Unavailable,
+    /// The native dialog backend failed.
+    Backend(String),
+}
+
+/// The configuration of a file or folder dialog.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FileDialog {
+    /// The title of the dialog.
+    pub title: String,
+    /// The folder the dialog starts in, if any.
+    pub directory: Option<PathBuf>,
+    /// The suggested file name, when saving.
+    pub file_name: Option<String>,
+    /// The filters the user can choose from.
+    pub filters: Vec<Filter>,
+    /// Whether multiple files can be picked, when opening.
+    pub multiple: bool,
+}
+
+impl FileDialog {
+    /// Creates a new [`FileDialog`] with the given title.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the folder the [`FileDialog`] starts in.
+    pub fn directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.directory = Some(directory.into());
+        self
+    }
+
+    /// Sets the suggested file name of the [`FileDialog`].
+    pub fn file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.file_name = Some(file_name.into());
+        self
+    }
+
+    /// Adds a [`Filter`] to the [`FileDialog`].
+    pub fn filter(mut self, filter: Filter) -> Self {
+        self.filters.push(filter);
+        self
+    }
+
+    /// Sets whether multiple files can be picked with the [`FileDialog`].
+    pub fn multiple(mut self, multiple: bool) -> Self {
+        self.multiple = multiple;
+        self
+    }
+}
+
+/// A named set of glob patterns used to filter files; like `*.png`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    /// The name of the filter.
+    pub name: String,
+    /// The glob patterns of the filter.
+    pub patterns: Vec<String>,
+}
+
+impl Filter {
+    /// Creates a new [`Filter`] with the given name and glob patterns.
+    pub fn new(
+        name: impl Into<String>,
+        patterns: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            patterns: patterns.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Returns true if the given file name matches any pattern of the
+    /// [`Filter`].
+    ///
+    /// Only `*` and `?` wildcards are supported, and matching is
+    /// case-insensitive.
+    pub fn matches(&self, file_name: &str) -> bool {
+        let file_name: Vec<char> = file_name.to_lowercase().chars().collect();
+
+        self.patterns.iter().any(|pattern| {
+            let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+
+            glob(&pattern, &file_name)
+        })
+    }
+}
+
+fn glob(pattern: &[char], text: &[char]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some(('*', rest)) => {
+            (0..=text.len()).any(|skip| glob(rest, &text[skip..]))
+        }
+        Some((expected, rest)) => match text.split_first() {
+            Some((actual, text)) => {
+                (*expected == '?' || expected == actual) && glob(rest, text)
+            }
+            None => false,
+        },
+    }
+}
+
+/// The configuration of a message dialog.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MessageDialog {
+    /// The title of the dialog.
+    pub title: String,
+    /// The description of the dialog.
+    pub description: String,
+    /// The [`Level`] of the dialog.
+    pub level: Level,
+    /// The [`Buttons`] of the dialog.
+    pub buttons: Buttons,
+}
+
+impl MessageDialog {
+    /// Creates a new [`MessageDialog`] with the given title and description.
+    pub fn new(
+        title: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            description: description.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the [`Level`] of the [`MessageDialog`].
+    pub fn level(mut self, level: Level) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Sets the [`Buttons`] of the [`MessageDialog`].
+    pub fn buttons(mut self, buttons: Buttons) -> Self {
+        self.buttons = buttons;
+        self
+    }
+}
+
+/// The severity of a [`MessageDialog`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Level {
+    /// An informative message.
+    #[default]
+    Info,
+    /// A warning.
+    Warning,
+    /// An error.
+    Error,
+}
+
+/// The buttons of a [`MessageDialog`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Buttons {
+    /// A single "Ok" button.
+    #[default]
+    Ok,
+    /// An "Ok" and a "Cancel" button.
+    OkCancel,
+    /// A "Yes" and a "No" button.
+    YesNo,
+}
+
+/// Shows a dialog to pick one or more files to open, parented to the window
+/// with the given [`window::Id`].
+///
+/// The resulting list is empty if the dialog was cancelled.
+pub fn open_file(
+    window: window::Id,
+    dialog: FileDialog,
+) -> Task<Result<Vec<PathBuf>, Error>> {
+    task::oneshot(move |channel| {
+        crate::Action::Dialog(Action::OpenFile {
+            window,
+            dialog,
+            channel,
+        })
+    })
+}
+
+/// Shows a dialog to pick a file to save to, parented to the window with the
+/// given [`window::Id`].
+pub fn save_file(
+    window: window::Id,
+    dialog: FileDialog,
+) -> Task<Result<Option<PathBuf>, Error>> {
+    task::oneshot(move |channel| {
+        crate::Action::Dialog(Action::SaveFile {
+            window,
+            dialog,
+            channel,
+        })
+    })
+}
+
+/// Shows a dialog to pick a folder, parented to the window with the given
+/// [`window::Id`].
+pub fn pick_folder(
+    window: window::Id,
+    dialog: FileDialog,
+) -> Task<Result<Option<PathBuf>, Error>> {
+    task::oneshot(move |channel| {
+        crate::Action::Dialog(Action::PickFolder {
+            window,
+            dialog,
+            channel,
+        })
+    })
+}
+
+/// Shows a [`MessageDialog`] with a single "Ok" button, parented to the window
+/// with the given [`window::Id`].
+pub fn message(
+    window: window::Id,
+    dialog: MessageDialog,
+) -> Task<Result<(), Error>> {
+    show(window, dialog.buttons(Buttons::Ok)).map(|result| result.map(|_| ()))
+}
+
+/// Shows a [`MessageDialog`] asking for confirmation, parented to the window
+/// with the given [`window::Id`].
+///
+/// The result is `true` if the user accepted.
+pub fn confirm(
+    window: window::Id,
+    dialog: MessageDialog,
+) -> Task<Result<bool, Error>> {
+    let dialog = if dialog.buttons == Buttons::Ok {
+        dialog.buttons(Buttons::OkCancel)
+    } else {
+        dialog
+    };
+
+    show(window, dialog)
+}
+
+fn show(
+    window: window::Id,
+    dialog: MessageDialog,
+) -> Task<Result<bool, Error>> {
+    task::oneshot(move |channel| {
+        crate::Action::Dialog(Action::Message {
+            window,
+            dialog,
+            channel,
+        })
+    })
+}
@@ -1,16 +1,120 @@
 //! Load and use fonts.
+use crate::core::font::{FamilyInfo, Script};
+use crate::futures::futures::channel::oneshot;
 use crate::task::{self, Task};
-use crate::Action;
+
 use std::borrow::Cow;
+use std::io;
+use std::path::PathBuf;
 
 /// An error while loading a font.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Error {}
+pub enum Error {
+    /// The font file could not be read.
+    Io(io::ErrorKind),
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error.kind())
+    }
+}
+
+/// A font action to be performed by some [`Task`].
+#[derive(Debug)]
+pub enum Action {
+    /// Load all the fonts in the file at the given path.
+    LoadFile {
+        /// The path of the font file.
+        path: PathBuf,
+        /// The channel to send back the load result.
+        channel: oneshot::Sender<Result<(), Error>>,
+    },
+
+    /// List the available font families.
+    ListFamilies(oneshot::Sender<Vec<FamilyInfo>>),
+
+    /// Query whether a font family is available.
+    HasFamily {
+        /// The name of the font family.
+        name: String,
+        /// The channel to send back the result.
+        channel: oneshot::Sender<bool>,
+    },
+
+    /// Set the fallback font families of a [`Script`].
+    SetFallback {
+        /// The [`Script`] to configure.
+        script: Script,
+        /// The ordered list of fallback font families.
+        families: Vec<String>,
+    },
+}
 
 /// Load a font from its bytes.
 pub fn load(bytes: impl Into<Cow<'static, [u8]>>) -> Task<Result<(), Error>> {
-    task::oneshot(|channel| Action::LoadFont {
+    task::oneshot(|channel| crate::Action::LoadFont {
         bytes: bytes.into(),
         channel,
     })
 }
+
+/// Load all the fonts in the file at the given path.
+pub fn load_file(path: impl Into<PathBuf>) -> Task<Result<(), Error>> {
+    let path = path.into();
+
+    task::oneshot(|channel| {
+        crate::Action::Font(Action::LoadFile { path, channel })
+    })
+}
+
+/// Lists the font families available to the application, sorted by name.
+///
+/// This includes both the fonts installed in the system and the fonts
+/// loaded by the application.
+pub fn families() -> Task<Vec<FamilyInfo>> {
+    task::oneshot(|channel| crate::Action::Font(Action::ListFamilies(channel)))
+}
+
+/// Queries whether a font family with the given name is available to the
+/// application.
+///
+/// The name is compared ignoring ASCII case.
+pub fn has_family(name: impl Into<String>) -> Task<bool> {
+    let name = name.into();
+
+    task::oneshot(|channel| {
+        crate::Action::Font(Action::HasFamily { name, channel })
+    })
+}
+
+/// Sets the ordered list of font families used as fallback for the given
+/// [`Script`]; that is, when the font of some text lacks the glyphs of a
+/// character.
+///
+/// Families that are not available are skipped. An empty list restores the
+/// platform defaults.
+///
+/// Use [`Script::Common`] to configure the fallback families used for
+/// every script; like emoji fonts.
+pub fn set_fallback<T>(
+    script: Script,
+    families: impl IntoIterator<Item = impl Into<String>>,
+) -> Task<T> {
+    task::effect(crate::Action::Font(Action::SetFallback {
+        script,
+        families: families.into_iter().map(Into::into).collect(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn io_errors_keep_their_kind() {
+        let error = io::Error::new(io::ErrorKind::NotFound, "missing font");
+
+        assert_eq!(Error::from(error), Error::Io(io::ErrorKind::NotFound));
+    }
+}
@@ -9,15 +9,23 @@
     html_logo_url = "https://raw.githubusercontent.com/iced-rs/iced/9ab6923e943f784985e9ef9ca28b10278297225d/docs/logo.svg"
 )]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#[cfg(feature = "a11y")]
+pub mod a11y;
 pub mod clipboard;
+pub mod dialog;
 pub mod dnd;
 pub mod font;
 pub mod keyboard;
+pub mod notification;
 pub mod overlay;
 pub mod platform_specific;
 pub mod program;
+#[cfg(feature = "replay")]
+pub mod replay;
 pub mod system;
 pub mod task;
+pub mod trace;
+pub mod tray;
 pub mod user_interface;
 pub mod window;
 
@@ -39,6 +47,7 @@ pub use iced_futures as futures;
 pub use debug::Debug;
 pub use program::Program;
 pub use task::Task;
+pub use trace::Trace;
 pub use user_interface::UserInterface;
 
 use crate::core::{widget, Color};
@@ -60,6 +69,9 @@ pub enum Action<T> {
         channel: oneshot::Sender<Result<(), font::Error>>,
     },
 
+    /// Run a font action.
+    Font(font::Action),
+
     /// Run a widget operation.
     Widget(Box<dyn widget::Operation>),
 
@@ -72,6 +84,12 @@ pub enum Action<T> {
     /// Run a system action.
     System(system::Action),
 
+    /// Run a dialog action.
+    Dialog(dialog::Action),
+
+    /// Run a notification action.
+    Notification(notification::Action),
+
     /// Exits the runtime.
     ///
     /// This will normally close any application windows and
@@ -97,10 +115,13 @@ impl<T> Action<T> {
             Action::LoadFont { bytes, channel } => {
                 Err(Action::LoadFont { bytes, channel })
             }
+            Action::Font(action) => Err(Action::Font(action)),
             Action::Widget(operation) => Err(Action::Widget(operation)),
             Action::Clipboard(action) => Err(Action::Clipboard(action)),
             Action::Window(action) => Err(Action::Window(action)),
             Action::System(action) => Err(Action::System(action)),
+            Action::Dialog(action) => Err(Action::Dialog(action)),
+            Action::Notification(action) => Err(Action::Notification(action)),
             Action::Exit => Err(Action::Exit),
             Action::Dnd(a) => Err(Action::Dnd(a)),
             Action::PlatformSpecific(a) => Err(Action::PlatformSpecific(a)),
@@ -118,6 +139,7 @@ where
             Action::LoadFont { .. } => {
                 write!(f, "Action::LoadFont")
             }
+            Action::Font(action) => write!(f, "Action::Font({action:?})"),
             Action::Widget { .. } => {
                 write!(f, "Action::Widget")
             }
@@ -126,6 +148,10 @@ where
             }
             Action::Window(_) => write!(f, "Action::Window"),
             Action::System(action) => write!(f, "Action::System({action:?})"),
+            Action::Dialog(action) => write!(f, "Action::Dialog({action:?})"),
+            Action::Notification(action) => {
+                write!(f, "Action::Notification({action:?})")
+            }
             Action::Exit => write!(f, "Action::Exit"),
             Action::PlatformSpecific(action) => {
                 write!(f, "Action::PlatformSpecific({:?})", action)
@@ -0,0 +1,167 @@
+//! Show desktop notifications.
+use crate::futures::futures::channel::oneshot;
+use crate::task::{self, Task};
+
+use std::time::Duration;
+
+/// A notification action to be performed by some [`Task`].
+#[derive(Debug)]
+pub enum Action {
+    /// Show a [`Notification`].
+    Show {
+        /// The [`Notification`] to show.
+        notification: Notification,
+        /// The channel to send back the [`Id`] of the notification.
+        channel: oneshot::Sender<Result<Id, Error>>,
+    },
+
+    /// Close the notification with the given [`Id`].
+    Close(Id),
+}
+
+/// The identifier of a notification, as assigned by the notification daemon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Id(pub u32);
+
+/// A desktop notification.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Notification {
+    /// The title of the notification.
+    pub title: String,
+    /// The body of the notification.
+    pub body: String,
+    /// The icon of the notification; either an icon name or a `file://` URI.
+    pub icon: Option<String>,
+    /// The [`Urgency`] of the notification.
+    pub urgency: Urgency,
+    /// The action buttons of the notification, as pairs of a key and a label.
+    ///
+    /// The key is reported back in [`Event::ActionInvoked`].
+    pub actions: Vec<(String, String)>,
+    /// The time after which the notification expires, if any.
+    ///
+    /// By default, the notification daemon decides.
+    pub timeout: Option<Duration>,
+}
+
+impl Notification {
+    /// Creates a new [`Notification`] with the given title.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the body of the [`Notification`].
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Sets the icon of the [`Notification`].
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Sets the [`Urgency`] of the [`Notification`].
+    pub fn urgency(mut self, urgency: Urgency) -> Self {
+        self.urgency = urgency;
+        self
+    }
+
+    /// Adds an action button to the [`Notification`].
+    pub fn action(
+        mut self,
+        key: impl Into<String>,
+        label: impl Into<String>,
+    ) -> Self {
+        self.actions.push((key.into(), label.into()));
+        self
+    }
+
+    /// Sets the time after which the [`Notification`] expires.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+}
+
+/// The urgency of a [`Notification`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Urgency {
+    /// A low urgency notification.
+    Low,
+    /// A normal urgency notification.
+    #[default]
+    Normal,
+    /// A critical notification; it may not expire.
+    Critical,
+}
+
+/// An event of a notification shown by the application.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// An action button of a notification was invoked.
+    ActionInvoked {
+        /// The [`Id`] of the notification.
+        id: Id,
+        /// The key of the invoked action.
+        action: String,
+    },
+
+    /// A notification was closed.
+    Closed {
+        /// The [`Id`] of the notification.
+        id: Id,
+        /// The [`Reason`] the notification was closed.
+        reason: Reason,
+    },
+}
+
+impl Event {
+    /// Returns the [`Id`] of the notification of the [`Event`].
+    pub fn id(&self) -> Id {
+        match self {
+            Event::ActionInvoked { id, .. } | Event::Closed { id, .. } => *id,
+        }
+    }
+}
+
+/// The reason a notification was closed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reason {
+    /// The notification expired.
+    Expired,
+    /// The notification was dismissed by the user.
+    Dismissed,
+    /// The notification was closed with [`close`].
+    Closed,
+    /// The reason is unknown.
+    Unknown,
+}
+
+/// An error produced by a notification [`Task`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// No notification daemon is available.
+    Unavailable,
+    /// The notification daemon failed.
+    Backend(String),
+}
+
+/// Shows the given [`Notification`], producing its [`Id`].
+pub fn show(notification: Notification) -> Task<Result<Id, Error>> {
+    task::oneshot(move |channel| {
+        crate::Action::Notification(Action::Show {
+            notification,
+            channel,
+        })
+    })
+}
+
+/// Closes the notification with the given [`Id`].
+pub fn close<T>(id: Id) -> Task<T> {
+    task::effect(crate::Action::Notification(Action::Close(id)))
+}
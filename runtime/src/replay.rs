@@ -0,0 +1,1040 @@
+//! Record and replay the input of a user interface.
+//!
+//! A [`Recorder`] writes every input [`Event`] delivered to a window, together
+//! with its timestamp and the size and scale factor of the window, to a
+//! session file. Windows are identified by the order in which they were
+//! opened, since their [`window::Id`] changes from run to run. A [`Session`]
+//! reads it back, so the same input can be replayed later against the same
+//! application; which makes it possible to reproduce bug reports
+//! deterministically.
+//!
+//! Only input is recorded: keyboard, mouse and touch events, and the window
+//! events caused by the user. Events that depend on the state of the platform,
+//! like window lifecycle, drag and drop or accessibility events, are left out.
+//!
+//! # Session files
+//! A session file is a sequence of JSON lines. The first line is a header
+//! with the version of the format, and every following line is a [`Record`].
+//! Every event is written with its own types, which mirror [`Event`] but are
+//! independent of it; so session files stay readable across releases as long
+//! as [`VERSION`] does not change.
+//!
+//! # Headless replay
+//! A [`Session`] can be replayed without a window by feeding its records to a
+//! [`program::State`]:
+//!
+//! ```no_run
+//! # use iced_runtime::core::{clipboard, id, mouse, renderer};
+//! # use iced_runtime::program;
+//! # use iced_runtime::{Debug, Program};
+//! use iced_runtime::replay::Session;
+//!
+//! # fn replay<P: Program + 'static>(
+//! #     id: id::Id,
+//! #     state: &mut program::State<P>,
+//! #     renderer: &mut P::Renderer,
+//! #     theme: &P::Theme,
+//! #     debug: &mut Debug,
+//! # ) {
+//! let session = Session::open("session.jsonl").expect("Open session");
+//! let mut cursor = mouse::Cursor::Unavailable;
+//!
+//! for record in session {
+//!     cursor = record.cursor(cursor);
+//!
+//!     for event in record.events {
+//!         state.queue_event(event);
+//!     }
+//!
+//!     let _ = state.update(
+//!         id.clone(),
+//!         record.size,
+//!         cursor,
+//!         renderer,
+//!         theme,
+//!         &renderer::Style {
+//!             scale_factor: record.scale_factor,
+//!             ..renderer::Style::default()
+//!         },
+//!         &mut clipboard::Null,
+//!         debug,
+//!     );
+//! }
+//! # }
+//! ```
+//!
+//! [`program::State`]: crate::program::State
+mod names;
+
+use crate::core::keyboard::{self, key};
+use crate::core::mouse;
+use crate::core::time::{Duration, Instant};
+use crate::core::touch;
+use crate::core::window;
+use crate::core::{Event, Point, Size, SmolStr, Vector};
+
+use serde::{Deserialize, Serialize};
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::vec;
+
+/// The name of the format of a session file.
+pub const FORMAT: &str = "iced-replay";
+
+/// The version of the format of a session file.
+pub const VERSION: u32 = 1;
+
+/// The input delivered to a window at some instant of a [`Session`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    /// The time since the start of the [`Session`].
+    pub time: Duration,
+
+    /// The window the events were delivered to, as the number of windows
+    /// opened before it.
+    pub window: usize,
+
+    /// The logical size of the window.
+    pub size: Size,
+
+    /// The scale factor of the window.
+    pub scale_factor: f64,
+
+    /// The events delivered to the window.
+    pub events: Vec<Event>,
+}
+
+impl Record {
+    /// Returns the [`mouse::Cursor`] after delivering the events of the
+    /// [`Record`], given the previous one.
+    pub fn cursor(&self, cursor: mouse::Cursor) -> mouse::Cursor {
+        self.events
+            .iter()
+            .fold(cursor, |cursor, event| match event {
+                Event::Mouse(mouse::Event::CursorMoved { position })
+                | Event::Touch(
+                    touch::Event::FingerPressed { position, .. }
+                    | touch::Event::FingerMoved { position, .. },
+                ) => mouse::Cursor::Available(*position),
+                Event::Mouse(mouse::Event::CursorLeft) => {
+                    mouse::Cursor::Unavailable
+                }
+                _ => cursor,
+            })
+    }
+}
+
+/// Returns true if the given [`Event`] is input that can be recorded and
+/// replayed.
+pub fn is_input(event: &Event) -> bool {
+    Input::from_event(event).is_some()
+}
+
+/// A writer of session files.
+#[derive(Debug)]
+pub struct Recorder {
+    file: io::BufWriter<fs::File>,
+    origin: Instant,
+}
+
+impl Recorder {
+    /// Creates a [`Recorder`] that writes a new session file at the given
+    /// path.
+    ///
+    /// The [`Session`] starts at the moment the [`Recorder`] is created.
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        let mut file = io::BufWriter::new(fs::File::create(path)?);
+
+        serde_json::to_writer(
+            &mut file,
+            &Header {
+                format: FORMAT.to_owned(),
+                version: VERSION,
+            },
+        )?;
+        file.write_all(b"\n")?;
+        file.flush()?;
+
+        Ok(Self {
+            file,
+            origin: Instant::now(),
+        })
+    }
+
+    /// Records the given events delivered to a window with the given size
+    /// and scale factor.
+    ///
+    /// The window is identified by the number of windows opened before it.
+    ///
+    /// Events that are not input are ignored, and nothing is written if no
+    /// input is left.
+    pub fn record(
+        &mut self,
+        window: usize,
+        size: Size,
+        scale_factor: f64,
+        events: &[Event],
+    ) -> io::Result<()> {
+        let events: Vec<Input> =
+            events.iter().filter_map(Input::from_event).collect();
+
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        let line = Line {
+            time: self.origin.elapsed().as_micros() as u64,
+            window,
+            size: size.into(),
+            scale_factor,
+            events,
+        };
+
+        serde_json::to_writer(&mut self.file, &line)?;
+        self.file.write_all(b"\n")?;
+
+        // Flush every record, so the session survives a crash; which is
+        // usually what we are trying to reproduce!
+        self.file.flush()
+    }
+}
+
+/// A recorded sequence of [`Record`], ready to be replayed.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Session {
+    records: Vec<Record>,
+}
+
+impl Session {
+    /// Reads the session file at the given path.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let contents = fs::read_to_string(path)
+            .map_err(|error| Error::Io(error.kind()))?;
+
+        Self::parse(&contents)
+    }
+
+    /// Parses the contents of a session file.
+    pub fn parse(contents: &str) -> Result<Self, Error> {
+        let mut lines = contents
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| (index + 1, line));
+
+        let (number, header) = lines.next().ok_or(Error::Empty)?;
+
+        let header: Header = serde_json::from_str(header)
+            .map_err(|error| Error::invalid(number, &error))?;
+
+        if header.format != FORMAT || header.version != VERSION {
+            return Err(Error::Unsupported {
+                format: header.format,
+                version: header.version,
+            });
+        }
+
+        let records = lines
+            .map(|(number, line)| {
+                serde_json::from_str::<Line>(line)
+                    .map(Record::from)
+                    .map_err(|error| Error::invalid(number, &error))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { records })
+    }
+
+    /// Returns the records of the [`Session`], in order.
+    pub fn records(&self) -> &[Record] {
+        &self.records
+    }
+
+    /// Returns the total duration of the [`Session`].
+    pub fn duration(&self) -> Duration {
+        self.records
+            .last()
+            .map(|record| record.time)
+            .unwrap_or_default()
+    }
+}
+
+impl IntoIterator for Session {
+    type Item = Record;
+    type IntoIter = vec::IntoIter<Record>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.records.into_iter()
+    }
+}
+
+/// An error while reading a [`Session`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The session file could not be read.
+    Io(io::ErrorKind),
+    /// The session file is empty.
+    Empty,
+    /// The session file was written with an unsupported format.
+    Unsupported {
+        /// The format of the session file.
+        format: String,
+        /// The version of the format of the session file.
+        version: u32,
+    },
+    /// A line of the session file is not valid.
+    Invalid {
+        /// The number of the invalid line, starting at 1.
+        line: usize,
+        /// The reason the line is not valid.
+        reason: String,
+    },
+}
+
+impl Error {
+    fn invalid(line: usize, error: &serde_json::Error) -> Self {
+        Self::Invalid {
+            line,
+            reason: error.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct Header {
+    format: String,
+    version: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Line {
+    /// The time since the start of the session, in microseconds.
+    time: u64,
+    /// The number of windows opened before the window.
+    window: usize,
+    size: Dimensions,
+    scale_factor: f64,
+    events: Vec<Input>,
+}
+
+impl From<Line> for Record {
+    fn from(line: Line) -> Self {
+        Self {
+            time: Duration::from_micros(line.time),
+            window: line.window,
+            size: line.size.into(),
+            scale_factor: line.scale_factor,
+            events: line.events.into_iter().map(Event::from).collect(),
+        }
+    }
+}
+
+/// The serialized form of an input [`Event`].
+///
+/// Every type of the format mirrors its counterpart in `iced_core`, so the
+/// format of session files does not change along with [`Event`].
+#[derive(Serialize, Deserialize)]
+enum Input {
+    Keyboard(Keyboard),
+    Mouse(Mouse),
+    Touch(Touch),
+    Window(Window),
+}
+
+#[derive(Serialize, Deserialize)]
+enum Keyboard {
+    KeyPressed {
+        key: Key,
+        modified_key: Key,
+        physical_key: Physical,
+        location: Location,
+        modifiers: Vec<Modifier>,
+        text: Option<String>,
+    },
+    KeyReleased {
+        key: Key,
+        modified_key: Key,
+        physical_key: Physical,
+        location: Location,
+        modifiers: Vec<Modifier>,
+    },
+    ModifiersChanged(Vec<Modifier>),
+}
+
+/// A key, with the names in [`names`].
+#[derive(Serialize, Deserialize)]
+enum Key {
+    Named(String),
+    Character(String),
+    Unidentified,
+}
+
+/// A physical key, with the names in [`names`].
+#[derive(Serialize, Deserialize)]
+enum Physical {
+    Code(String),
+    Unidentified(NativeCode),
+}
+
+#[derive(Serialize, Deserialize)]
+enum NativeCode {
+    Unidentified,
+    Android(u32),
+    MacOS(u16),
+    Windows(u16),
+    Xkb(u32),
+}
+
+#[derive(Serialize, Deserialize)]
+enum Location {
+    Standard,
+    Left,
+    Right,
+    Numpad,
+}
+
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+enum Modifier {
+    Shift,
+    Ctrl,
+    Alt,
+    Logo,
+    CapsLock,
+}
+
+#[derive(Serialize, Deserialize)]
+enum Mouse {
+    CursorEntered,
+    CursorLeft,
+    CursorMoved { position: Position },
+    RawMotion { x: f32, y: f32 },
+    ButtonPressed(Button),
+    ButtonReleased(Button),
+    WheelScrolled { delta: ScrollDelta },
+}
+
+#[derive(Serialize, Deserialize)]
+enum Button {
+    Left,
+    Right,
+    Middle,
+    Back,
+    Forward,
+    Other(u16),
+}
+
+#[derive(Serialize, Deserialize)]
+enum ScrollDelta {
+    Lines { x: f32, y: f32 },
+    Pixels { x: f32, y: f32 },
+}
+
+#[derive(Serialize, Deserialize)]
+enum Touch {
+    FingerPressed { finger: u64, position: Position },
+    FingerMoved { finger: u64, position: Position },
+    FingerLifted { finger: u64, position: Position },
+    FingerLost { finger: u64, position: Position },
+}
+
+#[derive(Serialize, Deserialize)]
+enum Window {
+    Moved(Position),
+    CloseRequested,
+    Focused,
+    Unfocused,
+    FileHovered(PathBuf),
+    FileDropped(PathBuf),
+    FilesHoveredLeft,
+}
+
+/// The serialized form of a [`Point`].
+#[derive(Clone, Copy, Serialize, Deserialize)]
+struct Position {
+    x: f32,
+    y: f32,
+}
+
+/// The serialized form of a [`Size`].
+#[derive(Clone, Copy, Serialize, Deserialize)]
+struct Dimensions {
+    width: f32,
+    height: f32,
+}
+
+impl Input {
+    fn from_event(event: &Event) -> Option<Self> {
+        Some(match event {
+            Event::Keyboard(event) => Self::Keyboard(Keyboard::from(event)),
+            Event::Mouse(event) => Self::Mouse(Mouse::from(*event)),
+            Event::Touch(event) => Self::Touch(Touch::from(*event)),
+            Event::Window(event) => Self::Window(match event {
+                window::Event::Moved(position) => {
+                    Window::Moved((*position).into())
+                }
+                window::Event::CloseRequested => Window::CloseRequested,
+                window::Event::Focused => Window::Focused,
+                window::Event::Unfocused => Window::Unfocused,
+                window::Event::FileHovered(path) => {
+                    Window::FileHovered(path.clone())
+                }
+                window::Event::FileDropped(path) => {
+                    Window::FileDropped(path.clone())
+                }
+                window::Event::FilesHoveredLeft => Window::FilesHoveredLeft,
+                window::Event::Opened { .. }
+                | window::Event::Closed
+                | window::Event::Resized(_)
+                | window::Event::MonitorChanged(_)
+                | window::Event::RedrawRequested(_) => return None,
+            }),
+            _ => return None,
+        })
+    }
+}
+
+impl From<Input> for Event {
+    fn from(input: Input) -> Self {
+        match input {
+            Input::Keyboard(event) => Event::Keyboard(event.into()),
+            Input::Mouse(event) => Event::Mouse(event.into()),
+            Input::Touch(event) => Event::Touch(event.into()),
+            Input::Window(event) => Event::Window(match event {
+                Window::Moved(position) => {
+                    window::Event::Moved(position.into())
+                }
+                Window::CloseRequested => window::Event::CloseRequested,
+                Window::Focused => window::Event::Focused,
+                Window::Unfocused => window::Event::Unfocused,
+                Window::FileHovered(path) => window::Event::FileHovered(path),
+                Window::FileDropped(path) => window::Event::FileDropped(path),
+                Window::FilesHoveredLeft => window::Event::FilesHoveredLeft,
+            }),
+        }
+    }
+}
+
+impl From<Point> for Position {
+    fn from(point: Point) -> Self {
+        Self {
+            x: point.x,
+            y: point.y,
+        }
+    }
+}
+
+impl From<Position> for Point {
+    fn from(position: Position) -> Self {
+        Self::new(position.x, position.y)
+    }
+}
+
+impl From<Size> for Dimensions {
+    fn from(size: Size) -> Self {
+        Self {
+            width: size.width,
+            height: size.height,
+        }
+    }
+}
+
+impl From<Dimensions> for Size {
+    fn from(dimensions: Dimensions) -> Self {
+        Self::new(dimensions.width, dimensions.height)
+    }
+}
+
+impl From<&keyboard::Event> for Keyboard {
+    fn from(event: &keyboard::Event) -> Self {
+        match event {
+            keyboard::Event::KeyPressed {
+                key,
+                modified_key,
+                physical_key,
+                location,
+                modifiers,
+                text,
+            } => Self::KeyPressed {
+                key: key.into(),
+                modified_key: modified_key.into(),
+                physical_key: (*physical_key).into(),
+                location: (*location).into(),
+                modifiers: Modifier::list(*modifiers),
+                text: text.as_ref().map(ToString::to_string),
+            },
+            keyboard::Event::KeyReleased {
+                key,
+                modified_key,
+                physical_key,
+                location,
+                modifiers,
+            } => Self::KeyReleased {
+                key: key.into(),
+                modified_key: modified_key.into(),
+                physical_key: (*physical_key).into(),
+                location: (*location).into(),
+                modifiers: Modifier::list(*modifiers),
+            },
+            keyboard::Event::ModifiersChanged(modifiers) => {
+                Self::ModifiersChanged(Modifier::list(*modifiers))
+            }
+        }
+    }
+}
+
+impl From<Keyboard> for keyboard::Event {
+    fn from(event: Keyboard) -> Self {
+        match event {
+            Keyboard::KeyPressed {
+                key,
+                modified_key,
+                physical_key,
+                location,
+                modifiers,
+                text,
+            } => Self::KeyPressed {
+                key: key.into(),
+                modified_key: modified_key.into(),
+                physical_key: physical_key.into(),
+                location: location.into(),
+                modifiers: Modifier::collect(&modifiers),
+                text: text.map(SmolStr::from),
+            },
+            Keyboard::KeyReleased {
+                key,
+                modified_key,
+                physical_key,
+                location,
+                modifiers,
+            } => Self::KeyReleased {
+                key: key.into(),
+                modified_key: modified_key.into(),
+                physical_key: physical_key.into(),
+                location: location.into(),
+                modifiers: Modifier::collect(&modifiers),
+            },
+            Keyboard::ModifiersChanged(modifiers) => {
+                Self::ModifiersChanged(Modifier::collect(&modifiers))
+            }
+        }
+    }
+}
+
+impl From<&keyboard::Key> for Key {
+    fn from(key: &keyboard::Key) -> Self {
+        match key {
+            keyboard::Key::Named(named) => names::named(*named)
+                .map_or(Self::Unidentified, |name| {
+                    Self::Named(name.to_owned())
+                }),
+            keyboard::Key::Character(character) => {
+                Self::Character(character.to_string())
+            }
+            keyboard::Key::Unidentified => Self::Unidentified,
+        }
+    }
+}
+
+impl From<Key> for keyboard::Key {
+    fn from(key: Key) -> Self {
+        match key {
+            Key::Named(name) => names::parse_named(&name)
+                .map_or(Self::Unidentified, Self::Named),
+            Key::Character(character) => Self::Character(character.into()),
+            Key::Unidentified => Self::Unidentified,
+        }
+    }
+}
+
+impl From<key::Physical> for Physical {
+    fn from(physical: key::Physical) -> Self {
+        match physical {
+            key::Physical::Code(code) => names::code(code)
+                .map_or(Self::Unidentified(NativeCode::Unidentified), |name| {
+                    Self::Code(name.to_owned())
+                }),
+            key::Physical::Unidentified(native) => {
+                Self::Unidentified(match native {
+                    key::NativeCode::Unidentified => NativeCode::Unidentified,
+                    key::NativeCode::Android(code) => NativeCode::Android(code),
+                    key::NativeCode::MacOS(code) => NativeCode::MacOS(code),
+                    key::NativeCode::Windows(code) => NativeCode::Windows(code),
+                    key::NativeCode::Xkb(code) => NativeCode::Xkb(code),
+                })
+            }
+        }
+    }
+}
+
+impl From<Physical> for key::Physical {
+    fn from(physical: Physical) -> Self {
+        let native = match physical {
+            Physical::Code(name) => {
+                if let Some(code) = names::parse_code(&name) {
+                    return Self::Code(code);
+                }
+
+                NativeCode::Unidentified
+            }
+            Physical::Unidentified(native) => native,
+        };
+
+        Self::Unidentified(match native {
+            NativeCode::Unidentified => key::NativeCode::Unidentified,
+            NativeCode::Android(code) => key::NativeCode::Android(code),
+            NativeCode::MacOS(code) => key::NativeCode::MacOS(code),
+            NativeCode::Windows(code) => key::NativeCode::Windows(code),
+            NativeCode::Xkb(code) => key::NativeCode::Xkb(code),
+        })
+    }
+}
+
+impl From<keyboard::Location> for Location {
+    fn from(location: keyboard::Location) -> Self {
+        match location {
+            keyboard::Location::Standard => Self::Standard,
+            keyboard::Location::Left => Self::Left,
+            keyboard::Location::Right => Self::Right,
+            keyboard::Location::Numpad => Self::Numpad,
+        }
+    }
+}
+
+impl From<Location> for keyboard::Location {
+    fn from(location: Location) -> Self {
+        match location {
+            Location::Standard => Self::Standard,
+            Location::Left => Self::Left,
+            Location::Right => Self::Right,
+            Location::Numpad => Self::Numpad,
+        }
+    }
+}
+
+impl Modifier {
+    const ALL: [(Self, keyboard::Modifiers); 5] = [
+        (Self::Shift, keyboard::Modifiers::SHIFT),
+        (Self::Ctrl, keyboard::Modifiers::CTRL),
+        (Self::Alt, keyboard::Modifiers::ALT),
+        (Self::Logo, keyboard::Modifiers::LOGO),
+        (Self::CapsLock, keyboard::Modifiers::CAPS_LOCK),
+    ];
+
+    fn list(modifiers: keyboard::Modifiers) -> Vec<Self> {
+        Self::ALL
+            .iter()
+            .filter(|(_, flag)| modifiers.contains(*flag))
+            .map(|(modifier, _)| *modifier)
+            .collect()
+    }
+
+    fn collect(modifiers: &[Self]) -> keyboard::Modifiers {
+        Self::ALL
+            .iter()
+            .filter(|(modifier, _)| modifiers.contains(modifier))
+            .fold(keyboard::Modifiers::empty(), |modifiers, (_, flag)| {
+                modifiers | *flag
+            })
+    }
+}
+
+impl From<mouse::Event> for Mouse {
+    fn from(event: mouse::Event) -> Self {
+        match event {
+            mouse::Event::CursorEntered => Self::CursorEntered,
+            mouse::Event::CursorLeft => Self::CursorLeft,
+            mouse::Event::CursorMoved { position } => Self::CursorMoved {
+                position: position.into(),
+            },
+            mouse::Event::RawMotion { delta } => Self::RawMotion {
+                x: delta.x,
+                y: delta.y,
+            },
+            mouse::Event::ButtonPressed(button) => {
+                Self::ButtonPressed(button.into())
+            }
+            mouse::Event::ButtonReleased(button) => {
+                Self::ButtonReleased(button.into())
+            }
+            mouse::Event::WheelScrolled { delta } => Self::WheelScrolled {
+                delta: match delta {
+                    mouse::ScrollDelta::Lines { x, y } => {
+                        ScrollDelta::Lines { x, y }
+                    }
+                    mouse::ScrollDelta::Pixels { x, y } => {
+                        ScrollDelta::Pixels { x, y }
+                    }
+                },
+            },
+        }
+    }
+}
+
+impl From<Mouse> for mouse::Event {
+    fn from(event: Mouse) -> Self {
+        match event {
+            Mouse::CursorEntered => Self::CursorEntered,
+            Mouse::CursorLeft => Self::CursorLeft,
+            Mouse::CursorMoved { position } => Self::CursorMoved {
+                position: position.into(),
+            },
+            Mouse::RawMotion { x, y } => Self::RawMotion {
+                delta: Vector::new(x, y),
+            },
+            Mouse::ButtonPressed(button) => Self::ButtonPressed(button.into()),
+            Mouse::ButtonReleased(button) => {
+                Self::ButtonReleased(button.into())
+            }
+            Mouse::WheelScrolled { delta } => Self::WheelScrolled {
+                delta: match delta {
+                    ScrollDelta::Lines { x, y } => {
+                        mouse::ScrollDelta::Lines { x, y }
+                    }
+                    ScrollDelta::Pixels { x, y } => {
+                        mouse::ScrollDelta::Pixels { x, y }
+                    }
+                },
+            },
+        }
+    }
+}
+
+impl From<mouse::Button> for Button {
+    fn from(button: mouse::Button) -> Self {
+        match button {
+            mouse::Button::Left => Self::Left,
+            mouse::Button::Right => Self::Right,
+            mouse::Button::Middle => Self::Middle,
+            mouse::Button::Back => Self::Back,
+            mouse::Button::Forward => Self::Forward,
+            mouse::Button::Other(button) => Self::Other(button),
+        }
+    }
+}
+
+impl From<Button> for mouse::Button {
+    fn from(button: Button) -> Self {
+        match button {
+            Button::Left => Self::Left,
+            Button::Right => Self::Right,
+            Button::Middle => Self::Middle,
+            Button::Back => Self::Back,
+            Button::Forward => Self::Forward,
+            Button::Other(button) => Self::Other(button),
+        }
+    }
+}
+
+impl From<touch::Event> for Touch {
+    fn from(event: touch::Event) -> Self {
+        match event {
+            touch::Event::FingerPressed { id, position } => {
+                Self::FingerPressed {
+                    finger: id.0,
+                    position: position.into(),
+                }
+            }
+            touch::Event::FingerMoved { id, position } => Self::FingerMoved {
+                finger: id.0,
+                position: position.into(),
+            },
+            touch::Event::FingerLifted { id, position } => Self::FingerLifted {
+                finger: id.0,
+                position: position.into(),
+            },
+            touch::Event::FingerLost { id, position } => Self::FingerLost {
+                finger: id.0,
+                position: position.into(),
+            },
+        }
+    }
+}
+
+impl From<Touch> for touch::Event {
+    fn from(event: Touch) -> Self {
+        match event {
+            Touch::FingerPressed { finger, position } => Self::FingerPressed {
+                id: touch::Finger(finger),
+                position: position.into(),
+            },
+            Touch::FingerMoved { finger, position } => Self::FingerMoved {
+                id: touch::Finger(finger),
+                position: position.into(),
+            },
+            Touch::FingerLifted { finger, position } => Self::FingerLifted {
+                id: touch::Finger(finger),
+                position: position.into(),
+            },
+            Touch::FingerLost { finger, position } => Self::FingerLost {
+                id: touch::Finger(finger),
+                position: position.into(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key_pressed() -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Character("a".into()),
+            modified_key: keyboard::Key::Character("A".into()),
+            physical_key: key::Physical::Code(key::Code::KeyA),
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::SHIFT,
+            text: Some("A".into()),
+        })
+    }
+
+    #[test]
+    fn version_1_sessions_are_readable() {
+        let session =
+            Session::parse(include_str!("replay/v1.jsonl")).expect("Parse");
+
+        assert_eq!(
+            session.records(),
+            [
+                Record {
+                    time: Duration::ZERO,
+                    window: 0,
+                    size: Size::new(800.0, 600.0),
+                    scale_factor: 1.0,
+                    events: vec![
+                        Event::Mouse(mouse::Event::CursorMoved {
+                            position: Point::new(10.0, 20.0),
+                        }),
+                        Event::Mouse(mouse::Event::ButtonPressed(
+                            mouse::Button::Left,
+                        )),
+                    ],
+                },
+                Record {
+                    time: Duration::from_millis(16),
+                    window: 0,
+                    size: Size::new(800.0, 600.0),
+                    scale_factor: 1.0,
+                    events: vec![
+                        key_pressed(),
+                        Event::Keyboard(keyboard::Event::KeyReleased {
+                            key: keyboard::Key::Named(key::Named::Enter),
+                            modified_key: keyboard::Key::Named(
+                                key::Named::Enter,
+                            ),
+                            physical_key: key::Physical::Unidentified(
+                                key::NativeCode::Xkb(104),
+                            ),
+                            location: keyboard::Location::Numpad,
+                            modifiers: keyboard::Modifiers::empty(),
+                        }),
+                        Event::Keyboard(keyboard::Event::ModifiersChanged(
+                            keyboard::Modifiers::CTRL
+                                | keyboard::Modifiers::ALT,
+                        )),
+                    ],
+                },
+                Record {
+                    time: Duration::from_millis(32),
+                    window: 1,
+                    size: Size::new(400.0, 300.0),
+                    scale_factor: 2.0,
+                    events: vec![
+                        Event::Touch(touch::Event::FingerPressed {
+                            id: touch::Finger(1),
+                            position: Point::new(5.0, 5.0),
+                        }),
+                        Event::Mouse(mouse::Event::WheelScrolled {
+                            delta: mouse::ScrollDelta::Lines {
+                                x: 0.0,
+                                y: -1.0
+                            },
+                        }),
+                        Event::Mouse(mouse::Event::RawMotion {
+                            delta: Vector::new(1.0, 2.0),
+                        }),
+                        Event::Window(window::Event::Focused),
+                        Event::Window(window::Event::FileDropped(
+                            PathBuf::from("/tmp/a.txt"),
+                        )),
+                    ],
+                },
+            ]
+        );
+
+        assert_eq!(session.duration(), Duration::from_millis(32));
+    }
+
+    #[test]
+    fn recorded_sessions_are_replayed() {
+        let path = std::env::temp_dir()
+            .join(format!("iced-replay-{}.jsonl", std::process::id()));
+
+        let events = [
+            key_pressed(),
+            Event::Window(window::Event::RedrawRequested(Instant::now())),
+            Event::Mouse(mouse::Event::CursorLeft),
+        ];
+
+        let mut recorder = Recorder::create(&path).expect("Create recorder");
+
+        recorder
+            .record(0, Size::new(100.0, 50.0), 1.5, &events)
+            .expect("Record input");
+        recorder
+            .record(2, Size::new(10.0, 10.0), 1.0, &events[1..2])
+            .expect("Record nothing");
+
+        drop(recorder);
+
+        let session = Session::open(&path).expect("Open session");
+        let _ = fs::remove_file(&path);
+
+        let [record] = session.records() else {
+            panic!("Expected one record, found {:?}", session.records());
+        };
+
+        assert_eq!(record.window, 0);
+        assert_eq!(record.size, Size::new(100.0, 50.0));
+        assert_eq!(record.scale_factor, 1.5);
+        assert_eq!(record.events, [events[0].clone(), events[2].clone()]);
+        assert_eq!(
+            record.cursor(mouse::Cursor::Available(Point::ORIGIN)),
+            mouse::Cursor::Unavailable
+        );
+    }
+
+    #[test]
+    fn invalid_sessions_are_rejected() {
+        assert_eq!(Session::parse("\n\n"), Err(Error::Empty));
+
+        assert_eq!(
+            Session::parse(r#"{"format":"iced-replay","version":2}"#),
+            Err(Error::Unsupported {
+                format: String::from("iced-replay"),
+                version: 2,
+            })
+        );
+
+        assert!(matches!(
+            Session::parse(
+                "{\"format\":\"iced-replay\",\"version\":1}\n\n{\"time\":0}"
+            ),
+            Err(Error::Invalid { line: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn unknown_key_names_are_unidentified() {
+        let physical = key::Physical::from(Physical::Code("Teleport".into()));
+        let key = keyboard::Key::from(Key::Named("Teleport".into()));
+
+        assert_eq!(
+            physical,
+            key::Physical::Unidentified(key::NativeCode::Unidentified)
+        );
+        assert_eq!(key, keyboard::Key::Unidentified);
+    }
+}
@@ -0,0 +1,54 @@
+//! Trace the runtime of an application to diagnose why it is busy.
+use std::path::PathBuf;
+
+/// The destination of the traces of an application.
+///
+/// A trace records the messages, tasks, subscriptions and redraws of an
+/// application, and how long each phase of its runtime took.
+///
+/// Traces are only recorded when the `debug` feature is enabled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Trace {
+    /// Emit every phase of the runtime as a `tracing` span, and every other
+    /// event as a `tracing` event, with the `iced::trace` target.
+    Tracing,
+    /// Write a Chrome trace JSON file to the given path, which can be opened
+    /// with `chrome://tracing` or [Perfetto].
+    ///
+    /// [Perfetto]: https://ui.perfetto.dev
+    Chrome(PathBuf),
+}
+
+impl Trace {
+    /// Returns the [`Trace`] configured by the `ICED_TRACE` environment
+    /// variable, if any.
+    ///
+    /// `ICED_TRACE=tracing` selects [`Trace::Tracing`], while any other
+    /// non-empty value is the path of a [`Trace::Chrome`] file.
+    pub fn from_env() -> Option<Self> {
+        Self::parse(&std::env::var("ICED_TRACE").ok()?)
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "" => None,
+            "tracing" => Some(Self::Tracing),
+            path => Some(Self::Chrome(PathBuf::from(path))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn environment_values_are_parsed() {
+        assert_eq!(Trace::parse(""), None);
+        assert_eq!(Trace::parse("tracing"), Some(Trace::Tracing));
+        assert_eq!(
+            Trace::parse("/tmp/trace.json"),
+            Some(Trace::Chrome(PathBuf::from("/tmp/trace.json")))
+        );
+    }
+}
@@ -0,0 +1,133 @@
+//! Show an icon in the system tray.
+use crate::core::window;
+
+/// An icon in the system tray, with a tooltip and a menu.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Tray {
+    /// The unique identifier of the [`Tray`] in the application.
+    pub id: String,
+    /// The title of the [`Tray`].
+    pub title: String,
+    /// The icon of the [`Tray`].
+    pub icon: Option<window::Icon>,
+    /// The tooltip of the [`Tray`].
+    pub tooltip: String,
+    /// The menu of the [`Tray`].
+    pub menu: Vec<Item>,
+}
+
+impl Tray {
+    /// Creates a new [`Tray`] with the given identifier.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            title: String::new(),
+            icon: None,
+            tooltip: String::new(),
+            menu: Vec::new(),
+        }
+    }
+
+    /// Sets the title of the [`Tray`].
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Sets the icon of the [`Tray`].
+    pub fn icon(mut self, icon: window::Icon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Sets the tooltip of the [`Tray`].
+    pub fn tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.tooltip = tooltip.into();
+        self
+    }
+
+    /// Adds an [`Item`] to the menu of the [`Tray`].
+    pub fn item(mut self, item: Item) -> Self {
+        self.menu.push(item);
+        self
+    }
+}
+
+/// An item of the menu of a [`Tray`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Item {
+    /// A button.
+    Button {
+        /// The key reported back in [`Event::MenuItemInvoked`].
+        key: String,
+        /// The label of the button.
+        label: String,
+        /// Whether the button can be invoked.
+        enabled: bool,
+    },
+    /// A checkable item.
+    Toggle {
+        /// The key reported back in [`Event::MenuItemInvoked`].
+        key: String,
+        /// The label of the item.
+        label: String,
+        /// Whether the item is checked.
+        checked: bool,
+    },
+    /// A separator.
+    Separator,
+    /// A submenu.
+    Submenu {
+        /// The label of the submenu.
+        label: String,
+        /// The items of the submenu.
+        items: Vec<Item>,
+    },
+}
+
+impl Item {
+    /// Creates an enabled [`Item::Button`].
+    pub fn button(key: impl Into<String>, label: impl Into<String>) -> Self {
+        Self::Button {
+            key: key.into(),
+            label: label.into(),
+            enabled: true,
+        }
+    }
+
+    /// Creates an [`Item::Toggle`].
+    pub fn toggle(
+        key: impl Into<String>,
+        label: impl Into<String>,
+        checked: bool,
+    ) -> Self {
+        Self::Toggle {
+            key: key.into(),
+            label: label.into(),
+            checked,
+        }
+    }
+
+    /// Creates an [`Item::Submenu`].
+    pub fn submenu(
+        label: impl Into<String>,
+        items: impl IntoIterator<Item = Item>,
+    ) -> Self {
+        Self::Submenu {
+            label: label.into(),
+            items: items.into_iter().collect(),
+        }
+    }
+}
+
+/// An event of a [`Tray`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// The [`Tray`] was activated; normally, with a left click.
+    Activated,
+    /// The [`Tray`] was activated in a secondary way; normally, with a middle
+    /// click.
+    SecondaryActivated,
+    /// An [`Item`] of the menu was invoked, with the given key.
+    MenuItemInvoked(String),
+}
@@ -636,6 +636,16 @@ where
         )
     }
 
+    /// Inspects the widgets of the [`UserInterface`], returning their
+    /// layout as an [`inspect::Node`] tree.
+    ///
+    /// [`inspect::Node`]: widget::inspect::Node
+    pub fn inspect(&self) -> widget::inspect::Node {
+        self.root
+            .as_widget()
+            .inspect(&self.state, Layout::new(&self.base))
+    }
+
     /// Find widget with given id
     pub fn find(&self, id: &widget::Id) -> Option<&widget::Tree> {
         self.state.find(id)
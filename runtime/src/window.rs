@@ -1,11 +1,14 @@
 //! Build window-based GUI applications.
 pub mod screenshot;
+pub mod stats;
 
 pub use screenshot::Screenshot;
+pub use stats::Stats;
 
 use crate::core::time::Instant;
 use crate::core::window::{
-    Event, Icon, Id, Level, Mode, Settings, UserAttention,
+    CursorGrab, Event, Geometry, Icon, Id, Level, Mode, Monitor, Settings,
+    UserAttention,
 };
 use crate::core::{Point, Size};
 use crate::futures::event;
@@ -42,6 +45,37 @@ pub enum Action {
     /// Resize the window to the given logical dimensions.
     Resize(Id, Size),
 
+    /// Set the minimum logical dimensions of the window.
+    SetMinSize(Id, Option<Size>),
+
+    /// Set the maximum logical dimensions of the window.
+    SetMaxSize(Id, Option<Size>),
+
+    /// Set the logical increments in which the window can be resized.
+    ///
+    /// ## Platform-specific
+    /// - **iOS / Android / Web / Wayland / Orbital:** Unsupported.
+    SetResizeIncrements(Id, Option<Size>),
+
+    /// Set whether the window can be resized by the user.
+    SetResizable(Id, bool),
+
+    /// Set the aspect ratio (width / height) the window is kept at when
+    /// resized.
+    SetAspectRatio(Id, Option<f32>),
+
+    /// Set whether the contents of the window are protected from being
+    /// captured by other applications.
+    ///
+    /// ## Platform-specific
+    /// - **iOS / Android / X11 / Wayland / Web / Orbital:** Unsupported.
+    SetContentProtected(Id, bool),
+
+    /// Set the title of the window, overriding the title of the program.
+    ///
+    /// `None` restores the title of the program.
+    SetTitle(Id, Option<String>),
+
     /// Get the current logical dimensions of the window.
     GetSize(Id, oneshot::Sender<Size>),
 
@@ -63,6 +97,10 @@ pub enum Action {
     /// Get the current logical coordinates of the window.
     GetPosition(Id, oneshot::Sender<Option<Point>>),
 
+    /// Get a [`Geometry`] snapshot of the window, which can be used to
+    /// restore it later.
+    GetGeometry(Id, oneshot::Sender<Geometry>),
+
     /// Get the current scale factor (DPI) of the window.
     GetScaleFactor(Id, oneshot::Sender<f32>),
 
@@ -148,6 +186,9 @@ pub enum Action {
     /// Screenshot the viewport of the window.
     Screenshot(Id, oneshot::Sender<Screenshot>),
 
+    /// Fetch the [`Stats`] of the last frame of the window.
+    Stats(Id, oneshot::Sender<Stats>),
+
     /// Enables mouse passthrough for the given window.
     ///
     /// This disables mouse events for the window and passes mouse events
@@ -160,11 +201,25 @@ pub enum Action {
     /// from being passed to whatever is underneath.
     DisableMousePassthrough(Id),
 
+    /// Set the [`CursorGrab`] mode of the window.
+    ///
+    /// If locking the cursor is not supported, it will be confined instead.
+    SetCursorGrab(Id, CursorGrab),
+
+    /// Set whether the mouse cursor is visible over the window.
+    SetCursorVisible(Id, bool),
+
     /// Enable window blur.
     EnableBlur(Id),
 
     /// Disable window blur.
     DisableBlur(Id),
+
+    /// Get the monitors available in the system.
+    GetMonitors(oneshot::Sender<Vec<Monitor>>),
+
+    /// Get the [`Monitor`] the window is on, if known.
+    GetCurrentMonitor(Id, oneshot::Sender<Option<Monitor>>),
 }
 
 /// Subscribes to the frames of the window of the running application.
@@ -248,6 +303,19 @@ pub fn resize_events() -> Subscription<(Id, Size)> {
     })
 }
 
+/// Subscribes to all [`Event::MonitorChanged`] occurrences in the running application.
+pub fn monitor_changes() -> Subscription<(Id, Monitor)> {
+    event::listen_with(|event, _status, id| {
+        if let crate::core::Event::Window(Event::MonitorChanged(monitor)) =
+            event
+        {
+            Some((id, monitor))
+        } else {
+            None
+        }
+    })
+}
+
 /// Subscribes to all [`Event::CloseRequested`] occurrences in the running application.
 pub fn close_requests() -> Subscription<Id> {
     event::listen_with(|event, _status, id| {
@@ -297,6 +365,70 @@ pub fn resize<T>(id: Id, new_size: Size) -> Task<T> {
     task::effect(crate::Action::Window(Action::Resize(id, new_size)))
 }
 
+/// Sets the minimum logical dimensions of the window.
+///
+/// `None` removes the constraint.
+pub fn set_min_size<T>(id: Id, size: Option<Size>) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetMinSize(id, size)))
+}
+
+/// Sets the maximum logical dimensions of the window.
+///
+/// `None` removes the constraint.
+pub fn set_max_size<T>(id: Id, size: Option<Size>) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetMaxSize(id, size)))
+}
+
+/// Sets the logical increments in which the window can be resized.
+///
+/// `None` removes the constraint.
+pub fn set_resize_increments<T>(id: Id, increments: Option<Size>) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetResizeIncrements(
+        id, increments,
+    )))
+}
+
+/// Sets whether the window can be resized by the user.
+pub fn set_resizable<T>(id: Id, resizable: bool) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetResizable(id, resizable)))
+}
+
+/// Sets the aspect ratio (width / height) the window is kept at when
+/// resized.
+///
+/// `None` lets the window be resized freely.
+pub fn set_aspect_ratio<T>(id: Id, aspect_ratio: Option<f32>) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetAspectRatio(
+        id,
+        aspect_ratio,
+    )))
+}
+
+/// Sets whether the contents of the window are protected from being
+/// captured by other applications.
+pub fn set_content_protected<T>(id: Id, protected: bool) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetContentProtected(
+        id, protected,
+    )))
+}
+
+/// Sets the title of the window.
+///
+/// The title is kept regardless of the title of the application, until
+/// [`reset_title`] is called.
+pub fn set_title<T>(id: Id, title: impl Into<String>) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetTitle(
+        id,
+        Some(title.into()),
+    )))
+}
+
+/// Restores the title of the application as the title of the window,
+/// undoing any [`set_title`].
+pub fn reset_title<T>(id: Id) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetTitle(id, None)))
+}
+
 /// Get the window's size in logical dimensions.
 pub fn get_size(id: Id) -> Task<Size> {
     task::oneshot(move |channel| {
@@ -335,6 +467,16 @@ pub fn get_position(id: Id) -> Task<Option<Point>> {
     })
 }
 
+/// Gets a [`Geometry`] snapshot of the window with the given [`Id`].
+///
+/// The snapshot can be persisted and later passed to
+/// [`Settings::geometry`] to restore the window.
+pub fn get_geometry(id: Id) -> Task<Geometry> {
+    task::oneshot(move |channel| {
+        crate::Action::Window(Action::GetGeometry(id, channel))
+    })
+}
+
 /// Gets the scale factor of the window with the given [`Id`].
 pub fn get_scale_factor(id: Id) -> Task<f32> {
     task::oneshot(move |channel| {
@@ -347,6 +489,18 @@ pub fn move_to<T>(id: Id, position: Point) -> Task<T> {
     task::effect(crate::Action::Window(Action::Move(id, position)))
 }
 
+/// Gets the monitors available in the system.
+pub fn monitors() -> Task<Vec<Monitor>> {
+    task::oneshot(|channel| crate::Action::Window(Action::GetMonitors(channel)))
+}
+
+/// Gets the [`Monitor`] the window with the given [`Id`] is on, if known.
+pub fn current_monitor(id: Id) -> Task<Option<Monitor>> {
+    task::oneshot(move |channel| {
+        crate::Action::Window(Action::GetCurrentMonitor(id, channel))
+    })
+}
+
 /// Changes the [`Mode`] of the window.
 pub fn change_mode<T>(id: Id, mode: Mode) -> Task<T> {
     task::effect(crate::Action::Window(Action::ChangeMode(id, mode)))
@@ -447,6 +601,16 @@ pub fn screenshot(id: Id) -> Task<Screenshot> {
     })
 }
 
+/// Fetches the [`Stats`] of the last frame of the window.
+///
+/// This can be used to build a performance HUD, or to track rendering
+/// regressions in tests.
+pub fn stats(id: Id) -> Task<Stats> {
+    task::oneshot(move |channel| {
+        crate::Action::Window(Action::Stats(id, channel))
+    })
+}
+
 /// Enables mouse passthrough for the given window.
 ///
 /// This disables mouse events for the window and passes mouse events
@@ -463,6 +627,23 @@ pub fn disable_mouse_passthrough<Message>(id: Id) -> Task<Message> {
     task::effect(crate::Action::Window(Action::DisableMousePassthrough(id)))
 }
 
+/// Sets the [`CursorGrab`] mode of the window with the given [`Id`].
+///
+/// While the cursor is grabbed, the window will receive
+/// [`mouse::Event::RawMotion`] events. If locking the cursor is not
+/// supported, it will be confined instead.
+///
+/// [`mouse::Event::RawMotion`]: crate::core::mouse::Event::RawMotion
+pub fn set_cursor_grab<T>(id: Id, mode: CursorGrab) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetCursorGrab(id, mode)))
+}
+
+/// Sets whether the mouse cursor is visible over the window with the
+/// given [`Id`].
+pub fn set_cursor_visible<T>(id: Id, visible: bool) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetCursorVisible(id, visible)))
+}
+
 /// Enable the blur effect for a window.
 ///
 /// This is only supported on platforms that support window blur.
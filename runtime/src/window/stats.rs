@@ -0,0 +1,70 @@
+//! Measure the frames of a window.
+use crate::core::renderer;
+use crate::core::time::Duration;
+use crate::core::window::Id;
+use crate::futures::futures::channel::mpsc;
+use crate::futures::Subscription;
+
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// Statistics about the last frame of a window, captured with
+/// `window::stats()` or [`listen`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Stats {
+    /// The time spent building and laying out the user interface of the
+    /// window the last time it changed.
+    pub layout: Duration,
+
+    /// The time spent drawing the user interface of the window.
+    pub draw: Duration,
+
+    /// The time spent presenting the frame to the window surface.
+    pub present: Duration,
+
+    /// The statistics of the renderer of the window.
+    pub renderer: renderer::Stats,
+}
+
+/// The senders of the [`listen`] subscriptions currently alive.
+static LISTENERS: Mutex<Vec<mpsc::UnboundedSender<(Id, Stats)>>> =
+    Mutex::new(Vec::new());
+
+/// Subscribes to the [`Stats`] of every frame presented by the windows of
+/// the application.
+///
+/// The [`Stats`] of a frame are only computed while a [`Subscription`]
+/// returned by this function is alive.
+pub fn listen() -> Subscription<(Id, Stats)> {
+    Subscription::run(listener)
+}
+
+fn listener() -> mpsc::UnboundedReceiver<(Id, Stats)> {
+    let (sender, receiver) = mpsc::unbounded();
+
+    listeners().push(sender);
+
+    receiver
+}
+
+/// Returns whether any [`listen`] subscription is alive.
+///
+/// Runtimes should only compute the [`Stats`] of a frame, and [`notify`]
+/// them, when this is true.
+pub fn is_listened() -> bool {
+    let mut listeners = listeners();
+    listeners.retain(|listener| !listener.is_closed());
+
+    !listeners.is_empty()
+}
+
+/// Sends the [`Stats`] of a frame of the given window to every [`listen`]
+/// subscription.
+pub fn notify(id: Id, stats: Stats) {
+    listeners().retain(|listener| {
+        listener.unbounded_send((id, stats.clone())).is_ok()
+    });
+}
+
+fn listeners() -> MutexGuard<'static, Vec<mpsc::UnboundedSender<(Id, Stats)>>> {
+    LISTENERS.lock().unwrap_or_else(PoisonError::into_inner)
+}
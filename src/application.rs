@@ -212,6 +212,40 @@ impl<P: Program> Application<P> {
         self
     }
 
+    /// Runs the [`Application`] as a single instance with the given identifier.
+    ///
+    /// Launching the [`Application`] again while it is running forwards the
+    /// command-line arguments to the running instance and exits. The
+    /// forwarded arguments can be received with [`instance::listen`].
+    ///
+    /// [`instance::listen`]: crate::instance::listen
+    pub fn single_instance(self, id: impl Into<String>) -> Self {
+        Self {
+            settings: Settings {
+                id: Some(id.into()),
+                single_instance: true,
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
+    /// Records the traces of the [`Application`] to the given [`Trace`]
+    /// destination.
+    ///
+    /// Traces are only recorded when the `debug` feature is enabled.
+    ///
+    /// [`Trace`]: crate::settings::Trace
+    pub fn trace(self, trace: crate::settings::Trace) -> Self {
+        Self {
+            settings: Settings {
+                trace: Some(trace),
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
     /// Sets the [`window::Settings`] of the [`Application`].
     ///
     /// Overwrites any previous [`window::Settings`].
@@ -542,8 +542,13 @@ pub mod task {
 pub mod clipboard {
     //! Access the clipboard.
     pub use crate::runtime::clipboard::{
-        read, read_data, read_primary, read_primary_data, write, write_data,
-        write_primary, write_primary_data,
+        read, read_data, read_html, read_mime_types, read_primary,
+        read_primary_data, read_primary_mime_types, write, write_data,
+        write_html, write_primary, write_primary_data, write_primary_html,
+    };
+    #[cfg(feature = "image")]
+    pub use crate::runtime::clipboard::{
+        read_image, read_primary_image, write_image, write_primary_image, Image,
     };
     pub use dnd;
     pub use mime;
@@ -572,6 +577,22 @@ pub mod font {
     pub use crate::runtime::font::*;
 }
 
+pub mod dialog {
+    //! Show native file, folder and message dialogs.
+    //!
+    //! A fallback built from widgets is available in [`widget::dialog`].
+    //!
+    //! [`widget::dialog`]: crate::widget::dialog
+    pub use crate::runtime::dialog::*;
+}
+
+pub mod notification {
+    //! Show desktop notifications.
+    pub use crate::runtime::notification::*;
+    #[cfg(any(feature = "winit", feature = "wayland"))]
+    pub use crate::shell::notification::listen;
+}
+
 pub mod event {
     //! Handle events of a user interface.
     #[cfg(feature = "wayland")]
@@ -581,17 +602,25 @@ pub mod event {
     pub use iced_futures::event::{listen, listen_raw, listen_with};
 }
 
+#[cfg(any(feature = "winit", feature = "wayland"))]
+pub mod instance {
+    //! Run a single instance of an application.
+    pub use crate::shell::instance::{acquire, listen, Instance};
+}
+
 pub mod keyboard {
     //! Listen and react to keyboard events.
-    pub use crate::core::keyboard::key;
-    pub use crate::core::keyboard::{Event, Key, Location, Modifiers};
+    pub use crate::core::keyboard::{key, shortcut};
+    pub use crate::core::keyboard::{
+        Event, Key, Location, Modifiers, Shortcut,
+    };
     pub use iced_futures::keyboard::{on_key_press, on_key_release};
 }
 
 pub mod mouse {
     //! Listen and react to mouse events.
     pub use crate::core::mouse::{
-        Button, Cursor, Event, Interaction, ScrollDelta,
+        Button, Cursor, CustomCursor, Event, Interaction, ScrollDelta,
     };
 }
 
@@ -603,6 +632,13 @@ pub mod system {
     pub use crate::shell::system::*;
 }
 
+pub mod tray {
+    //! Show an icon in the system tray.
+    pub use crate::runtime::tray::*;
+    #[cfg(any(feature = "winit", feature = "wayland"))]
+    pub use crate::shell::tray::listen;
+}
+
 pub mod overlay {
     //! Display interactive elements on top of other widgets.
 
@@ -192,6 +192,8 @@ pub trait Program: Sized {
                 antialiasing: settings.antialiasing,
                 exit_on_close_request: settings.exit_on_close_request,
                 is_daemon: settings.exit_on_close_request,
+                single_instance: settings.single_instance,
+                trace: settings.trace,
             }
             .into(),
             renderer_settings,
@@ -3,6 +3,8 @@ use crate::{Font, Pixels};
 
 use std::borrow::Cow;
 
+pub use crate::runtime::Trace;
+
 /// The settings of an iced program.
 #[derive(Debug, Clone)]
 pub struct Settings {
@@ -38,6 +40,24 @@ pub struct Settings {
 
     /// Whether the application is a daemon
     pub is_daemon: bool,
+
+    /// Whether only a single instance of the application may run at a time.
+    ///
+    /// If enabled, launching the application while it is already running
+    /// forwards the command-line arguments to the running instance, which
+    /// can receive them with [`instance::listen`], and exits.
+    ///
+    /// It requires an [`id`](Self::id).
+    ///
+    /// [`instance::listen`]: crate::instance::listen
+    pub single_instance: bool,
+
+    /// Where to record the traces of the application, if anywhere.
+    ///
+    /// The `ICED_TRACE` environment variable takes precedence over this
+    /// setting. Traces are only recorded when the `debug` feature is
+    /// enabled.
+    pub trace: Option<Trace>,
 }
 
 impl Default for Settings {
@@ -50,6 +70,8 @@ impl Default for Settings {
             antialiasing: false,
             exit_on_close_request: false,
             is_daemon: false,
+            single_instance: false,
+            trace: None,
         }
     }
 }
@@ -61,6 +83,8 @@ impl From<Settings> for iced_winit::Settings {
             id: settings.id,
             fonts: settings.fonts,
             is_daemon: settings.is_daemon,
+            single_instance: settings.single_instance,
+            trace: settings.trace,
         }
     }
 }
@@ -413,6 +413,7 @@ impl Engine {
                 horizontal_alignment,
                 vertical_alignment,
                 shaping,
+                ellipsize,
                 clip_bounds: text_bounds, // TODO
             } => {
                 let physical_bounds = *text_bounds * transformation;
@@ -435,6 +436,7 @@ impl Engine {
                     *horizontal_alignment,
                     *vertical_alignment,
                     *shaping,
+                    *ellipsize,
                     pixels,
                     clip_mask,
                     transformation,
@@ -639,6 +641,14 @@ impl Engine {
         }
     }
 
+    pub fn cached_buffers(&self) -> usize {
+        self.text_pipeline.cached_buffers()
+    }
+
+    pub fn cached_glyphs(&self) -> usize {
+        self.text_pipeline.cached_glyphs()
+    }
+
     pub fn trim(&mut self) {
         self.text_pipeline.trim_cache();
 
@@ -655,7 +665,7 @@ pub fn into_color(color: Color) -> tiny_skia::Color {
         .expect("Convert color from iced to tiny_skia")
 }
 
-fn into_transform(transformation: Transformation) -> tiny_skia::Transform {
+pub fn into_transform(transformation: Transformation) -> tiny_skia::Transform {
     let translation = transformation.translation();
 
     tiny_skia::Transform {
@@ -668,7 +678,7 @@ fn into_transform(transformation: Transformation) -> tiny_skia::Transform {
     }
 }
 
-fn rounded_rectangle(
+pub fn rounded_rectangle(
     bounds: Rectangle,
     border_radius: [f32; 4],
 ) -> tiny_skia::Path {
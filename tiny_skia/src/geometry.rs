@@ -1,4 +1,4 @@
-use crate::core::text::LineHeight;
+use crate::core::text::{Ellipsize, LineHeight};
 use crate::core::{self, Pixels, Point, Radians, Rectangle, Size, Svg, Vector};
 use crate::graphics::cache::{self, Cached};
 use crate::graphics::geometry::fill::{self, Fill};
@@ -16,6 +16,7 @@ pub enum Geometry {
         images: Vec<graphics::Image>,
         primitives: Vec<Primitive>,
         clip_bounds: Rectangle,
+        masked: Vec<Masked<Geometry>>,
     },
     Cache(Cache),
 }
@@ -26,6 +27,18 @@ pub struct Cache {
     pub images: Rc<[graphics::Image]>,
     pub primitives: Rc<[Primitive]>,
     pub clip_bounds: Rectangle,
+    pub masked: Rc<[Masked<Cache>]>,
+}
+
+/// Some geometry clipped by a path.
+#[derive(Debug, Clone)]
+pub struct Masked<T> {
+    /// The path of the mask.
+    pub mask: tiny_skia::Path,
+    /// The bounds of the mask.
+    pub bounds: Rectangle,
+    /// The clipped geometry.
+    pub geometry: T,
 }
 
 impl Cached for Geometry {
@@ -35,18 +48,27 @@ impl Cached for Geometry {
         Self::Cache(cache.clone())
     }
 
-    fn cache(self, _group: cache::Group, _previous: Option<Cache>) -> Cache {
+    fn cache(self, group: cache::Group, _previous: Option<Cache>) -> Cache {
         match self {
             Self::Live {
                 primitives,
                 images,
                 text,
                 clip_bounds,
+                masked,
             } => Cache {
                 primitives: Rc::from(primitives),
                 images: Rc::from(images),
                 text: Rc::from(text),
                 clip_bounds,
+                masked: masked
+                    .into_iter()
+                    .map(|masked| Masked {
+                        mask: masked.mask,
+                        bounds: masked.bounds,
+                        geometry: masked.geometry.cache(group, None),
+                    })
+                    .collect(),
             },
             Self::Cache(cache) => cache,
         }
@@ -61,6 +83,8 @@ pub struct Frame {
     primitives: Vec<Primitive>,
     images: Vec<graphics::Image>,
     text: Vec<Text>,
+    mask: Option<Option<tiny_skia::Path>>,
+    masked: Vec<Masked<Geometry>>,
 }
 
 impl Frame {
@@ -75,6 +99,8 @@ impl Frame {
             primitives: Vec::new(),
             images: Vec::new(),
             text: Vec::new(),
+            mask: None,
+            masked: Vec::new(),
             transform: tiny_skia::Transform::from_translate(
                 clip_bounds.x,
                 clip_bounds.y,
@@ -234,6 +260,7 @@ impl geometry::frame::Backend for Frame {
                 horizontal_alignment: text.horizontal_alignment,
                 vertical_alignment: text.vertical_alignment,
                 shaping: text.shaping,
+                ellipsize: Ellipsize::None,
                 clip_bounds: Rectangle::with_size(Size::INFINITY),
             });
         } else {
@@ -253,10 +280,41 @@ impl geometry::frame::Backend for Frame {
         Self::with_clip(clip_bounds)
     }
 
-    fn paste(&mut self, frame: Self) {
+    fn draft_with_mask(&mut self, mask: &Path) -> Self {
+        let mut frame = Self::with_clip(self.clip_bounds);
+        frame.transform = self.transform;
+        frame.mask = Some(
+            convert_path(mask).and_then(|path| path.transform(self.transform)),
+        );
+
+        frame
+    }
+
+    fn paste(&mut self, mut frame: Self) {
+        if let Some(mask) = frame.mask.take() {
+            // An empty mask hides everything
+            if let Some(mask) = mask {
+                let bounds = mask.bounds();
+
+                self.masked.push(Masked {
+                    bounds: Rectangle {
+                        x: bounds.x(),
+                        y: bounds.y(),
+                        width: bounds.width(),
+                        height: bounds.height(),
+                    },
+                    mask,
+                    geometry: frame.into_geometry(),
+                });
+            }
+
+            return;
+        }
+
         self.primitives.extend(frame.primitives);
         self.text.extend(frame.text);
         self.images.extend(frame.images);
+        self.masked.extend(frame.masked);
     }
 
     fn translate(&mut self, translation: Vector) {
@@ -288,6 +346,7 @@ impl geometry::frame::Backend for Frame {
             images: self.images,
             text: self.text,
             clip_bounds: self.clip_bounds,
+            masked: self.masked,
         }
     }
 
@@ -2,6 +2,7 @@ use crate::core::renderer::Quad;
 use crate::core::{
     self, Background, Color, Point, Rectangle, Svg, Transformation,
 };
+use crate::engine;
 use crate::graphics::damage;
 use crate::graphics::layer;
 use crate::graphics::text::{Editor, Paragraph, Raw, Text};
@@ -15,6 +16,7 @@ pub type Stack = layer::Stack<Layer>;
 #[derive(Debug, Clone)]
 pub struct Layer {
     pub bounds: Rectangle,
+    pub masks: Vec<Mask>,
     pub quads: Vec<(Quad, Background)>,
     pub primitives: Vec<Item<Primitive>>,
     pub text: Vec<Item<Text>>,
@@ -89,6 +91,7 @@ impl Layer {
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
             shaping: text.shaping,
+            ellipsize: text.ellipsize,
             clip_bounds: clip_bounds * transformation,
         };
 
@@ -197,7 +200,8 @@ impl Layer {
     }
 
     pub fn damage(previous: &Self, current: &Self) -> Vec<Rectangle> {
-        if previous.bounds != current.bounds {
+        if previous.bounds != current.bounds || previous.masks != current.masks
+        {
             return vec![previous.bounds, current.bounds];
         }
 
@@ -290,6 +294,7 @@ impl Default for Layer {
     fn default() -> Self {
         Self {
             bounds: Rectangle::INFINITE,
+            masks: Vec::new(),
             quads: Vec::new(),
             primitives: Vec::new(),
             text: Vec::new(),
@@ -299,6 +304,8 @@ impl Default for Layer {
 }
 
 impl graphics::Layer for Layer {
+    type Mask = Mask;
+
     fn with_bounds(bounds: Rectangle) -> Self {
         Self {
             bounds,
@@ -306,6 +313,11 @@ impl graphics::Layer for Layer {
         }
     }
 
+    fn set_masks(&mut self, masks: &[Mask]) {
+        self.masks.clear();
+        self.masks.extend_from_slice(masks);
+    }
+
     fn flush(&mut self) {}
 
     fn resize(&mut self, bounds: Rectangle) {
@@ -314,6 +326,7 @@ impl graphics::Layer for Layer {
 
     fn reset(&mut self) {
         self.bounds = Rectangle::INFINITE;
+        self.masks.clear();
 
         self.quads.clear();
         self.primitives.clear();
@@ -322,6 +335,58 @@ impl graphics::Layer for Layer {
     }
 }
 
+/// The clipping mask of a [`Layer`], applied on top of its bounds.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Mask {
+    /// A rectangle with rounded corners.
+    Rounded {
+        /// The bounds of the rectangle.
+        bounds: Rectangle,
+        /// The border radius of each corner of the rectangle.
+        border_radius: [f32; 4],
+    },
+    /// An arbitrary path.
+    Path {
+        /// The path to fill.
+        path: tiny_skia::Path,
+        /// The [`Transformation`] of the path.
+        transformation: Transformation,
+    },
+}
+
+impl Mask {
+    /// Returns the physical path of the [`Mask`] for the given scale factor.
+    pub fn to_path(&self, scale_factor: f32) -> Option<tiny_skia::Path> {
+        match self {
+            Mask::Rounded {
+                bounds,
+                border_radius,
+            } => {
+                let bounds = *bounds * scale_factor;
+
+                if bounds.width < 1.0 || bounds.height < 1.0 {
+                    return None;
+                }
+
+                Some(engine::rounded_rectangle(
+                    bounds,
+                    border_radius.map(|radius| {
+                        (radius * scale_factor)
+                            .min(bounds.width / 2.0)
+                            .min(bounds.height / 2.0)
+                    }),
+                ))
+            }
+            Mask::Path {
+                path,
+                transformation,
+            } => path.clone().transform(engine::into_transform(
+                *transformation * Transformation::scale(scale_factor),
+            )),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum Item<T> {
     Live(T),
@@ -353,4 +418,8 @@ impl<T> Item<T> {
             Item::Cached(cache, _, _) => cache,
         }
     }
+
+    pub fn count(&self) -> usize {
+        self.as_slice().len()
+    }
 }
@@ -46,6 +46,7 @@ pub struct Renderer {
     default_text_size: Pixels,
     layers: layer::Stack,
     engine: Engine, // TODO: Shared engine
+    masking: Option<Masking>,
 }
 
 impl Renderer {
@@ -55,6 +56,7 @@ impl Renderer {
             default_text_size,
             layers: layer::Stack::new(),
             engine: Engine::new(),
+            masking: None,
         }
     }
 
@@ -141,65 +143,86 @@ impl Renderer {
                     continue;
                 };
 
-                engine::adjust_clip_mask(clip_mask, clip_bounds);
-
-                for (quad, background) in &layer.quads {
-                    self.engine.draw_quad(
-                        quad,
-                        background,
-                        Transformation::scale(scale_factor),
+                if layer.masks.is_empty() {
+                    draw_layer(
+                        &mut self.engine,
+                        layer,
                         pixels,
                         clip_mask,
                         clip_bounds,
+                        scale_factor,
                     );
+
+                    continue;
                 }
 
-                for group in &layer.primitives {
-                    let Some(new_clip_bounds) = (group.clip_bounds()
-                        * scale_factor)
-                        .intersection(&clip_bounds)
-                    else {
-                        continue;
-                    };
-
-                    engine::adjust_clip_mask(clip_mask, new_clip_bounds);
-
-                    for primitive in group.as_slice() {
-                        self.engine.draw_primitive(
-                            primitive,
-                            group.transformation()
-                                * Transformation::scale(scale_factor),
-                            pixels,
-                            clip_mask,
-                            clip_bounds,
-                        );
-                    }
-
-                    engine::adjust_clip_mask(clip_mask, clip_bounds);
+                let Some(paths) = layer
+                    .masks
+                    .iter()
+                    .map(|mask| mask.to_path(scale_factor))
+                    .collect::<Option<Vec<_>>>()
+                else {
+                    continue;
+                };
+
+                if self.masking.as_ref().map_or(true, |masking| {
+                    masking.pixmap.width() != pixels.width()
+                        || masking.pixmap.height() != pixels.height()
+                }) {
+                    self.masking =
+                        Some(Masking::new(pixels.width(), pixels.height()));
                 }
 
-                for image in &layer.images {
-                    self.engine.draw_image(
-                        image,
-                        Transformation::scale(scale_factor),
-                        pixels,
-                        clip_mask,
-                        clip_bounds,
+                let masking = self.masking.as_mut().unwrap();
+
+                let Some(clip_rect) = tiny_skia::Rect::from_xywh(
+                    clip_bounds.x,
+                    clip_bounds.y,
+                    clip_bounds.width,
+                    clip_bounds.height,
+                ) else {
+                    continue;
+                };
+
+                masking.pixmap.fill_rect(
+                    clip_rect,
+                    &tiny_skia::Paint {
+                        blend_mode: tiny_skia::BlendMode::Clear,
+                        anti_alias: false,
+                        ..Default::default()
+                    },
+                    tiny_skia::Transform::identity(),
+                    None,
+                );
+
+                draw_layer(
+                    &mut self.engine,
+                    layer,
+                    &mut masking.pixmap.as_mut(),
+                    clip_mask,
+                    clip_bounds,
+                    scale_factor,
+                );
+
+                engine::adjust_clip_mask(&mut masking.mask, clip_bounds);
+
+                for path in &paths {
+                    masking.mask.intersect_path(
+                        path,
+                        tiny_skia::FillRule::Winding,
+                        true,
+                        tiny_skia::Transform::identity(),
                     );
                 }
 
-                for group in &layer.text {
-                    for text in group.as_slice() {
-                        self.engine.draw_text(
-                            text,
-                            group.transformation()
-                                * Transformation::scale(scale_factor),
-                            pixels,
-                            clip_mask,
-                            clip_bounds,
-                        );
-                    }
-                }
+                pixels.draw_pixmap(
+                    0,
+                    0,
+                    masking.pixmap.as_ref(),
+                    &tiny_skia::PixmapPaint::default(),
+                    tiny_skia::Transform::identity(),
+                    Some(&masking.mask),
+                );
             }
 
             if !overlay.is_empty() {
@@ -224,6 +247,122 @@ impl Renderer {
 
         self.engine.trim();
     }
+
+    /// Computes the [`Stats`] of the last frame drawn by the [`Renderer`].
+    ///
+    /// The [`Stats`] are only computed on demand; so drawing a frame does not
+    /// pay for them. They do not need a window either, which makes them
+    /// useful to track rendering regressions in headless tests.
+    ///
+    /// [`Stats`]: renderer::Stats
+    pub fn stats(&self) -> renderer::Stats {
+        use crate::core::renderer::stats;
+
+        let layers = self
+            .layers
+            .iter()
+            .map(|layer| stats::Layer {
+                bounds: layer.bounds,
+                quads: layer.quads.len(),
+                meshes: 0,
+                paths: layer.primitives.iter().map(layer::Item::count).sum(),
+                text: layer.text.iter().map(layer::Item::count).sum(),
+                images: layer.images.len(),
+                primitives: 0,
+            })
+            .collect();
+
+        renderer::Stats {
+            layers,
+            atlas: None,
+            text_cache: self.engine.cached_buffers(),
+            glyph_cache: Some(self.engine.cached_glyphs()),
+        }
+    }
+}
+
+/// An offscreen surface used to draw layers with a [`layer::Mask`].
+#[derive(Debug)]
+struct Masking {
+    pixmap: tiny_skia::Pixmap,
+    mask: tiny_skia::Mask,
+}
+
+impl Masking {
+    fn new(width: u32, height: u32) -> Self {
+        Self {
+            pixmap: tiny_skia::Pixmap::new(width, height)
+                .expect("Create masking pixmap"),
+            mask: tiny_skia::Mask::new(width, height)
+                .expect("Create masking mask"),
+        }
+    }
+}
+
+fn draw_layer(
+    engine: &mut Engine,
+    layer: &Layer,
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    clip_mask: &mut tiny_skia::Mask,
+    clip_bounds: Rectangle,
+    scale_factor: f32,
+) {
+    engine::adjust_clip_mask(clip_mask, clip_bounds);
+
+    for (quad, background) in &layer.quads {
+        engine.draw_quad(
+            quad,
+            background,
+            Transformation::scale(scale_factor),
+            pixels,
+            clip_mask,
+            clip_bounds,
+        );
+    }
+
+    for group in &layer.primitives {
+        let Some(new_clip_bounds) =
+            (group.clip_bounds() * scale_factor).intersection(&clip_bounds)
+        else {
+            continue;
+        };
+
+        engine::adjust_clip_mask(clip_mask, new_clip_bounds);
+
+        for primitive in group.as_slice() {
+            engine.draw_primitive(
+                primitive,
+                group.transformation() * Transformation::scale(scale_factor),
+                pixels,
+                clip_mask,
+                clip_bounds,
+            );
+        }
+
+        engine::adjust_clip_mask(clip_mask, clip_bounds);
+    }
+
+    for image in &layer.images {
+        engine.draw_image(
+            image,
+            Transformation::scale(scale_factor),
+            pixels,
+            clip_mask,
+            clip_bounds,
+        );
+    }
+
+    for group in &layer.text {
+        for text in group.as_slice() {
+            engine.draw_text(
+                text,
+                group.transformation() * Transformation::scale(scale_factor),
+                pixels,
+                clip_mask,
+                clip_bounds,
+            );
+        }
+    }
 }
 
 impl core::Renderer for Renderer {
@@ -231,6 +370,23 @@ impl core::Renderer for Renderer {
         self.layers.push_clip(bounds);
     }
 
+    fn start_rounded_layer(
+        &mut self,
+        bounds: Rectangle,
+        border_radius: core::border::Radius,
+    ) {
+        let transformation = self.layers.transformation();
+
+        self.layers.push_mask(
+            bounds,
+            layer::Mask::Rounded {
+                bounds: bounds * transformation,
+                border_radius: <[f32; 4]>::from(border_radius)
+                    .map(|radius| radius * transformation.scale_factor()),
+            },
+        );
+    }
+
     fn end_layer(&mut self) {
         self.layers.pop_clip();
     }
@@ -283,6 +439,21 @@ impl core::text::Renderer for Renderer {
         clip_bounds: Rectangle,
     ) {
         let (layer, transformation) = self.layers.current_mut();
+
+        for decoration in text
+            .decorations(position, clip_bounds)
+            .filter(|decoration| !decoration.is_overlay)
+        {
+            layer.draw_quad(
+                renderer::Quad {
+                    bounds: decoration.bounds,
+                    ..Default::default()
+                },
+                Background::Color(decoration.color.unwrap_or(color)),
+                transformation,
+            );
+        }
+
         layer.draw_paragraph(
             text,
             position,
@@ -290,6 +461,32 @@ impl core::text::Renderer for Renderer {
             clip_bounds,
             transformation,
         );
+
+        // Strikethroughs and overlines cross the glyphs, so they are drawn
+        // in a layer on top of the text
+        let mut overlays = text
+            .decorations(position, clip_bounds)
+            .filter(|decoration| decoration.is_overlay)
+            .peekable();
+
+        if overlays.peek().is_some() {
+            self.layers.push_clip(clip_bounds);
+
+            let (layer, transformation) = self.layers.current_mut();
+
+            for decoration in overlays {
+                layer.draw_quad(
+                    renderer::Quad {
+                        bounds: decoration.bounds,
+                        ..Default::default()
+                    },
+                    Background::Color(decoration.color.unwrap_or(color)),
+                    transformation,
+                );
+            }
+
+            self.layers.pop_clip();
+        }
     }
 
     fn fill_editor(
@@ -338,6 +535,7 @@ impl graphics::geometry::Renderer for Renderer {
                 images,
                 text,
                 clip_bounds,
+                masked,
             } => {
                 layer.draw_primitive_group(
                     primitives,
@@ -350,6 +548,14 @@ impl graphics::geometry::Renderer for Renderer {
                 }
 
                 layer.draw_text_group(text, clip_bounds, transformation);
+
+                for masked in masked {
+                    self.draw_masked_geometry(
+                        masked.bounds,
+                        masked.mask,
+                        masked.geometry,
+                    );
+                }
             }
             Geometry::Cache(cache) => {
                 layer.draw_primitive_cache(
@@ -367,11 +573,45 @@ impl graphics::geometry::Renderer for Renderer {
                     cache.clip_bounds,
                     transformation,
                 );
+
+                for masked in cache.masked.iter() {
+                    self.draw_masked_geometry(
+                        masked.bounds,
+                        masked.mask.clone(),
+                        Geometry::Cache(masked.geometry.clone()),
+                    );
+                }
             }
         }
     }
 }
 
+#[cfg(feature = "geometry")]
+impl Renderer {
+    fn draw_masked_geometry(
+        &mut self,
+        bounds: Rectangle,
+        mask: tiny_skia::Path,
+        geometry: Geometry,
+    ) {
+        use crate::graphics::geometry::Renderer as _;
+
+        let transformation = self.layers.transformation();
+
+        self.layers.push_mask(
+            bounds,
+            layer::Mask::Path {
+                path: mask,
+                transformation,
+            },
+        );
+
+        self.draw_geometry(geometry);
+
+        self.layers.pop_clip();
+    }
+}
+
 impl graphics::mesh::Renderer for Renderer {
     fn draw_mesh(&mut self, _mesh: graphics::Mesh) {
         log::warn!("iced_tiny_skia does not support drawing meshes");
@@ -429,3 +669,84 @@ impl core::svg::Renderer for Renderer {
 impl compositor::Default for Renderer {
     type Compositor = window::Compositor;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::renderer::Renderer as _;
+
+    #[test]
+    fn stats_are_computed_without_a_window() {
+        let mut renderer = Renderer::new(Font::default(), Pixels(16.0));
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: 0.0,
+                    y: 0.0,
+                    width: 10.0,
+                    height: 10.0,
+                },
+                ..renderer::Quad::default()
+            },
+            Color::BLACK,
+        );
+
+        let stats = renderer.stats();
+
+        assert_eq!(stats.layers.len(), 1);
+        assert_eq!(stats.quads(), 1);
+        assert_eq!(stats.meshes(), 0);
+        assert_eq!(stats.paths(), 0);
+    }
+
+    #[test]
+    fn coloured_strikethroughs_are_drawn_above_the_text() {
+        use crate::core::alignment;
+        use crate::core::text::{self, Paragraph as _, Span};
+        use crate::core::Size;
+
+        let mut renderer = Renderer::new(Font::default(), Pixels(16.0));
+
+        let spans = [Span::<()>::new("Struck")
+            .strikethrough_with(Color::from_rgb(1.0, 0.0, 0.0))];
+
+        let paragraph = Paragraph::with_spans(core::Text {
+            content: &spans[..],
+            bounds: Size::INFINITY,
+            size: Pixels(16.0),
+            line_height: text::LineHeight::default(),
+            font: Font::default(),
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::default(),
+            ellipsize: text::Ellipsize::default(),
+        });
+
+        renderer.fill_paragraph(
+            &paragraph,
+            Point::ORIGIN,
+            Color::BLACK,
+            Rectangle::INFINITE,
+        );
+
+        let stats = renderer.stats();
+
+        let text = stats
+            .layers
+            .iter()
+            .position(|layer| layer.text > 0)
+            .expect("Find text layer");
+
+        let strikethrough = stats
+            .layers
+            .iter()
+            .position(|layer| layer.quads > 0)
+            .expect("Find strikethrough layer");
+
+        assert_eq!(stats.layers[text].quads, 0);
+        assert!(strikethrough > text);
+    }
+}
@@ -1,5 +1,5 @@
 use crate::core::alignment;
-use crate::core::text::Shaping;
+use crate::core::text::{Ellipsize, Shaping};
 use crate::core::{
     Color, Font, Pixels, Point, Rectangle, Size, Transformation,
 };
@@ -111,6 +111,7 @@ impl Pipeline {
         horizontal_alignment: alignment::Horizontal,
         vertical_alignment: alignment::Vertical,
         shaping: Shaping,
+        ellipsize: Ellipsize,
         pixels: &mut tiny_skia::PixmapMut<'_>,
         clip_mask: Option<&tiny_skia::Mask>,
         transformation: Transformation,
@@ -127,6 +128,7 @@ impl Pipeline {
             size: size.into(),
             line_height,
             shaping,
+            ellipsize,
         };
 
         let (_, entry) = self.cache.get_mut().allocate(font_system, key);
@@ -185,6 +187,14 @@ impl Pipeline {
         );
     }
 
+    pub fn cached_buffers(&self) -> usize {
+        self.cache.borrow().len()
+    }
+
+    pub fn cached_glyphs(&self) -> usize {
+        self.glyph_cache.entries.len()
+    }
+
     pub fn trim_cache(&mut self) {
         self.cache.get_mut().trim();
         self.glyph_cache.trim();
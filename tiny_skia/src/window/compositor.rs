@@ -1,4 +1,4 @@
-use crate::core::{Color, Rectangle, Size};
+use crate::core::{self, Color, Rectangle, Size};
 use crate::graphics::compositor::{self, Information};
 use crate::graphics::damage;
 use crate::graphics::error::{self, Error};
@@ -118,6 +118,10 @@ impl crate::graphics::Compositor for Compositor {
         present(renderer, surface, viewport, background_color, overlay)
     }
 
+    fn stats(&self, renderer: &Self::Renderer) -> core::renderer::Stats {
+        renderer.stats()
+    }
+
     fn screenshot<T: AsRef<str>>(
         &mut self,
         renderer: &mut Self::Renderer,
@@ -0,0 +1,473 @@
+//! Clip layers to rounded rectangles and arbitrary shapes.
+use crate::core::{Rectangle, Size, Transformation};
+use crate::triangle;
+
+use std::num::NonZeroU64;
+
+/// The maximum amount of rounded masks that can clip a single layer.
+///
+/// Deeper chains of masks only keep the innermost ones.
+const MAX_ROUNDED: usize = 4;
+
+/// The clipping mask of a [`Layer`], applied on top of its bounds.
+///
+/// [`Layer`]: crate::Layer
+#[derive(Debug, Clone)]
+pub enum Mask {
+    /// A rectangle with rounded corners.
+    Rounded {
+        /// The bounds of the rectangle.
+        bounds: Rectangle,
+        /// The border radius of each corner of the rectangle.
+        border_radius: [f32; 4],
+    },
+    /// An arbitrary shape, described by its tessellated meshes.
+    Shape {
+        /// The meshes of the shape.
+        cache: triangle::Cache,
+        /// The [`Transformation`] of the meshes.
+        transformation: Transformation,
+    },
+}
+
+impl Mask {
+    /// Returns the batch of triangles that must be drawn to produce the
+    /// shape of the given chain of masks, if any.
+    ///
+    /// Only the innermost [`Mask::Shape`] of the chain is drawn.
+    pub(crate) fn triangles(masks: &[Mask]) -> Option<triangle::Batch> {
+        masks.iter().rev().find_map(|mask| match mask {
+            Mask::Rounded { .. } => None,
+            Mask::Shape {
+                cache,
+                transformation,
+            } => Some(vec![triangle::Item::Cached {
+                transformation: *transformation,
+                cache: cache.clone(),
+            }]),
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Pipeline {
+    format: wgpu::TextureFormat,
+    pipeline: wgpu::RenderPipeline,
+    clear_pipeline: wgpu::RenderPipeline,
+    uniforms_layout: wgpu::BindGroupLayout,
+    texture_layout: wgpu::BindGroupLayout,
+    targets: Option<Targets>,
+    layers: Vec<Layer>,
+    prepare_layer: usize,
+}
+
+impl Pipeline {
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
+        let uniforms_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_wgpu::clip uniforms layout"),
+                entries: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: wgpu::BufferSize::new(
+                            std::mem::size_of::<Uniforms>() as u64,
+                        ),
+                    },
+                    count: None,
+                }],
+            });
+
+        let texture_entry = |binding| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::FRAGMENT,
+            ty: wgpu::BindingType::Texture {
+                sample_type: wgpu::TextureSampleType::Float {
+                    filterable: false,
+                },
+                view_dimension: wgpu::TextureViewDimension::D2,
+                multisampled: false,
+            },
+            count: None,
+        };
+
+        let texture_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_wgpu::clip texture layout"),
+                entries: &[texture_entry(0), texture_entry(1)],
+            });
+
+        let layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("iced_wgpu::clip pipeline layout"),
+                push_constant_ranges: &[],
+                bind_group_layouts: &[&uniforms_layout, &texture_layout],
+            });
+
+        let shader =
+            device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some("iced_wgpu clip shader"),
+                source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Borrowed(
+                    include_str!("shader/clip.wgsl"),
+                )),
+            });
+
+        let pipeline =
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("iced_wgpu::clip pipeline"),
+                layout: Some(&layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: "vs_main",
+                    buffers: &[],
+                    compilation_options:
+                        wgpu::PipelineCompilationOptions::default(),
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: "fs_main",
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend: Some(
+                            wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING,
+                        ),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                    compilation_options:
+                        wgpu::PipelineCompilationOptions::default(),
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    front_face: wgpu::FrontFace::Cw,
+                    ..Default::default()
+                },
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState {
+                    count: 1,
+                    mask: !0,
+                    alpha_to_coverage_enabled: false,
+                },
+                multiview: None,
+                cache: None,
+            });
+
+        let clear_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("iced_wgpu::clip clear pipeline layout"),
+                push_constant_ranges: &[],
+                bind_group_layouts: &[],
+            });
+
+        let clear_pipeline =
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("iced_wgpu::clip clear pipeline"),
+                layout: Some(&clear_layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: "vs_main",
+                    buffers: &[],
+                    compilation_options:
+                        wgpu::PipelineCompilationOptions::default(),
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: "fs_clear",
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend: Some(wgpu::BlendState::REPLACE),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                    compilation_options:
+                        wgpu::PipelineCompilationOptions::default(),
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    front_face: wgpu::FrontFace::Cw,
+                    ..Default::default()
+                },
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState {
+                    count: 1,
+                    mask: !0,
+                    alpha_to_coverage_enabled: false,
+                },
+                multiview: None,
+                cache: None,
+            });
+
+        Self {
+            format,
+            pipeline,
+            clear_pipeline,
+            uniforms_layout,
+            texture_layout,
+            targets: None,
+            layers: Vec::new(),
+            prepare_layer: 0,
+        }
+    }
+
+    pub fn prepare(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        belt: &mut wgpu::util::StagingBelt,
+        masks: &[Mask],
+        scale_factor: f32,
+        target_size: Size<u32>,
+    ) {
+        match &self.targets {
+            Some(targets) if targets.size == target_size => {}
+            _ => {
+                self.targets = Some(Targets::new(
+                    device,
+                    self.format,
+                    &self.texture_layout,
+                    target_size,
+                ));
+            }
+        }
+
+        if self.layers.len() <= self.prepare_layer {
+            self.layers.push(Layer::new(device, &self.uniforms_layout));
+        }
+
+        let mut uniforms = Uniforms {
+            bounds: [[0.0; 4]; MAX_ROUNDED],
+            border_radius: [[0.0; 4]; MAX_ROUNDED],
+            rounded: 0,
+            shape: u32::from(Mask::triangles(masks).is_some()),
+            _padding: [0; 2],
+        };
+
+        let rounded = masks.iter().rev().filter_map(|mask| match mask {
+            Mask::Rounded {
+                bounds,
+                border_radius,
+            } => Some((*bounds * scale_factor, border_radius)),
+            Mask::Shape { .. } => None,
+        });
+
+        for (i, (bounds, border_radius)) in
+            rounded.take(MAX_ROUNDED).enumerate()
+        {
+            uniforms.bounds[i] =
+                [bounds.x, bounds.y, bounds.width, bounds.height];
+            uniforms.border_radius[i] = border_radius.map(|radius| {
+                (radius * scale_factor)
+                    .min(bounds.width / 2.0)
+                    .min(bounds.height / 2.0)
+            });
+            uniforms.rounded += 1;
+        }
+
+        let layer = &self.layers[self.prepare_layer];
+
+        belt.write_buffer(
+            encoder,
+            &layer.uniforms,
+            0,
+            NonZeroU64::new(std::mem::size_of::<Uniforms>() as u64)
+                .expect("non-empty uniforms"),
+            device,
+        )
+        .copy_from_slice(bytemuck::bytes_of(&uniforms));
+
+        self.prepare_layer += 1;
+    }
+
+    /// Returns the texture views where the contents and the shape of a masked
+    /// layer must be drawn, respectively.
+    pub fn targets(&self) -> (&wgpu::TextureView, &wgpu::TextureView) {
+        let targets = self.targets.as_ref().unwrap();
+
+        (&targets.content, &targets.mask)
+    }
+
+    pub fn render(
+        &self,
+        layer: usize,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::TextureView,
+        scissor_rect: Rectangle<u32>,
+    ) {
+        let Some(layer) = self.layers.get(layer) else {
+            return;
+        };
+
+        let mut render_pass =
+            encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("iced_wgpu::clip render pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: target,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Load,
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+
+        render_pass.set_scissor_rect(
+            scissor_rect.x,
+            scissor_rect.y,
+            scissor_rect.width,
+            scissor_rect.height,
+        );
+        render_pass.set_pipeline(&self.pipeline);
+        render_pass.set_bind_group(0, &layer.bind_group, &[]);
+        render_pass.set_bind_group(
+            1,
+            &self.targets.as_ref().unwrap().bind_group,
+            &[],
+        );
+        render_pass.draw(0..6, 0..1);
+    }
+
+    /// Clears the given region of a texture view, making it fully
+    /// transparent.
+    pub fn clear(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::TextureView,
+        scissor_rect: Rectangle<u32>,
+    ) {
+        let mut render_pass =
+            encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("iced_wgpu::clip clear pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: target,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Load,
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+
+        render_pass.set_scissor_rect(
+            scissor_rect.x,
+            scissor_rect.y,
+            scissor_rect.width,
+            scissor_rect.height,
+        );
+        render_pass.set_pipeline(&self.clear_pipeline);
+        render_pass.draw(0..6, 0..1);
+    }
+
+    pub fn end_frame(&mut self) {
+        self.prepare_layer = 0;
+    }
+}
+
+#[derive(Debug)]
+struct Layer {
+    uniforms: wgpu::Buffer,
+    bind_group: wgpu::BindGroup,
+}
+
+impl Layer {
+    fn new(device: &wgpu::Device, layout: &wgpu::BindGroupLayout) -> Self {
+        let uniforms = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("iced_wgpu::clip uniforms buffer"),
+            size: std::mem::size_of::<Uniforms>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("iced_wgpu::clip uniforms bind group"),
+            layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: uniforms.as_entire_binding(),
+            }],
+        });
+
+        Self {
+            uniforms,
+            bind_group,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Targets {
+    content: wgpu::TextureView,
+    mask: wgpu::TextureView,
+    bind_group: wgpu::BindGroup,
+    size: Size<u32>,
+}
+
+impl Targets {
+    fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        layout: &wgpu::BindGroupLayout,
+        size: Size<u32>,
+    ) -> Self {
+        let create_view = |label| {
+            device
+                .create_texture(&wgpu::TextureDescriptor {
+                    label: Some(label),
+                    size: wgpu::Extent3d {
+                        width: size.width,
+                        height: size.height,
+                        depth_or_array_layers: 1,
+                    },
+                    mip_level_count: 1,
+                    sample_count: 1,
+                    dimension: wgpu::TextureDimension::D2,
+                    format,
+                    usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                        | wgpu::TextureUsages::TEXTURE_BINDING,
+                    view_formats: &[],
+                })
+                .create_view(&wgpu::TextureViewDescriptor::default())
+        };
+
+        let content = create_view("iced_wgpu::clip content texture");
+        let mask = create_view("iced_wgpu::clip mask texture");
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("iced_wgpu::clip texture bind group"),
+            layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&content),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(&mask),
+                },
+            ],
+        });
+
+        Self {
+            content,
+            mask,
+            bind_group,
+            size,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+struct Uniforms {
+    bounds: [[f32; 4]; MAX_ROUNDED],
+    border_radius: [[f32; 4]; MAX_ROUNDED],
+    rounded: u32,
+    shape: u32,
+    // Uniforms must be aligned to 16 bytes
+    _padding: [u32; 2],
+}
@@ -1,4 +1,5 @@
 use crate::buffer;
+use crate::clip;
 use crate::graphics::Antialiasing;
 use crate::primitive;
 use crate::quad;
@@ -13,6 +14,7 @@ pub struct Engine {
     pub(crate) quad_pipeline: quad::Pipeline,
     pub(crate) text_pipeline: text::Pipeline,
     pub(crate) triangle_pipeline: triangle::Pipeline,
+    pub(crate) clip_pipeline: clip::Pipeline,
     #[cfg(any(feature = "image", feature = "svg"))]
     pub(crate) image_pipeline: crate::image::Pipeline,
     pub(crate) primitive_storage: primitive::Storage,
@@ -30,6 +32,7 @@ impl Engine {
         let quad_pipeline = quad::Pipeline::new(device, format);
         let triangle_pipeline =
             triangle::Pipeline::new(device, format, antialiasing);
+        let clip_pipeline = clip::Pipeline::new(device, format);
 
         #[cfg(any(feature = "image", feature = "svg"))]
         let image_pipeline = {
@@ -50,6 +53,7 @@ impl Engine {
             quad_pipeline,
             text_pipeline,
             triangle_pipeline,
+            clip_pipeline,
 
             #[cfg(any(feature = "image", feature = "svg"))]
             image_pipeline,
@@ -78,6 +82,7 @@ impl Engine {
         self.quad_pipeline.end_frame();
         self.text_pipeline.end_frame();
         self.triangle_pipeline.end_frame();
+        self.clip_pipeline.end_frame();
 
         #[cfg(any(feature = "image", feature = "svg"))]
         self.image_pipeline.end_frame();
@@ -1,7 +1,8 @@
 //! Build and draw geometry.
-use crate::core::text::LineHeight;
+use crate::core::text::{Ellipsize, LineHeight};
 use crate::core::{
-    self, Pixels, Point, Radians, Rectangle, Size, Svg, Transformation, Vector,
+    self, Color, Pixels, Point, Radians, Rectangle, Size, Svg, Transformation,
+    Vector,
 };
 use crate::graphics::cache::{self, Cached};
 use crate::graphics::color;
@@ -27,15 +28,37 @@ pub enum Geometry {
         meshes: Vec<Mesh>,
         images: Vec<Image>,
         text: Vec<Text>,
+        masked: Vec<Masked>,
     },
     Cached(Cache),
 }
 
+/// Some [`Geometry`] clipped by a mask.
+#[derive(Debug)]
+pub struct Masked {
+    /// The [`Mesh`] describing the shape of the mask.
+    pub mask: Mesh,
+    /// The clipped [`Geometry`].
+    pub geometry: Geometry,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct Cache {
     pub meshes: Option<triangle::Cache>,
     pub images: Option<Arc<[Image]>>,
     pub text: Option<text::Cache>,
+    pub masked: Option<Arc<[MaskedCache]>>,
+}
+
+/// The cached version of some [`Masked`] geometry.
+#[derive(Debug, Clone)]
+pub struct MaskedCache {
+    /// The bounds of the mask.
+    pub bounds: Rectangle,
+    /// The cached shape of the mask.
+    pub mask: triangle::Cache,
+    /// The cached geometry.
+    pub cache: Cache,
 }
 
 impl Cached for Geometry {
@@ -55,6 +78,7 @@ impl Cached for Geometry {
                 meshes,
                 images,
                 text,
+                masked,
             } => {
                 let images = if images.is_empty() {
                     None
@@ -62,6 +86,25 @@ impl Cached for Geometry {
                     Some(Arc::from(images))
                 };
 
+                let masked = if masked.is_empty() {
+                    None
+                } else {
+                    Some(
+                        masked
+                            .into_iter()
+                            .filter_map(|Masked { mask, geometry }| {
+                                let bounds = mask.clip_bounds();
+
+                                Some(MaskedCache {
+                                    bounds,
+                                    mask: triangle::Cache::new(vec![mask])?,
+                                    cache: geometry.cache(group, None),
+                                })
+                            })
+                            .collect(),
+                    )
+                };
+
                 if let Some(mut previous) = previous {
                     if let Some(cache) = &mut previous.meshes {
                         cache.update(meshes);
@@ -76,6 +119,7 @@ impl Cached for Geometry {
                     }
 
                     previous.images = images;
+                    previous.masked = masked;
 
                     previous
                 } else {
@@ -83,6 +127,7 @@ impl Cached for Geometry {
                         meshes: triangle::Cache::new(meshes),
                         images,
                         text: text::Cache::new(group, text),
+                        masked,
                     }
                 }
             }
@@ -99,6 +144,8 @@ pub struct Frame {
     meshes: Vec<Mesh>,
     images: Vec<Image>,
     text: Vec<Text>,
+    mask: Option<Mesh>,
+    masked: Vec<Masked>,
     transforms: Transforms,
     fill_tessellator: tessellation::FillTessellator,
     stroke_tessellator: tessellation::StrokeTessellator,
@@ -118,6 +165,8 @@ impl Frame {
             meshes: Vec::new(),
             images: Vec::new(),
             text: Vec::new(),
+            mask: None,
+            masked: Vec::new(),
             transforms: Transforms {
                 previous: Vec::new(),
                 current: Transform(lyon::math::Transform::translation(
@@ -339,6 +388,7 @@ impl geometry::frame::Backend for Frame {
                 horizontal_alignment: text.horizontal_alignment,
                 vertical_alignment: text.vertical_alignment,
                 shaping: text.shaping,
+                ellipsize: Ellipsize::None,
                 clip_bounds: self.clip_bounds,
             });
         } else {
@@ -394,13 +444,67 @@ impl geometry::frame::Backend for Frame {
         Frame::with_clip(clip_bounds)
     }
 
-    fn paste(&mut self, frame: Frame) {
+    fn draft_with_mask(&mut self, mask: &Path) -> Frame {
+        let mut frame = Frame::with_clip(self.clip_bounds);
+        frame.transforms.current = self.transforms.current;
+
+        let mut buffers = tessellation::VertexBuffers::new();
+        let mut builder = tessellation::BuffersBuilder::new(
+            &mut buffers,
+            TriangleVertex2DBuilder(color::pack(Color::WHITE)),
+        );
+
+        let options = tessellation::FillOptions::default();
+
+        if self.transforms.current.is_identity() {
+            self.fill_tessellator.tessellate_path(
+                mask.raw(),
+                &options,
+                &mut builder,
+            )
+        } else {
+            let path = mask.transform(&self.transforms.current.0);
+
+            self.fill_tessellator.tessellate_path(
+                path.raw(),
+                &options,
+                &mut builder,
+            )
+        }
+        .expect("Tessellate mask");
+
+        frame.mask = Some(Mesh::Solid {
+            buffers: mesh::Indexed {
+                vertices: buffers.vertices,
+                indices: buffers.indices,
+            },
+            clip_bounds: self.clip_bounds,
+            transformation: Transformation::IDENTITY,
+        });
+
+        frame
+    }
+
+    fn paste(&mut self, mut frame: Frame) {
+        if let Some(mask) = frame.mask.take() {
+            // An empty mask hides everything
+            if !mask.indices().is_empty() {
+                self.masked.push(Masked {
+                    mask,
+                    geometry: frame.into_geometry(),
+                });
+            }
+
+            return;
+        }
+
         self.meshes.extend(frame.meshes);
         self.meshes
             .extend(frame.buffers.into_meshes(frame.clip_bounds));
 
         self.images.extend(frame.images);
         self.text.extend(frame.text);
+        self.masked.extend(frame.masked);
     }
 
     fn into_geometry(mut self) -> Self::Geometry {
@@ -411,6 +515,7 @@ impl geometry::frame::Backend for Frame {
             meshes: self.meshes,
             images: self.images,
             text: self.text,
+            masked: self.masked,
         }
     }
 
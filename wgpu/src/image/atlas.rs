@@ -16,6 +16,7 @@ use allocator::Allocator;
 // https://docs.rs/wgpu/latest/wgpu/struct.Limits.html#structfield.max_buffer_size
 pub const SIZE: u32 = 4096;
 
+use crate::core::renderer;
 use crate::core::Size;
 use crate::graphics::color;
 
@@ -99,6 +100,15 @@ impl Atlas {
         self.layers.len()
     }
 
+    pub fn stats(&self) -> renderer::stats::Atlas {
+        renderer::stats::Atlas {
+            layers: self.layers.len(),
+            size: SIZE,
+            allocations: self.layers.iter().map(Layer::allocations).sum(),
+            allocated: self.layers.iter().map(Layer::allocated).sum(),
+        }
+    }
+
     pub fn upload(
         &mut self,
         device: &wgpu::Device,
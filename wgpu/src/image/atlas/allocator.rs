@@ -3,6 +3,7 @@ use guillotiere::{AtlasAllocator, Size};
 pub struct Allocator {
     raw: AtlasAllocator,
     allocations: usize,
+    allocated: u64,
 }
 
 impl Allocator {
@@ -12,6 +13,7 @@ impl Allocator {
         Allocator {
             raw,
             allocations: 0,
+            allocated: 0,
         }
     }
 
@@ -19,15 +21,19 @@ impl Allocator {
         let allocation =
             self.raw.allocate(Size::new(width as i32, height as i32))?;
 
+        let region = Region { allocation };
+
         self.allocations += 1;
+        self.allocated += region.area();
 
-        Some(Region { allocation })
+        Some(region)
     }
 
     pub fn deallocate(&mut self, region: &Region) {
         self.raw.deallocate(region.allocation.id);
 
         self.allocations = self.allocations.saturating_sub(1);
+        self.allocated = self.allocated.saturating_sub(region.area());
     }
 
     pub fn is_empty(&self) -> bool {
@@ -37,6 +43,10 @@ impl Allocator {
     pub fn allocations(&self) -> usize {
         self.allocations
     }
+
+    pub fn allocated(&self) -> u64 {
+        self.allocated
+    }
 }
 
 pub struct Region {
@@ -55,6 +65,12 @@ impl Region {
 
         crate::core::Size::new(size.width as u32, size.height as u32)
     }
+
+    fn area(&self) -> u64 {
+        let size = self.size();
+
+        u64::from(size.width) * u64::from(size.height)
+    }
 }
 
 impl std::fmt::Debug for Allocator {
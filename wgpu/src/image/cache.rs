@@ -1,4 +1,4 @@
-use crate::core::{self, Size};
+use crate::core::{self, renderer, Size};
 use crate::image::atlas::{self, Atlas};
 
 use std::sync::Arc;
@@ -35,6 +35,10 @@ impl Cache {
         self.atlas.layer_count()
     }
 
+    pub fn atlas_stats(&self) -> renderer::stats::Atlas {
+        self.atlas.stats()
+    }
+
     #[cfg(feature = "image")]
     pub fn measure_image(&mut self, handle: &core::image::Handle) -> Size<u32> {
         self.raster.load(handle).dimensions()
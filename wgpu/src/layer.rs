@@ -1,3 +1,4 @@
+use crate::clip::Mask;
 use crate::core::Radians;
 use crate::core::{
     self, renderer, Background, Color, Point, Rectangle, Svg, Transformation,
@@ -18,6 +19,7 @@ pub type Stack = layer::Stack<Layer>;
 #[derive(Debug)]
 pub struct Layer {
     pub bounds: Rectangle,
+    pub masks: Vec<Mask>,
     pub quads: quad::Batch,
     pub triangles: triangle::Batch,
     pub primitives: primitive::Batch,
@@ -107,6 +109,7 @@ impl Layer {
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
             shaping: text.shaping,
+            ellipsize: text.ellipsize,
             clip_bounds: clip_bounds * transformation,
         };
 
@@ -266,6 +269,8 @@ impl Layer {
 }
 
 impl graphics::Layer for Layer {
+    type Mask = Mask;
+
     fn with_bounds(bounds: Rectangle) -> Self {
         Self {
             bounds,
@@ -273,6 +278,11 @@ impl graphics::Layer for Layer {
         }
     }
 
+    fn set_masks(&mut self, masks: &[Mask]) {
+        self.masks.clear();
+        self.masks.extend_from_slice(masks);
+    }
+
     fn flush(&mut self) {
         self.flush_meshes();
         self.flush_text();
@@ -284,6 +294,7 @@ impl graphics::Layer for Layer {
 
     fn reset(&mut self) {
         self.bounds = Rectangle::INFINITE;
+        self.masks.clear();
 
         self.quads.clear();
         self.triangles.clear();
@@ -299,6 +310,7 @@ impl Default for Layer {
     fn default() -> Self {
         Self {
             bounds: Rectangle::INFINITE,
+            masks: Vec::new(),
             quads: quad::Batch::default(),
             triangles: triangle::Batch::default(),
             primitives: primitive::Batch::default(),
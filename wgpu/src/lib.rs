@@ -10,6 +10,7 @@
 //! - Text, which is rendered using [`glyphon`].
 //! - Quads or rectangles, with rounded borders and a solid background color.
 //! - Clip areas, useful to implement scrollables or hide overflowing content.
+//!   They can have rounded corners or follow an arbitrary shape.
 //! - Images and SVG, loaded from memory or the file system.
 //! - Meshes of triangles, useful to draw geometry freely.
 //!
@@ -22,6 +23,7 @@
 )]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 #![allow(missing_docs)]
+pub mod clip;
 pub mod layer;
 pub mod primitive;
 pub mod settings;
@@ -133,6 +135,47 @@ impl Renderer {
         self.image_cache.borrow_mut().trim();
     }
 
+    /// Computes the [`Stats`] of the last frame presented by the [`Renderer`].
+    ///
+    /// The [`Stats`] are only computed on demand; so presenting a frame does
+    /// not pay for them.
+    ///
+    /// [`Stats`]: core::renderer::Stats
+    pub fn stats(&self, engine: &Engine) -> core::renderer::Stats {
+        use crate::core::renderer::stats;
+
+        let layers = self
+            .layers
+            .iter()
+            .map(|layer| stats::Layer {
+                bounds: layer.bounds,
+                quads: layer.quads.len(),
+                meshes: layer.triangles.iter().map(triangle::Item::count).sum(),
+                paths: 0,
+                text: layer.text.iter().map(text::Item::count).sum(),
+                #[cfg(any(feature = "svg", feature = "image"))]
+                images: layer.images.len(),
+                #[cfg(not(any(feature = "svg", feature = "image")))]
+                images: 0,
+                primitives: layer.primitives.len(),
+            })
+            .collect();
+
+        #[cfg(any(feature = "svg", feature = "image"))]
+        let atlas = Some(self.image_cache.borrow().atlas_stats());
+
+        #[cfg(not(any(feature = "svg", feature = "image")))]
+        let atlas = None;
+
+        core::renderer::Stats {
+            layers,
+            atlas,
+            text_cache: engine.text_pipeline.cached_buffers()
+                + self.text_storage.cached_buffers(),
+            glyph_cache: None,
+        }
+    }
+
     fn prepare(
         &mut self,
         engine: &mut Engine,
@@ -147,6 +190,29 @@ impl Renderer {
         self.text_viewport.update(queue, viewport.physical_size());
 
         for layer in self.layers.iter_mut() {
+            if !layer.masks.is_empty() {
+                engine.clip_pipeline.prepare(
+                    device,
+                    encoder,
+                    &mut engine.staging_belt,
+                    &layer.masks,
+                    scale_factor,
+                    viewport.physical_size(),
+                );
+
+                if let Some(triangles) = clip::Mask::triangles(&layer.masks) {
+                    engine.triangle_pipeline.prepare(
+                        device,
+                        encoder,
+                        &mut engine.staging_belt,
+                        &mut self.triangle_storage,
+                        &triangles,
+                        Transformation::scale(scale_factor),
+                        viewport.physical_size(),
+                    );
+                }
+            }
+
             if !layer.quads.is_empty() {
                 engine.quad_pipeline.prepare(
                     device,
@@ -255,6 +321,7 @@ impl Renderer {
         let mut quad_layer = 0;
         let mut mesh_layer = 0;
         let mut text_layer = 0;
+        let mut clip_layer = 0;
 
         #[cfg(any(feature = "svg", feature = "image"))]
         let mut image_layer = 0;
@@ -269,6 +336,12 @@ impl Renderer {
         let scale = Transformation::scale(scale_factor);
 
         for layer in self.layers.iter() {
+            let clip = (!layer.masks.is_empty()).then(|| {
+                clip_layer += 1;
+
+                clip_layer - 1
+            });
+
             let Some(physical_bounds) =
                 physical_bounds.intersection(&(layer.bounds * scale))
             else {
@@ -279,6 +352,52 @@ impl Renderer {
                 continue;
             };
 
+            let target = if clip.is_some() {
+                let _ = ManuallyDrop::into_inner(render_pass);
+
+                let (content, shape) = engine.clip_pipeline.targets();
+
+                // Only the bounds of the layer are ever read back
+                engine.clip_pipeline.clear(encoder, content, scissor_rect);
+
+                if let Some(triangles) = clip::Mask::triangles(&layer.masks) {
+                    engine.clip_pipeline.clear(encoder, shape, scissor_rect);
+
+                    mesh_layer += engine.triangle_pipeline.render(
+                        encoder,
+                        shape,
+                        &self.triangle_storage,
+                        mesh_layer,
+                        &triangles,
+                        physical_bounds,
+                        scale,
+                    );
+                }
+
+                render_pass = ManuallyDrop::new(encoder.begin_render_pass(
+                    &wgpu::RenderPassDescriptor {
+                        label: Some("iced_wgpu render pass"),
+                        color_attachments: &[Some(
+                            wgpu::RenderPassColorAttachment {
+                                view: content,
+                                resolve_target: None,
+                                ops: wgpu::Operations {
+                                    load: wgpu::LoadOp::Load,
+                                    store: wgpu::StoreOp::Store,
+                                },
+                            },
+                        )],
+                        depth_stencil_attachment: None,
+                        timestamp_writes: None,
+                        occlusion_query_set: None,
+                    },
+                ));
+
+                content
+            } else {
+                frame
+            };
+
             if !layer.quads.is_empty() {
                 engine.quad_pipeline.render(
                     quad_layer,
@@ -295,7 +414,7 @@ impl Renderer {
 
                 mesh_layer += engine.triangle_pipeline.render(
                     encoder,
-                    frame,
+                    target,
                     &self.triangle_storage,
                     mesh_layer,
                     &layer.triangles,
@@ -308,7 +427,7 @@ impl Renderer {
                         label: Some("iced_wgpu render pass"),
                         color_attachments: &[Some(
                             wgpu::RenderPassColorAttachment {
-                                view: frame,
+                                view: target,
                                 resolve_target: None,
                                 ops: wgpu::Operations {
                                     load: wgpu::LoadOp::Load,
@@ -334,7 +453,7 @@ impl Renderer {
                         instance.primitive.render(
                             encoder,
                             &engine.primitive_storage,
-                            frame,
+                            target,
                             &clip_bounds,
                         );
                     }
@@ -345,7 +464,7 @@ impl Renderer {
                         label: Some("iced_wgpu render pass"),
                         color_attachments: &[Some(
                             wgpu::RenderPassColorAttachment {
-                                view: frame,
+                                view: target,
                                 resolve_target: None,
                                 ops: wgpu::Operations {
                                     load: wgpu::LoadOp::Load,
@@ -382,6 +501,36 @@ impl Renderer {
                     &mut render_pass,
                 );
             }
+
+            if let Some(clip_layer) = clip {
+                let _ = ManuallyDrop::into_inner(render_pass);
+
+                engine.clip_pipeline.render(
+                    clip_layer,
+                    encoder,
+                    frame,
+                    scissor_rect,
+                );
+
+                render_pass = ManuallyDrop::new(encoder.begin_render_pass(
+                    &wgpu::RenderPassDescriptor {
+                        label: Some("iced_wgpu render pass"),
+                        color_attachments: &[Some(
+                            wgpu::RenderPassColorAttachment {
+                                view: frame,
+                                resolve_target: None,
+                                ops: wgpu::Operations {
+                                    load: wgpu::LoadOp::Load,
+                                    store: wgpu::StoreOp::Store,
+                                },
+                            },
+                        )],
+                        depth_stencil_attachment: None,
+                        timestamp_writes: None,
+                        occlusion_query_set: None,
+                    },
+                ));
+            }
         }
 
         let _ = ManuallyDrop::into_inner(render_pass);
@@ -410,6 +559,7 @@ impl Renderer {
                         vertical_alignment: alignment::Vertical::Top,
                         shaping: core::text::Shaping::Advanced,
                         wrapping: core::text::Wrapping::Word,
+                        ellipsize: core::text::Ellipsize::None,
                     };
 
                     renderer.fill_text(
@@ -437,6 +587,23 @@ impl core::Renderer for Renderer {
         self.layers.push_clip(bounds);
     }
 
+    fn start_rounded_layer(
+        &mut self,
+        bounds: Rectangle,
+        border_radius: core::border::Radius,
+    ) {
+        let transformation = self.layers.transformation();
+
+        self.layers.push_mask(
+            bounds,
+            clip::Mask::Rounded {
+                bounds: bounds * transformation,
+                border_radius: <[f32; 4]>::from(border_radius)
+                    .map(|radius| radius * transformation.scale_factor()),
+            },
+        );
+    }
+
     fn end_layer(&mut self) {
         self.layers.pop_clip();
     }
@@ -490,6 +657,20 @@ impl core::text::Renderer for Renderer {
     ) {
         let (layer, transformation) = self.layers.current_mut();
 
+        for decoration in text
+            .decorations(position, clip_bounds)
+            .filter(|decoration| !decoration.is_overlay)
+        {
+            layer.draw_quad(
+                core::renderer::Quad {
+                    bounds: decoration.bounds,
+                    ..Default::default()
+                },
+                Background::Color(decoration.color.unwrap_or(color)),
+                transformation,
+            );
+        }
+
         layer.draw_paragraph(
             text,
             position,
@@ -497,6 +678,32 @@ impl core::text::Renderer for Renderer {
             clip_bounds,
             transformation,
         );
+
+        // Strikethroughs and overlines cross the glyphs, so they are drawn
+        // in a layer on top of the text
+        let mut overlays = text
+            .decorations(position, clip_bounds)
+            .filter(|decoration| decoration.is_overlay)
+            .peekable();
+
+        if overlays.peek().is_some() {
+            self.layers.push_clip(clip_bounds);
+
+            let (layer, transformation) = self.layers.current_mut();
+
+            for decoration in overlays {
+                layer.draw_quad(
+                    core::renderer::Quad {
+                        bounds: decoration.bounds,
+                        ..Default::default()
+                    },
+                    Background::Color(decoration.color.unwrap_or(color)),
+                    transformation,
+                );
+            }
+
+            self.layers.pop_clip();
+        }
     }
 
     fn fill_editor(
@@ -596,6 +803,7 @@ impl graphics::geometry::Renderer for Renderer {
                 meshes,
                 images,
                 text,
+                masked,
             } => {
                 layer.draw_mesh_group(meshes, transformation);
 
@@ -604,6 +812,14 @@ impl graphics::geometry::Renderer for Renderer {
                 }
 
                 layer.draw_text_group(text, transformation);
+
+                for geometry::Masked { mask, geometry } in masked {
+                    let bounds = mask.clip_bounds();
+
+                    if let Some(mask) = triangle::Cache::new(vec![mask]) {
+                        self.draw_masked_geometry(bounds, mask, geometry);
+                    }
+                }
             }
             Geometry::Cached(cache) => {
                 if let Some(meshes) = cache.meshes {
@@ -619,11 +835,47 @@ impl graphics::geometry::Renderer for Renderer {
                 if let Some(text) = cache.text {
                     layer.draw_text_cache(text, transformation);
                 }
+
+                if let Some(masked) = cache.masked {
+                    for masked in masked.iter() {
+                        self.draw_masked_geometry(
+                            masked.bounds,
+                            masked.mask.clone(),
+                            Geometry::Cached(masked.cache.clone()),
+                        );
+                    }
+                }
             }
         }
     }
 }
 
+#[cfg(feature = "geometry")]
+impl Renderer {
+    fn draw_masked_geometry(
+        &mut self,
+        bounds: Rectangle,
+        mask: triangle::Cache,
+        geometry: Geometry,
+    ) {
+        use crate::graphics::geometry::Renderer as _;
+
+        let transformation = self.layers.transformation();
+
+        self.layers.push_mask(
+            bounds,
+            clip::Mask::Shape {
+                cache: mask,
+                transformation,
+            },
+        );
+
+        self.draw_geometry(geometry);
+
+        self.layers.pop_clip();
+    }
+}
+
 impl primitive::Renderer for Renderer {
     fn draw_primitive(&mut self, bounds: Rectangle, primitive: impl Primitive) {
         let (layer, transformation) = self.layers.current_mut();
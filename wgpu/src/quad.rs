@@ -251,6 +251,11 @@ impl Batch {
         self.solids.is_empty() && self.gradients.is_empty()
     }
 
+    /// Returns the amount of quads in the [`Batch`].
+    pub fn len(&self) -> usize {
+        self.solids.len() + self.gradients.len()
+    }
+
     /// Adds a [`Quad`] with the provided `Background` type to the quad [`Layer`].
     pub fn add(&mut self, quad: Quad, background: &Background) {
         let kind = match background {
@@ -0,0 +1,78 @@
+struct Uniforms {
+    bounds: array<vec4<f32>, 4>,
+    border_radius: array<vec4<f32>, 4>,
+    rounded: u32,
+    shape: u32,
+}
+
+@group(0) @binding(0) var<uniform> uniforms: Uniforms;
+@group(1) @binding(0) var u_content: texture_2d<f32>;
+@group(1) @binding(1) var u_mask: texture_2d<f32>;
+
+var<private> positions: array<vec2<f32>, 6> = array<vec2<f32>, 6>(
+    vec2<f32>(-1.0, 1.0),
+    vec2<f32>(1.0, 1.0),
+    vec2<f32>(1.0, -1.0),
+    vec2<f32>(-1.0, 1.0),
+    vec2<f32>(-1.0, -1.0),
+    vec2<f32>(1.0, -1.0)
+);
+
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32) -> @builtin(position) vec4<f32> {
+    return vec4<f32>(positions[vertex_index], 0.0, 1.0);
+}
+
+@fragment
+fn fs_main(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    let coords = vec2<i32>(position.xy);
+    let color = textureLoad(u_content, coords, 0);
+
+    var coverage = 1.0;
+
+    if uniforms.shape == 1u {
+        coverage = textureLoad(u_mask, coords, 0).a;
+    }
+
+    // Every mask of the chain clips the contents
+    for (var i = 0u; i < uniforms.rounded; i++) {
+        let half_size = uniforms.bounds[i].zw / 2.0;
+        let center = uniforms.bounds[i].xy + half_size;
+
+        let radius = select_border_radius(
+            uniforms.border_radius[i],
+            position.xy,
+            center
+        );
+
+        let distance = rounded_box_sdf(position.xy - center, half_size, radius);
+
+        coverage *= 1.0 - smoothstep(-0.5, 0.5, distance);
+    }
+
+    // The contents are premultiplied, so the coverage applies to every channel
+    return color * coverage;
+}
+
+@fragment
+fn fs_clear() -> @location(0) vec4<f32> {
+    return vec4<f32>(0.0, 0.0, 0.0, 0.0);
+}
+
+// Given a vector from a point to the center of a rounded rectangle of the given `size` and
+// border `radius`, determines the point's distance from the nearest edge of the rounded rectangle
+fn rounded_box_sdf(to_center: vec2<f32>, size: vec2<f32>, radius: f32) -> f32 {
+    return length(max(abs(to_center) - size + vec2<f32>(radius, radius), vec2<f32>(0.0, 0.0))) - radius;
+}
+
+// Based on the fragment position and the center of the rectangle, select one of the 4 radii.
+// Order matches CSS border radius attribute:
+// radii.x = top-left, radii.y = top-right, radii.z = bottom-right, radii.w = bottom-left
+fn select_border_radius(radii: vec4<f32>, position: vec2<f32>, center: vec2<f32>) -> f32 {
+    var rx = radii.x;
+    var ry = radii.y;
+    rx = select(radii.x, radii.y, position.x > center.x);
+    ry = select(radii.w, radii.z, position.x > center.x);
+    rx = select(rx, ry, position.y > center.y);
+    return rx;
+}
@@ -33,6 +33,16 @@ pub enum Item {
     },
 }
 
+impl Item {
+    /// Returns the amount of text runs in the [`Item`].
+    pub fn count(&self) -> usize {
+        match self {
+            Item::Group { text, .. } => text.len(),
+            Item::Cached { cache, .. } => cache.text.len(),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Cache {
     id: Id,
@@ -221,6 +231,15 @@ impl Storage {
         }
     }
 
+    /// Returns the amount of text buffers cached by the uploads of the
+    /// [`Storage`].
+    pub fn cached_buffers(&self) -> usize {
+        self.uploads
+            .values()
+            .map(|upload| upload.buffer_cache.len())
+            .sum()
+    }
+
     pub fn trim(&mut self) {
         self.uploads
             .retain(|_id, upload| upload.text.strong_count() > 0);
@@ -303,6 +322,11 @@ impl Pipeline {
         }
     }
 
+    /// Returns the amount of text buffers cached by the [`Pipeline`].
+    pub fn cached_buffers(&self) -> usize {
+        self.cache.len()
+    }
+
     pub fn prepare(
         &mut self,
         device: &wgpu::Device,
@@ -467,6 +491,7 @@ fn prepare(
                 line_height,
                 font,
                 shaping,
+                ellipsize,
                 ..
             } => {
                 let (key, _) = buffer_cache.allocate(
@@ -481,6 +506,7 @@ fn prepare(
                             height: bounds.height,
                         },
                         shaping: *shaping,
+                        ellipsize: *ellipsize,
                     },
                 );
 
@@ -28,6 +28,16 @@ pub enum Item {
     },
 }
 
+impl Item {
+    /// Returns the amount of meshes in the [`Item`].
+    pub fn count(&self) -> usize {
+        match self {
+            Item::Group { meshes, .. } => meshes.len(),
+            Item::Cached { cache, .. } => cache.batch.len(),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Cache {
     id: Id,
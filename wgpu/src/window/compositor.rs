@@ -1,5 +1,5 @@
 //! Connect a window with a renderer.
-use crate::core::{Color, Size};
+use crate::core::{self, Color, Size};
 use crate::graphics::color;
 use crate::graphics::compositor;
 use crate::graphics::error;
@@ -440,6 +440,10 @@ impl graphics::Compositor for Compositor {
         present(self, renderer, surface, viewport, background_color, overlay)
     }
 
+    fn stats(&self, renderer: &Self::Renderer) -> core::renderer::Stats {
+        renderer.stats(&self.engine)
+    }
+
     fn screenshot<T: AsRef<str>>(
         &mut self,
         renderer: &mut Self::Renderer,
@@ -32,7 +32,7 @@ use crate::core::renderer;
 use crate::core::theme::palette;
 use crate::core::touch;
 use crate::core::widget::tree::{self, Tree};
-use crate::core::widget::Operation;
+use crate::core::widget::{inspect, Operation};
 use crate::core::{
     Background, Clipboard, Color, Element, Layout, Length, Padding, Rectangle,
     Shadow, Shell, Size, Theme, Vector, Widget,
@@ -77,6 +77,32 @@ use iced_renderer::core::widget::operation;
 ///     button("I am disabled!").into()
 /// }
 /// ```
+///
+/// The content of a [`Button`] can be any widget; labels that may overflow
+/// it can be ellipsized by using an ellipsized `text` widget as its content:
+///
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::core::text; }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::text::{Ellipsize, EllipsizeHeightLimit};
+/// use iced::widget::{button, text};
+///
+/// #[derive(Clone)]
+/// enum Message {
+///     ButtonPressed,
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     button(
+///         text("A label far too long for its button")
+///             .ellipsize(Ellipsize::End(EllipsizeHeightLimit::Lines(1))),
+///     )
+///     .width(120)
+///     .on_press(Message::ButtonPressed)
+///     .into()
+/// }
+/// ```
 #[allow(missing_debug_implementations)]
 pub struct Button<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
 where
@@ -640,6 +666,21 @@ where
     fn set_id(&mut self, id: Id) {
         self.id = id;
     }
+
+    fn inspect(&self, tree: &Tree, layout: Layout<'_>) -> inspect::Node {
+        let node = inspect::Node::new::<Self>(self.id(), self.size(), layout)
+            .with_padding(self.padding);
+
+        match layout.children().next() {
+            Some(c_layout) => {
+                node.with_children([self.content.as_widget().inspect(
+                    &tree.children[0],
+                    c_layout.with_virtual_offset(layout.virtual_offset()),
+                )])
+            }
+            None => node,
+        }
+    }
 }
 
 impl<'a, Message, Theme, Renderer> From<Button<'a, Message, Theme, Renderer>>
@@ -935,3 +976,48 @@ impl operation::Focusable for State {
         self.is_focused = false;
     }
 }
+
+#[cfg(all(test, feature = "a11y"))]
+mod tests {
+    use super::*;
+    use crate::core::Size;
+    use crate::Space;
+
+    use iced_accessibility::accesskit::{Action, Role};
+    use iced_runtime::a11y::Inspector;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Increment,
+    }
+
+    #[test]
+    fn default_action_presses_the_button() {
+        let button: Button<'_, Message, crate::Theme, ()> =
+            Button::new(Space::new(Length::Fixed(40.0), Length::Fixed(20.0)))
+                .name("Increment")
+                .on_press(Message::Increment);
+
+        let mut inspector = Inspector::new(button, Size::new(800.0, 600.0), ());
+
+        let _ = inspector.assert_node(Role::Button, "Increment");
+
+        assert_eq!(
+            inspector.perform(Role::Button, "Increment", Action::Default),
+            vec![Message::Increment]
+        );
+    }
+
+    #[test]
+    fn disabled_buttons_ignore_the_default_action() {
+        let button: Button<'_, Message, crate::Theme, ()> =
+            Button::new(Space::new(Length::Fixed(40.0), Length::Fixed(20.0)))
+                .name("Increment");
+
+        let mut inspector = Inspector::new(button, Size::new(800.0, 600.0), ());
+
+        assert!(inspector
+            .perform(Role::Button, "Increment", Action::Default)
+            .is_empty());
+    }
+}
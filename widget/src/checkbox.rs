@@ -340,6 +340,7 @@ where
                     alignment::Vertical::Top,
                     self.text_shaping,
                     self.text_wrapping,
+                    text::Ellipsize::None,
                 )
             },
         )
@@ -450,6 +451,7 @@ where
                         vertical_alignment: alignment::Vertical::Center,
                         shaping: *shaping,
                         wrapping: text::Wrapping::default(),
+                        ellipsize: text::Ellipsize::default(),
                     },
                     bounds.center(),
                     style.icon_color,
@@ -1,15 +1,16 @@
 //! Distribute content vertically.
 use crate::core::alignment::{self, Alignment};
 use crate::core::event::{self, Event};
-use crate::core::layout;
+use crate::core::layout::{self, flex::Axis};
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
-use crate::core::widget::{Operation, Tree};
+use crate::core::widget::{inspect, tree, Operation, Tree};
 use crate::core::{
     Clipboard, Element, Layout, Length, Padding, Pixels, Rectangle, Shell,
     Size, Vector, Widget,
 };
+use crate::reorder;
 
 /// A container that distributes its contents vertically.
 ///
@@ -44,6 +45,7 @@ pub struct Column<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
     align: Alignment,
     clip: bool,
     children: Vec<Element<'a, Message, Theme, Renderer>>,
+    on_reorder: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
 }
 
 impl<'a, Message, Theme, Renderer> Column<'a, Message, Theme, Renderer>
@@ -88,6 +90,7 @@ where
             align: Alignment::Start,
             clip: false,
             children,
+            on_reorder: None,
         }
     }
 
@@ -138,6 +141,23 @@ where
         self
     }
 
+    /// Sets the message that should be produced when a child of the
+    /// [`Column`] is dragged to a new position.
+    ///
+    /// The closure receives the index of the child and the index it should
+    /// be moved to, once it is removed from its original position.
+    ///
+    /// A parent [`Scrollable`] scrolls while dragging near its edges.
+    ///
+    /// [`Scrollable`]: crate::Scrollable
+    pub fn on_reorder(
+        mut self,
+        on_reorder: impl Fn(usize, usize) -> Message + 'a,
+    ) -> Self {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
     /// Adds an element to the [`Column`].
     pub fn push(
         mut self,
@@ -201,6 +221,23 @@ impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
 where
     Renderer: crate::core::Renderer,
 {
+    fn tag(&self) -> tree::Tag {
+        // Only reorderable containers are stateful
+        if self.on_reorder.is_some() {
+            tree::Tag::of::<reorder::State>()
+        } else {
+            tree::Tag::stateless()
+        }
+    }
+
+    fn state(&self) -> tree::State {
+        if self.on_reorder.is_some() {
+            tree::State::new(reorder::State::default())
+        } else {
+            tree::State::None
+        }
+    }
+
     fn children(&self) -> Vec<Tree> {
         self.children.iter().map(Tree::new).collect()
     }
@@ -272,7 +309,35 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
-        self.children
+        let mut status = event::Status::Ignored;
+
+        let cursor = if let Some(on_reorder) = &self.on_reorder {
+            let state = tree.state.downcast_mut::<reorder::State>();
+            let was_picked = state.is_picked();
+
+            status = reorder::update(
+                state,
+                &Axis::Vertical,
+                self.spacing,
+                &event,
+                layout,
+                cursor,
+                viewport,
+                shell,
+                on_reorder.as_ref(),
+            );
+
+            if was_picked || state.is_picked() {
+                mouse::Cursor::Unavailable
+            } else {
+                cursor
+            }
+        } else {
+            cursor
+        };
+
+        let status = self
+            .children
             .iter_mut()
             .zip(&mut tree.children)
             .zip(layout.children())
@@ -288,7 +353,18 @@ where
                     viewport,
                 )
             })
-            .fold(event::Status::Ignored, event::Status::merge)
+            .fold(status, event::Status::merge);
+
+        if self.on_reorder.is_some() && status == event::Status::Ignored {
+            reorder::press(
+                tree.state.downcast_mut::<reorder::State>(),
+                &event,
+                layout,
+                cursor,
+            );
+        }
+
+        status
     }
 
     fn mouse_interaction(
@@ -299,6 +375,12 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
+        if self.on_reorder.is_some()
+            && tree.state.downcast_ref::<reorder::State>().is_picked()
+        {
+            return mouse::Interaction::Grabbing;
+        }
+
         self.children
             .iter()
             .zip(&tree.children)
@@ -333,6 +415,26 @@ where
                 viewport
             };
 
+            if self.on_reorder.is_some() {
+                let state = tree.state.downcast_ref::<reorder::State>();
+
+                if state.is_picked() {
+                    reorder::draw(
+                        state,
+                        &Axis::Vertical,
+                        &self.children,
+                        tree,
+                        renderer,
+                        theme,
+                        style,
+                        layout,
+                        viewport,
+                    );
+
+                    return;
+                }
+            }
+
             for ((child, state), c_layout) in self
                 .children
                 .iter()
@@ -414,6 +516,24 @@ where
             );
         }
     }
+
+    fn inspect(&self, tree: &Tree, layout: Layout<'_>) -> inspect::Node {
+        inspect::Node::new::<Self>(self.id(), self.size(), layout)
+            .with_padding(self.padding)
+            .with_children(
+                self.children
+                    .iter()
+                    .zip(&tree.children)
+                    .zip(layout.children())
+                    .map(|((child, state), c_layout)| {
+                        child.as_widget().inspect(
+                            state,
+                            c_layout
+                                .with_virtual_offset(layout.virtual_offset()),
+                        )
+                    }),
+            )
+    }
 }
 
 impl<'a, Message, Theme, Renderer> From<Column<'a, Message, Theme, Renderer>>
@@ -427,3 +547,20 @@ where
         Self::new(column)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_reorderable_columns_are_stateful() {
+        let column = Column::<'_, (), crate::Theme, ()>::new();
+
+        assert_eq!(Widget::tag(&column), tree::Tag::stateless());
+        assert!(matches!(Widget::state(&column), tree::State::None));
+
+        let column = column.on_reorder(|_, _| ());
+
+        assert_eq!(Widget::tag(&column), tree::Tag::of::<reorder::State>());
+    }
+}
@@ -74,6 +74,9 @@ use crate::text_input::{self, TextInput};
 use std::cell::RefCell;
 use std::fmt::Display;
 
+#[cfg(feature = "a11y")]
+use std::borrow::Cow;
+
 /// A widget for searching and selecting a single value from a list of options.
 ///
 /// # Example
@@ -319,6 +322,39 @@ where
         self.menu_class = class.into();
         self
     }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the name of the [`ComboBox`].
+    ///
+    /// By default, the placeholder is used.
+    pub fn name(mut self, name: impl Into<Cow<'a, str>>) -> Self {
+        self.text_input = self.text_input.name(name);
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`ComboBox`].
+    pub fn description_widget(
+        mut self,
+        description: &impl iced_accessibility::Describes,
+    ) -> Self {
+        self.text_input = self.text_input.description_widget(description);
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`ComboBox`].
+    pub fn description(mut self, description: impl Into<Cow<'a, str>>) -> Self {
+        self.text_input = self.text_input.description(description);
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the label of the [`ComboBox`].
+    pub fn label(mut self, label: &dyn iced_accessibility::Labels) -> Self {
+        self.text_input = self.text_input.label(label);
+        self
+    }
 }
 
 /// The local state of a [`ComboBox`].
@@ -522,6 +558,23 @@ where
     ) -> event::Status {
         let menu = tree.state.downcast_mut::<Menu<T>>();
 
+        // The menu is open while the text input is focused
+        #[cfg(feature = "a11y")]
+        let event = match event {
+            Event::A11y(target, mut request) => {
+                use iced_accessibility::accesskit::Action;
+
+                request.action = match request.action {
+                    Action::Expand => Action::Focus,
+                    Action::Collapse => Action::Blur,
+                    action => action,
+                };
+
+                Event::A11y(target, request)
+            }
+            event => event,
+        };
+
         let started_focused = {
             let text_input_state = tree.children[0]
                 .state
@@ -885,6 +938,39 @@ where
             None
         }
     }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &widget::Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::accesskit::{Action, Role};
+
+        let is_expanded = state.children[0]
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+            .is_focused();
+
+        let mut tree =
+            self.text_input
+                .a11y_nodes(layout, &state.children[0], cursor);
+
+        if let Some(node) = tree.root_mut().first_mut() {
+            let node = node.node_mut();
+
+            node.set_role(Role::ComboBox);
+            node.set_expanded(is_expanded);
+            node.add_action(if is_expanded {
+                Action::Collapse
+            } else {
+                Action::Expand
+            });
+        }
+
+        tree
+    }
 }
 
 impl<'a, T, Message, Theme, Renderer>
@@ -28,7 +28,7 @@ use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::widget::tree::{self, Tree};
-use crate::core::widget::{self, Id, Operation};
+use crate::core::widget::{self, inspect, Id, Operation};
 use crate::core::{
     self, color, Background, Clipboard, Color, Element, Layout, Length,
     Padding, Pixels, Point, Rectangle, Shadow, Shell, Size, Theme, Vector,
@@ -198,6 +198,9 @@ where
 
     /// Sets whether the contents of the [`Container`] should be clipped on
     /// overflow.
+    ///
+    /// If the [`Style`] of the [`Container`] has a border radius, its contents
+    /// will be clipped with rounded corners as well.
     pub fn clip(mut self, clip: bool) -> Self {
         self.clip = clip;
         self
@@ -358,31 +361,43 @@ where
         if let Some(clipped_viewport) = bounds.intersection(viewport) {
             draw_background(renderer, &style, bounds);
 
-            self.content.as_widget().draw(
-                tree,
-                renderer,
-                theme,
-                &renderer::Style {
-                    icon_color: style
-                        .icon_color
-                        .unwrap_or(renderer_style.icon_color),
-                    text_color: style
-                        .text_color
-                        .unwrap_or(renderer_style.text_color),
-                    scale_factor: renderer_style.scale_factor,
-                },
-                layout
-                    .children()
-                    .next()
-                    .unwrap()
-                    .with_virtual_offset(layout.virtual_offset()),
-                cursor,
-                if self.clip {
-                    &clipped_viewport
-                } else {
-                    viewport
-                },
-            );
+            let draw_content = |renderer: &mut Renderer| {
+                self.content.as_widget().draw(
+                    tree,
+                    renderer,
+                    theme,
+                    &renderer::Style {
+                        icon_color: style
+                            .icon_color
+                            .unwrap_or(renderer_style.icon_color),
+                        text_color: style
+                            .text_color
+                            .unwrap_or(renderer_style.text_color),
+                        scale_factor: renderer_style.scale_factor,
+                    },
+                    layout
+                        .children()
+                        .next()
+                        .unwrap()
+                        .with_virtual_offset(layout.virtual_offset()),
+                    cursor,
+                    if self.clip {
+                        &clipped_viewport
+                    } else {
+                        viewport
+                    },
+                );
+            };
+
+            if self.clip && style.border.radius != border::Radius::default() {
+                renderer.with_rounded_layer(
+                    bounds,
+                    style.border.radius,
+                    draw_content,
+                );
+            } else {
+                draw_content(renderer);
+            }
         }
     }
 
@@ -446,6 +461,21 @@ where
     fn set_id(&mut self, id: Id) {
         self.content.as_widget_mut().set_id(id);
     }
+
+    fn inspect(&self, tree: &Tree, layout: Layout<'_>) -> inspect::Node {
+        let node = inspect::Node::new::<Self>(self.id(), self.size(), layout)
+            .with_padding(self.padding);
+
+        match layout.children().next() {
+            Some(c_layout) => {
+                node.with_children([self.content.as_widget().inspect(
+                    tree,
+                    c_layout.with_virtual_offset(layout.virtual_offset()),
+                )])
+            }
+            None => node,
+        }
+    }
 }
 
 impl<'a, Message, Theme, Renderer> From<Container<'a, Message, Theme, Renderer>>
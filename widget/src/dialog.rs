@@ -0,0 +1,352 @@
+//! Show dialogs built from widgets.
+//!
+//! These can be used as a fallback when a native dialog is unavailable; that
+//! is, when a dialog task fails with [`Error::Unavailable`].
+//!
+//! [`Error::Unavailable`]: crate::runtime::dialog::Error::Unavailable
+use crate::core;
+use crate::core::{Element, Length};
+use crate::overlay;
+use crate::runtime::dialog::{Buttons, FileDialog, Filter, MessageDialog};
+use crate::{
+    button, column, container, horizontal_space, pick_list, row, scrollable,
+    text, text_input,
+};
+
+use std::fs;
+use std::path::PathBuf;
+
+/// Creates a dialog showing the given [`MessageDialog`].
+///
+/// `on_close` is called with `true` if the message was accepted.
+pub fn message<'a, Message, Theme, Renderer>(
+    dialog: &'a MessageDialog,
+    on_close: impl Fn(bool) -> Message,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: button::Catalog + container::Catalog + text::Catalog + 'a,
+    Renderer: core::text::Renderer + 'a,
+{
+    let (accept, reject) = match dialog.buttons {
+        Buttons::Ok => ("Ok", None),
+        Buttons::OkCancel => ("Ok", Some("Cancel")),
+        Buttons::YesNo => ("Yes", Some("No")),
+    };
+
+    let buttons = row(reject
+        .map(|label| button(text(label)).on_press(on_close(false)).into())
+        .into_iter()
+        .chain([button(text(accept)).on_press(on_close(true)).into()]))
+    .spacing(10);
+
+    container(
+        column([
+            text(&dialog.title).size(20).into(),
+            text(&dialog.description).into(),
+            container(buttons).align_right(Length::Fill).into(),
+        ])
+        .spacing(20)
+        .max_width(400),
+    )
+    .padding(20)
+    .into()
+}
+
+/// A file or folder chooser built from widgets.
+#[derive(Debug, Clone)]
+pub struct FileChooser {
+    dialog: FileDialog,
+    mode: Mode,
+    directory: PathBuf,
+    entries: Vec<Entry>,
+    selected: Vec<PathBuf>,
+    file_name: String,
+    filter: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Open,
+    Save,
+    Folder,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    path: PathBuf,
+    name: String,
+    is_directory: bool,
+}
+
+/// An interaction with a [`FileChooser`].
+#[derive(Debug, Clone)]
+pub enum Action {
+    /// Navigate to the given folder.
+    Navigate(PathBuf),
+    /// Select the given file.
+    Select(PathBuf),
+    /// Change the file name to save to.
+    FileNameChanged(String),
+    /// Change the active [`Filter`] by name.
+    FilterChanged(String),
+    /// Accept the current selection.
+    Accept,
+    /// Cancel the dialog.
+    Cancel,
+}
+
+/// The outcome of a [`FileChooser`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// The given paths were picked.
+    Picked(Vec<PathBuf>),
+    /// The dialog was cancelled.
+    Cancelled,
+}
+
+impl FileChooser {
+    /// Creates a [`FileChooser`] to pick files to open.
+    pub fn open(dialog: FileDialog) -> Self {
+        Self::new(dialog, Mode::Open)
+    }
+
+    /// Creates a [`FileChooser`] to pick a file to save to.
+    pub fn save(dialog: FileDialog) -> Self {
+        Self::new(dialog, Mode::Save)
+    }
+
+    /// Creates a [`FileChooser`] to pick a folder.
+    pub fn pick_folder(dialog: FileDialog) -> Self {
+        Self::new(dialog, Mode::Folder)
+    }
+
+    fn new(dialog: FileDialog, mode: Mode) -> Self {
+        let directory = dialog
+            .directory
+            .clone()
+            .or_else(|| std::env::current_dir().ok())
+            .unwrap_or_else(|| PathBuf::from("/"));
+
+        let mut chooser = Self {
+            file_name: dialog.file_name.clone().unwrap_or_default(),
+            filter: dialog.filters.first().map(|filter| filter.name.clone()),
+            dialog,
+            mode,
+            directory: PathBuf::new(),
+            entries: Vec::new(),
+            selected: Vec::new(),
+        };
+
+        chooser.navigate(directory);
+        chooser
+    }
+
+    /// Performs an [`Action`] on the [`FileChooser`], returning its
+    /// [`Outcome`] once the dialog is done.
+    pub fn update(&mut self, action: Action) -> Option<Outcome> {
+        match action {
+            Action::Navigate(directory) => {
+                self.navigate(directory);
+            }
+            Action::Select(path) => {
+                if self.mode == Mode::Open && self.dialog.multiple {
+                    if let Some(index) = self
+                        .selected
+                        .iter()
+                        .position(|selected| *selected == path)
+                    {
+                        let _ = self.selected.remove(index);
+                    } else {
+                        self.selected.push(path);
+                    }
+                } else {
+                    if self.mode == Mode::Save {
+                        if let Some(file_name) = path.file_name() {
+                            self.file_name =
+                                file_name.to_string_lossy().into_owned();
+                        }
+                    }
+
+                    self.selected = vec![path];
+                }
+            }
+            Action::FileNameChanged(file_name) => {
+                self.file_name = file_name;
+            }
+            Action::FilterChanged(filter) => {
+                self.filter = Some(filter);
+            }
+            Action::Accept => return self.picked().map(Outcome::Picked),
+            Action::Cancel => return Some(Outcome::Cancelled),
+        }
+
+        None
+    }
+
+    /// Returns the view of the [`FileChooser`].
+    pub fn view<'a, Theme, Renderer>(
+        &'a self,
+    ) -> Element<'a, Action, Theme, Renderer>
+    where
+        Theme: button::Catalog
+            + overlay::menu::Catalog
+            + pick_list::Catalog
+            + scrollable::Catalog
+            + text::Catalog
+            + text_input::Catalog
+            + 'a,
+        Renderer: core::text::Renderer + 'a,
+    {
+        let filter = self.filter.as_ref().and_then(|name| {
+            self.dialog
+                .filters
+                .iter()
+                .find(|filter| filter.name == *name)
+        });
+
+        let header = row([
+            button(text(".."))
+                .on_press_maybe(
+                    self.directory
+                        .parent()
+                        .map(|parent| Action::Navigate(parent.to_path_buf())),
+                )
+                .into(),
+            text(self.directory.display().to_string()).into(),
+        ])
+        .spacing(10);
+
+        let entries = column(
+            self.entries
+                .iter()
+                .filter(|entry| self.is_visible(entry, filter))
+                .map(|entry| {
+                    let label = if entry.is_directory {
+                        format!("{}/", entry.name)
+                    } else if self.selected.contains(&entry.path) {
+                        format!("✓ {}", entry.name)
+                    } else {
+                        entry.name.clone()
+                    };
+
+                    let action = if entry.is_directory {
+                        Action::Navigate(entry.path.clone())
+                    } else {
+                        Action::Select(entry.path.clone())
+                    };
+
+                    button(text(label))
+                        .width(Length::Fill)
+                        .on_press(action)
+                        .into()
+                }),
+        )
+        .spacing(2);
+
+        let file_name = (self.mode == Mode::Save).then(|| {
+            text_input("File name", &self.file_name)
+                .on_input(Action::FileNameChanged)
+                .on_submit(Action::Accept)
+                .into()
+        });
+
+        let filters = (self.mode != Mode::Folder
+            && !self.dialog.filters.is_empty())
+        .then(|| {
+            let names: Vec<String> = self
+                .dialog
+                .filters
+                .iter()
+                .map(|filter| filter.name.clone())
+                .collect();
+
+            pick_list(names, self.filter.clone(), Action::FilterChanged).into()
+        });
+
+        let accept = match self.mode {
+            Mode::Open => "Open",
+            Mode::Save => "Save",
+            Mode::Folder => "Select",
+        };
+
+        let footer = row(file_name.into_iter().chain(filters).chain([
+            horizontal_space().into(),
+            button(text("Cancel")).on_press(Action::Cancel).into(),
+            button(text(accept))
+                .on_press_maybe(
+                    self.picked().is_some().then_some(Action::Accept),
+                )
+                .into(),
+        ]))
+        .spacing(10);
+
+        column([
+            text(&self.dialog.title).size(20).into(),
+            header.into(),
+            scrollable(entries).height(Length::Fill).into(),
+            footer.into(),
+        ])
+        .spacing(10)
+        .padding(20)
+        .into()
+    }
+
+    fn navigate(&mut self, directory: PathBuf) {
+        let Ok(entries) = fs::read_dir(&directory) else {
+            return;
+        };
+
+        self.entries = entries
+            .filter_map(Result::ok)
+            .filter_map(|entry| {
+                let name = entry.file_name().to_string_lossy().into_owned();
+
+                if name.starts_with('.') {
+                    return None;
+                }
+
+                let path = entry.path();
+
+                Some(Entry {
+                    is_directory: path.is_dir(),
+                    path,
+                    name,
+                })
+            })
+            .collect();
+
+        self.entries.sort_by(|a, b| {
+            b.is_directory
+                .cmp(&a.is_directory)
+                .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+        });
+
+        self.directory = directory;
+        self.selected.clear();
+    }
+
+    fn is_visible(&self, entry: &Entry, filter: Option<&Filter>) -> bool {
+        if entry.is_directory {
+            return true;
+        }
+
+        match self.mode {
+            Mode::Folder => false,
+            Mode::Open | Mode::Save => {
+                filter.is_none_or(|filter| filter.matches(&entry.name))
+            }
+        }
+    }
+
+    fn picked(&self) -> Option<Vec<PathBuf>> {
+        match self.mode {
+            Mode::Open => {
+                (!self.selected.is_empty()).then(|| self.selected.clone())
+            }
+            Mode::Save => (!self.file_name.is_empty())
+                .then(|| vec![self.directory.join(&self.file_name)]),
+            Mode::Folder => Some(vec![self.directory.clone()]),
+        }
+    }
+}
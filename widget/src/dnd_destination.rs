@@ -0,0 +1,694 @@
+//! Accept data dropped on any widget through drag and drop.
+use crate::core::clipboard::DndDestinationRectangles;
+use crate::core::event::{self, Event};
+use crate::core::id::Internal;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::{inspect, tree, Id, Operation, Tree};
+use crate::core::{
+    Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+
+use dnd::{DndAction, DndDestinationRectangle, DndEvent, OfferEvent};
+use window_clipboard::mime::AllowedMimeTypes;
+
+use std::borrow::Cow;
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+/// A widget that accepts drag and drop offers of some MIME types.
+///
+/// Positions are given in the coordinates of the window, like any other
+/// cursor position.
+#[allow(missing_debug_implementations)]
+pub struct DndDestination<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    id: Id,
+    drag_id: Option<u128>,
+    content: Element<'a, Message, Theme, Renderer>,
+    mime_types: Vec<Cow<'static, str>>,
+    actions: DndAction,
+    preferred_action: DndAction,
+    forward_drag_as_cursor: bool,
+    on_enter: Option<Box<dyn Fn(Point, Vec<String>) -> Message + 'a>>,
+    on_motion: Option<Box<dyn Fn(Point) -> Message + 'a>>,
+    on_leave: Option<Message>,
+    on_action_selected: Option<Box<dyn Fn(DndAction) -> Message + 'a>>,
+    on_drop: Option<Box<dyn Fn(Point) -> Message + 'a>>,
+    on_data: Option<Box<dyn Fn(String, Vec<u8>, DndAction) -> Message + 'a>>,
+}
+
+impl<'a, Message, Theme, Renderer>
+    DndDestination<'a, Message, Theme, Renderer>
+{
+    /// Creates a new [`DndDestination`] accepting the given MIME types.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        mime_types: Vec<Cow<'static, str>>,
+    ) -> Self {
+        Self {
+            id: Id::unique(),
+            drag_id: None,
+            content: content.into(),
+            mime_types,
+            actions: DndAction::Copy | DndAction::Move,
+            preferred_action: DndAction::Move,
+            forward_drag_as_cursor: false,
+            on_enter: None,
+            on_motion: None,
+            on_leave: None,
+            on_action_selected: None,
+            on_drop: None,
+            on_data: None,
+        }
+    }
+
+    /// Creates a new [`DndDestination`] accepting the MIME types of `T`.
+    ///
+    /// The given closure is called with the dropped data once it has been
+    /// received, or with `None` if it could not be converted.
+    pub fn for_data<T: AllowedMimeTypes>(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        on_data: impl Fn(Option<T>, DndAction) -> Message + 'a,
+    ) -> Self {
+        let mime_types = T::allowed().iter().cloned().map(Cow::Owned).collect();
+
+        Self::new(content, mime_types).on_data(
+            move |mime_type, data, action| {
+                on_data(T::try_from((data, mime_type)).ok(), action)
+            },
+        )
+    }
+
+    /// Sets the [`Id`] of the [`DndDestination`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = id;
+        self
+    }
+
+    /// Sets the identifier of the destination rectangle registered with the
+    /// platform.
+    ///
+    /// By default, it is derived from the [`Id`] of the [`DndDestination`].
+    pub fn drag_id(mut self, drag_id: u128) -> Self {
+        self.drag_id = Some(drag_id);
+        self
+    }
+
+    /// Sets the [`DndAction`]s accepted by the [`DndDestination`].
+    pub fn actions(mut self, actions: DndAction) -> Self {
+        self.actions = actions;
+        self
+    }
+
+    /// Sets the preferred [`DndAction`] of the [`DndDestination`].
+    ///
+    /// The action that is finally performed is negotiated with the source
+    /// and notified through [`DndDestination::on_action_selected`].
+    pub fn preferred_action(mut self, action: DndAction) -> Self {
+        self.preferred_action = action;
+        self
+    }
+
+    /// Sets whether the position of an offer should be forwarded to the
+    /// content as cursor movement.
+    ///
+    /// This lets the content react to the drag, like showing a hover state.
+    pub fn forward_drag_as_cursor(mut self, forward: bool) -> Self {
+        self.forward_drag_as_cursor = forward;
+        self
+    }
+
+    /// Sets the message produced when an offer enters the
+    /// [`DndDestination`], given its position and its MIME types.
+    pub fn on_enter(
+        mut self,
+        on_enter: impl Fn(Point, Vec<String>) -> Message + 'a,
+    ) -> Self {
+        self.on_enter = Some(Box::new(on_enter));
+        self
+    }
+
+    /// Sets the message produced when an offer moves over the
+    /// [`DndDestination`].
+    pub fn on_motion(
+        mut self,
+        on_motion: impl Fn(Point) -> Message + 'a,
+    ) -> Self {
+        self.on_motion = Some(Box::new(on_motion));
+        self
+    }
+
+    /// Sets the message produced when an offer leaves the
+    /// [`DndDestination`] without being dropped.
+    pub fn on_leave(mut self, message: Message) -> Self {
+        self.on_leave = Some(message);
+        self
+    }
+
+    /// Sets the message produced when the [`DndAction`] of an offer is
+    /// negotiated.
+    pub fn on_action_selected(
+        mut self,
+        on_action_selected: impl Fn(DndAction) -> Message + 'a,
+    ) -> Self {
+        self.on_action_selected = Some(Box::new(on_action_selected));
+        self
+    }
+
+    /// Sets the message produced when an offer is dropped on the
+    /// [`DndDestination`].
+    ///
+    /// The dropped data is received afterwards.
+    pub fn on_drop(mut self, on_drop: impl Fn(Point) -> Message + 'a) -> Self {
+        self.on_drop = Some(Box::new(on_drop));
+        self
+    }
+
+    /// Sets the message produced when the data of a dropped offer is
+    /// received, given its MIME type, its bytes and the selected
+    /// [`DndAction`].
+    pub fn on_data(
+        mut self,
+        on_data: impl Fn(String, Vec<u8>, DndAction) -> Message + 'a,
+    ) -> Self {
+        self.on_data = Some(Box::new(on_data));
+        self
+    }
+
+    fn destination_id(&self) -> Option<u128> {
+        self.drag_id.or_else(|| destination_id(&self.id))
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    offer: Option<Offer>,
+}
+
+#[derive(Debug)]
+struct Offer {
+    position: Point,
+    action: DndAction,
+    is_dropped: bool,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DndDestination<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_mut(&mut self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(
+            Some(&self.id),
+            layout.bounds(),
+            &mut |operation| {
+                self.content.as_widget().operate(
+                    &mut tree.children[0],
+                    layout,
+                    renderer,
+                    operation,
+                );
+            },
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let event::Status::Captured = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        ) {
+            return event::Status::Captured;
+        }
+
+        let Event::Dnd(DndEvent::Offer(id, offer_event)) = event else {
+            return event::Status::Ignored;
+        };
+
+        if id.is_none() || id != self.destination_id() {
+            return event::Status::Ignored;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        let forwarded = match offer_event {
+            OfferEvent::Enter {
+                x, y, mime_types, ..
+            } => {
+                let position = Point::new(x as f32, y as f32);
+
+                state.offer = Some(Offer {
+                    position,
+                    action: DndAction::empty(),
+                    is_dropped: false,
+                });
+
+                if let Some(on_enter) = &self.on_enter {
+                    shell.publish(on_enter(position, mime_types));
+                }
+
+                Some(mouse::Event::CursorMoved { position })
+            }
+            OfferEvent::Motion { x, y } => {
+                let position = Point::new(x as f32, y as f32);
+
+                if let Some(offer) = &mut state.offer {
+                    offer.position = position;
+                } else {
+                    state.offer = Some(Offer {
+                        position,
+                        action: DndAction::empty(),
+                        is_dropped: false,
+                    });
+
+                    if let Some(on_enter) = &self.on_enter {
+                        shell.publish(on_enter(position, Vec::new()));
+                    }
+                }
+
+                if let Some(on_motion) = &self.on_motion {
+                    shell.publish(on_motion(position));
+                }
+
+                Some(mouse::Event::CursorMoved { position })
+            }
+            OfferEvent::Leave | OfferEvent::LeaveDestination => {
+                // The data of a dropped offer is received after it leaves
+                if state.offer.as_ref().is_some_and(|offer| !offer.is_dropped) {
+                    state.offer = None;
+
+                    if let Some(on_leave) = self.on_leave.clone() {
+                        shell.publish(on_leave);
+                    }
+                }
+
+                Some(mouse::Event::CursorLeft)
+            }
+            OfferEvent::SelectedAction(action) => {
+                if let Some(offer) = &mut state.offer {
+                    offer.action = action;
+                }
+
+                if let Some(on_action_selected) = &self.on_action_selected {
+                    shell.publish(on_action_selected(action));
+                }
+
+                None
+            }
+            OfferEvent::Drop => {
+                if let Some(offer) = &mut state.offer {
+                    offer.is_dropped = true;
+
+                    if let Some(on_drop) = &self.on_drop {
+                        shell.publish(on_drop(offer.position));
+                    }
+                }
+
+                None
+            }
+            OfferEvent::Data { data, mime_type } => {
+                let Some(offer) = state.offer.take_if(|offer| offer.is_dropped)
+                else {
+                    return event::Status::Ignored;
+                };
+
+                if let Some(on_data) = &self.on_data {
+                    shell.publish(on_data(mime_type, data, offer.action));
+                }
+
+                None
+            }
+        };
+
+        if let Some(forwarded) =
+            forwarded.filter(|_| self.forward_drag_as_cursor)
+        {
+            let cursor = match forwarded {
+                mouse::Event::CursorMoved { position } => {
+                    mouse::Cursor::Available(position)
+                }
+                _ => mouse::Cursor::Unavailable,
+            };
+
+            let _ = self.content.as_widget_mut().on_event(
+                &mut tree.children[0],
+                Event::Mouse(forwarded),
+                layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+
+        event::Status::Captured
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            renderer_style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            translation,
+        )
+    }
+
+    fn drag_destinations(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        dnd_rectangles: &mut DndDestinationRectangles,
+    ) {
+        if let Some(id) = self.destination_id() {
+            let bounds = layout.bounds();
+
+            dnd_rectangles.push(DndDestinationRectangle {
+                id,
+                rectangle: rectangle(bounds),
+                mime_types: self.mime_types.clone(),
+                actions: self.actions,
+                preferred: self.preferred_action,
+            });
+        }
+
+        self.content.as_widget().drag_destinations(
+            &state.children[0],
+            layout,
+            renderer,
+            dnd_rectangles,
+        );
+    }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        self.content
+            .as_widget()
+            .a11y_nodes(layout, &state.children[0], cursor)
+    }
+
+    fn id(&self) -> Option<Id> {
+        Some(self.id.clone())
+    }
+
+    fn set_id(&mut self, id: Id) {
+        self.id = id;
+    }
+
+    fn inspect(&self, tree: &Tree, layout: Layout<'_>) -> inspect::Node {
+        inspect::Node::new::<Self>(self.id(), self.size(), layout)
+            .with_children([self
+                .content
+                .as_widget()
+                .inspect(&tree.children[0], layout)])
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<DndDestination<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(
+        destination: DndDestination<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(destination)
+    }
+}
+
+/// The MIME types of the plain text accepted by the text widgets.
+pub(crate) const TEXT_MIME_TYPES: [&str; 2] =
+    ["text/plain;charset=utf-8", "text/plain"];
+
+/// The MIME type of a list of dropped files.
+pub(crate) const URI_LIST_MIME_TYPE: &str = "text/uri-list";
+
+/// Returns the identifier of the destination rectangle of the widget with
+/// the given [`Id`], if it can have one.
+pub(crate) fn destination_id(id: &Id) -> Option<u128> {
+    match &id.0 {
+        Internal::Unique(id) | Internal::Custom(id, _) => Some(u128::from(*id)),
+        Internal::Set(_) => None,
+    }
+}
+
+/// Returns the destination rectangle of a text widget accepting dropped
+/// text and, optionally, dropped files.
+pub(crate) fn text_destination(
+    id: u128,
+    bounds: Rectangle,
+    accepts_text: bool,
+    accepts_files: bool,
+) -> DndDestinationRectangle {
+    // Files are preferred over their textual representation
+    let files = accepts_files.then_some(URI_LIST_MIME_TYPE);
+    let text = TEXT_MIME_TYPES.into_iter().filter(|_| accepts_text);
+
+    DndDestinationRectangle {
+        id,
+        rectangle: rectangle(bounds),
+        mime_types: files.into_iter().chain(text).map(Cow::Borrowed).collect(),
+        actions: DndAction::Copy | DndAction::Move,
+        preferred: DndAction::Copy,
+    }
+}
+
+/// Parses the paths of the local files in a `text/uri-list`.
+///
+/// URIs of other schemes or of files in remote hosts are ignored.
+pub(crate) fn file_paths(data: &[u8]) -> Vec<PathBuf> {
+    String::from_utf8_lossy(data)
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.starts_with('#'))
+        .filter_map(file_path)
+        .collect()
+}
+
+fn file_path(uri: &str) -> Option<PathBuf> {
+    let rest = uri.strip_prefix("file://")?;
+    let start = rest.find('/')?;
+
+    let host = &rest[..start];
+
+    if !host.is_empty() && !host.eq_ignore_ascii_case("localhost") {
+        return None;
+    }
+
+    let path = &rest[start..];
+
+    // Drive letters are preceded by the separator of the URI; like
+    // in `file:///C:/Users`
+    #[cfg(windows)]
+    let path = match path.as_bytes() {
+        [b'/', drive, b':', ..] if drive.is_ascii_alphabetic() => &path[1..],
+        _ => path,
+    };
+
+    os_string(percent_decode(path)).map(PathBuf::from)
+}
+
+#[cfg(unix)]
+fn os_string(bytes: Vec<u8>) -> Option<OsString> {
+    use std::os::unix::ffi::OsStringExt;
+
+    Some(OsString::from_vec(bytes))
+}
+
+#[cfg(not(unix))]
+fn os_string(bytes: Vec<u8>) -> Option<OsString> {
+    String::from_utf8(bytes).ok().map(OsString::from)
+}
+
+fn percent_decode(input: &str) -> Vec<u8> {
+    let bytes = input.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let escaped = bytes
+            .get(i + 1..i + 3)
+            .filter(|_| bytes[i] == b'%')
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+        if let Some(byte) = escaped {
+            decoded.push(byte);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+
+    decoded
+}
+
+fn rectangle(bounds: Rectangle) -> dnd::Rectangle {
+    dnd::Rectangle {
+        x: f64::from(bounds.x),
+        y: f64::from(bounds.y),
+        width: f64::from(bounds.width),
+        height: f64::from(bounds.height),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn local_files_are_parsed() {
+        let data = b"# Dropped files\r\n\
+            file:///home/user/notes.txt\r\n\
+            file://localhost/home/user/My%20Documents/report.pdf\r\n";
+
+        assert_eq!(
+            file_paths(data),
+            vec![
+                PathBuf::from("/home/user/notes.txt"),
+                PathBuf::from("/home/user/My Documents/report.pdf"),
+            ]
+        );
+    }
+
+    #[test]
+    fn remote_files_and_other_schemes_are_ignored() {
+        let data = b"file://example.com/home/user/notes.txt\n\
+            https://iced.rs/index.html\n\
+            /home/user/notes.txt\n";
+
+        assert!(file_paths(data).is_empty());
+    }
+
+    #[test]
+    fn invalid_escapes_are_kept() {
+        assert_eq!(percent_decode("/100%"), b"/100%");
+        assert_eq!(percent_decode("/50%25/%zz"), b"/50%/%zz");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn non_utf8_file_names_are_preserved() {
+        use std::os::unix::ffi::OsStrExt;
+
+        let paths = file_paths(b"file:///tmp/caf%E9.txt");
+
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths[0].as_os_str().as_bytes(), b"/tmp/caf\xe9.txt");
+    }
+}
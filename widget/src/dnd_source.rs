@@ -0,0 +1,447 @@
+//! Start drag and drop operations from any widget.
+use crate::core::clipboard::{self, IconSurface};
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::{inspect, tree, Id, Operation, Tree};
+use crate::core::{
+    Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+
+use dnd::{DndAction, DndEvent, SourceEvent};
+use window_clipboard::mime::AsMimeTypes;
+
+/// A widget that starts a drag and drop operation when its content is
+/// dragged.
+///
+/// The dragged data is produced lazily by the closure given to
+/// [`DndSource::drag_content`], and it is offered to the destinations in
+/// every MIME type it supports.
+#[allow(missing_debug_implementations)]
+pub struct DndSource<
+    'a,
+    Message,
+    D,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    id: Id,
+    content: Element<'a, Message, Theme, Renderer>,
+    actions: DndAction,
+    drag_threshold: f32,
+    drag_content: Option<Box<dyn Fn() -> D + 'a>>,
+    drag_icon:
+        Option<Box<dyn Fn() -> Element<'static, (), Theme, Renderer> + 'a>>,
+    on_start: Option<Message>,
+    on_finish: Option<Box<dyn Fn(DndAction) -> Message + 'a>>,
+    on_cancel: Option<Message>,
+}
+
+impl<'a, Message, D, Theme, Renderer>
+    DndSource<'a, Message, D, Theme, Renderer>
+{
+    /// The default distance the cursor has to travel before a drag starts.
+    pub const DEFAULT_DRAG_THRESHOLD: f32 = 8.0;
+
+    /// Creates a new [`DndSource`] with the given content.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            id: Id::unique(),
+            content: content.into(),
+            actions: DndAction::Copy | DndAction::Move,
+            drag_threshold: Self::DEFAULT_DRAG_THRESHOLD,
+            drag_content: None,
+            drag_icon: None,
+            on_start: None,
+            on_finish: None,
+            on_cancel: None,
+        }
+    }
+
+    /// Sets the [`Id`] of the [`DndSource`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = id;
+        self
+    }
+
+    /// Sets the [`DndAction`]s offered to the destinations.
+    ///
+    /// By default, both [`DndAction::Copy`] and [`DndAction::Move`] are
+    /// offered.
+    pub fn actions(mut self, actions: DndAction) -> Self {
+        self.actions = actions;
+        self
+    }
+
+    /// Sets the distance the cursor has to travel while pressed before a
+    /// drag starts.
+    pub fn drag_threshold(mut self, threshold: f32) -> Self {
+        self.drag_threshold = threshold;
+        self
+    }
+
+    /// Sets the closure that produces the dragged data.
+    ///
+    /// No drag is started unless the [`DndSource`] has some content.
+    pub fn drag_content(mut self, content: impl Fn() -> D + 'a) -> Self {
+        self.drag_content = Some(Box::new(content));
+        self
+    }
+
+    /// Sets the closure that produces the icon following the cursor
+    /// during the drag.
+    pub fn drag_icon(
+        mut self,
+        icon: impl Fn() -> Element<'static, (), Theme, Renderer> + 'a,
+    ) -> Self {
+        self.drag_icon = Some(Box::new(icon));
+        self
+    }
+
+    /// Sets the message produced when a drag starts.
+    pub fn on_start(mut self, message: Message) -> Self {
+        self.on_start = Some(message);
+        self
+    }
+
+    /// Sets the message produced when the dragged data is dropped on a
+    /// destination, given the [`DndAction`] it was dropped with.
+    ///
+    /// The original data should only be removed when the action is
+    /// [`DndAction::Move`]. The action is empty if the destination never
+    /// chose one.
+    pub fn on_finish(
+        mut self,
+        on_finish: impl Fn(DndAction) -> Message + 'a,
+    ) -> Self {
+        self.on_finish = Some(Box::new(on_finish));
+        self
+    }
+
+    /// Sets the message produced when a drag is cancelled.
+    pub fn on_cancel(mut self, message: Message) -> Self {
+        self.on_cancel = Some(message);
+        self
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    is_pressed_at: Option<Point>,
+    is_dragging: bool,
+    action: Option<DndAction>,
+}
+
+impl<'a, Message, D, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DndSource<'a, Message, D, Theme, Renderer>
+where
+    Message: Clone,
+    D: AsMimeTypes + Send + 'static,
+    Theme: 'static,
+    Renderer: renderer::Renderer + 'static,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_mut(&mut self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(
+            Some(&self.id),
+            layout.bounds(),
+            &mut |operation| {
+                self.content.as_widget().operate(
+                    &mut tree.children[0],
+                    layout,
+                    renderer,
+                    operation,
+                );
+            },
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let event::Status::Captured = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        ) {
+            return event::Status::Captured;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if self.drag_content.is_none() {
+                    return event::Status::Ignored;
+                }
+
+                if let Some(position) = cursor.position_over(layout.bounds()) {
+                    state.is_pressed_at = Some(position);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.is_pressed_at.take().is_some() {
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                let Some(pressed_at) = state.is_pressed_at else {
+                    return event::Status::Ignored;
+                };
+
+                if position.distance(pressed_at) < self.drag_threshold {
+                    return event::Status::Captured;
+                }
+
+                let Some(content) = self.drag_content.as_ref().map(|f| f())
+                else {
+                    state.is_pressed_at = None;
+                    return event::Status::Ignored;
+                };
+
+                let bounds = layout.bounds();
+                let icon = self.drag_icon.as_ref().map(|f| {
+                    let icon = f();
+                    let state = icon.as_widget().state();
+
+                    IconSurface::new(
+                        icon,
+                        state,
+                        bounds.position() - pressed_at,
+                    )
+                });
+
+                clipboard::start_dnd(
+                    clipboard,
+                    false,
+                    Some(clipboard::DndSource::Widget(self.id.clone())),
+                    icon,
+                    Box::new(content),
+                    self.actions,
+                );
+
+                state.is_pressed_at = None;
+                state.is_dragging = true;
+                state.action = None;
+
+                if let Some(on_start) = self.on_start.clone() {
+                    shell.publish(on_start);
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Dnd(DndEvent::Source(SourceEvent::Action(action)))
+                if state.is_dragging =>
+            {
+                state.action = Some(action);
+            }
+            Event::Dnd(DndEvent::Source(SourceEvent::Finished))
+                if state.is_dragging =>
+            {
+                state.is_dragging = false;
+
+                let action = state.action.take().unwrap_or(DndAction::empty());
+
+                if let Some(on_finish) = &self.on_finish {
+                    shell.publish(on_finish(action));
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Dnd(DndEvent::Source(SourceEvent::Cancelled))
+                if state.is_dragging =>
+            {
+                state.is_dragging = false;
+                state.action = None;
+
+                if let Some(on_cancel) = self.on_cancel.clone() {
+                    shell.publish(on_cancel);
+                }
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.is_dragging {
+            return mouse::Interaction::Grabbing;
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            renderer_style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            translation,
+        )
+    }
+
+    fn drag_destinations(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        dnd_rectangles: &mut clipboard::DndDestinationRectangles,
+    ) {
+        self.content.as_widget().drag_destinations(
+            &state.children[0],
+            layout,
+            renderer,
+            dnd_rectangles,
+        );
+    }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        self.content
+            .as_widget()
+            .a11y_nodes(layout, &state.children[0], cursor)
+    }
+
+    fn id(&self) -> Option<Id> {
+        Some(self.id.clone())
+    }
+
+    fn set_id(&mut self, id: Id) {
+        self.id = id;
+    }
+
+    fn inspect(&self, tree: &Tree, layout: Layout<'_>) -> inspect::Node {
+        inspect::Node::new::<Self>(self.id(), self.size(), layout)
+            .with_children([self
+                .content
+                .as_widget()
+                .inspect(&tree.children[0], layout)])
+    }
+}
+
+impl<'a, Message, D, Theme, Renderer>
+    From<DndSource<'a, Message, D, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    D: AsMimeTypes + Send + 'static,
+    Theme: 'static,
+    Renderer: renderer::Renderer + 'static,
+{
+    fn from(
+        source: DndSource<'a, Message, D, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(source)
+    }
+}
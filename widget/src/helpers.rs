@@ -7,6 +7,8 @@ use crate::core;
 use crate::core::widget::operation::{self, Operation};
 use crate::core::window;
 use crate::core::{Element, Length, Pixels, Widget};
+use crate::dnd_destination::DndDestination;
+use crate::dnd_source::DndSource;
 use crate::keyed;
 use crate::list::{self, List};
 use crate::overlay;
@@ -510,6 +512,14 @@ where
                 translation,
             )
         }
+
+        fn inspect(
+            &self,
+            tree: &Tree,
+            layout: Layout<'_>,
+        ) -> core::widget::inspect::Node {
+            self.content.as_widget().inspect(tree, layout)
+        }
     }
 
     Element::new(Opaque {
@@ -772,6 +782,26 @@ where
 
             top_overlay
         }
+
+        fn inspect(
+            &self,
+            tree: &Tree,
+            layout: Layout<'_>,
+        ) -> core::widget::inspect::Node {
+            let children = [&self.base, &self.top]
+                .into_iter()
+                .zip(layout.children().zip(&tree.children))
+                .map(|(child, (layout, tree))| {
+                    child.as_widget().inspect(tree, layout)
+                });
+
+            core::widget::inspect::Node::new::<Self>(
+                self.id(),
+                self.size(),
+                layout,
+            )
+            .with_children(children)
+        }
     }
 
     Element::new(Hover {
@@ -1845,6 +1875,43 @@ where
     MouseArea::new(widget)
 }
 
+/// Creates a new [`DndSource`] that starts a drag and drop operation when
+/// its content is dragged.
+pub fn dnd_source<'a, Message, D, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> DndSource<'a, Message, D, Theme, Renderer>
+where
+    D: window_clipboard::mime::AsMimeTypes + Send + 'static,
+    Renderer: core::Renderer,
+{
+    DndSource::new(content)
+}
+
+/// Creates a new [`DndDestination`] that accepts drag and drop offers of the
+/// given MIME types.
+pub fn dnd_destination<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    mime_types: Vec<std::borrow::Cow<'static, str>>,
+) -> DndDestination<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    DndDestination::new(content, mime_types)
+}
+
+/// Creates a new [`DndDestination`] that accepts drag and drop offers of
+/// some data `T`, producing a message once it is dropped.
+pub fn dnd_destination_for_data<'a, T, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    on_data: impl Fn(Option<T>, dnd::DndAction) -> Message + 'a,
+) -> DndDestination<'a, Message, Theme, Renderer>
+where
+    T: window_clipboard::mime::AllowedMimeTypes,
+    Renderer: core::Renderer,
+{
+    DndDestination::for_data(content, on_data)
+}
+
 /// A widget that applies any `Theme` to its contents.
 pub fn themer<'a, Message, OldTheme, NewTheme, Renderer>(
     new_theme: NewTheme,
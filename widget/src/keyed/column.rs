@@ -2,16 +2,17 @@
 //! Distribute content vertically.
 
 use crate::core::event::{self, Event};
-use crate::core::layout;
+use crate::core::layout::{self, flex::Axis};
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::widget::tree::{self, Tree};
-use crate::core::widget::Operation;
+use crate::core::widget::{inspect, Operation};
 use crate::core::{
     Alignment, Clipboard, Element, Layout, Length, Padding, Pixels, Rectangle,
     Shell, Size, Vector, Widget,
 };
+use crate::reorder;
 
 /// A container that distributes its contents vertically while keeping continuity.
 ///
@@ -50,6 +51,7 @@ pub struct Column<
     align_items: Alignment,
     keys: Vec<Key>,
     children: Vec<Element<'a, Message, Theme, Renderer>>,
+    on_reorder: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
 }
 
 impl<'a, Key, Message, Theme, Renderer>
@@ -83,6 +85,7 @@ where
             align_items: Alignment::Start,
             keys,
             children,
+            on_reorder: None,
         }
     }
 
@@ -145,6 +148,24 @@ where
         self
     }
 
+    /// Sets the message that should be produced when a child of the
+    /// [`Column`] is dragged to a new position.
+    ///
+    /// The closure receives the index of the child and the index it should
+    /// be moved to, once it is removed from its original position. Since
+    /// children are keyed, their state follows them to their new position.
+    ///
+    /// A parent [`Scrollable`] scrolls while dragging near its edges.
+    ///
+    /// [`Scrollable`]: crate::Scrollable
+    pub fn on_reorder(
+        mut self,
+        on_reorder: impl Fn(usize, usize) -> Message + 'a,
+    ) -> Self {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
     /// Adds an element to the [`Column`].
     pub fn push(
         mut self,
@@ -203,6 +224,7 @@ where
     Key: Copy + PartialEq,
 {
     keys: Vec<Key>,
+    reorder: reorder::State,
 }
 
 impl<'a, Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -218,6 +240,7 @@ where
     fn state(&self) -> tree::State {
         tree::State::new(State {
             keys: self.keys.clone(),
+            reorder: reorder::State::default(),
         })
     }
 
@@ -314,7 +337,35 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
-        self.children
+        let mut status = event::Status::Ignored;
+
+        let cursor = if let Some(on_reorder) = &self.on_reorder {
+            let state = &mut tree.state.downcast_mut::<State<Key>>().reorder;
+            let was_picked = state.is_picked();
+
+            status = reorder::update(
+                state,
+                &Axis::Vertical,
+                self.spacing,
+                &event,
+                layout,
+                cursor,
+                viewport,
+                shell,
+                on_reorder.as_ref(),
+            );
+
+            if was_picked || state.is_picked() {
+                mouse::Cursor::Unavailable
+            } else {
+                cursor
+            }
+        } else {
+            cursor
+        };
+
+        let status = self
+            .children
             .iter_mut()
             .zip(&mut tree.children)
             .zip(layout.children())
@@ -330,7 +381,18 @@ where
                     viewport,
                 )
             })
-            .fold(event::Status::Ignored, event::Status::merge)
+            .fold(status, event::Status::merge);
+
+        if self.on_reorder.is_some() && status == event::Status::Ignored {
+            reorder::press(
+                &mut tree.state.downcast_mut::<State<Key>>().reorder,
+                &event,
+                layout,
+                cursor,
+            );
+        }
+
+        status
     }
 
     fn mouse_interaction(
@@ -341,6 +403,10 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
+        if tree.state.downcast_ref::<State<Key>>().reorder.is_picked() {
+            return mouse::Interaction::Grabbing;
+        }
+
         self.children
             .iter()
             .zip(&tree.children)
@@ -368,6 +434,24 @@ where
         cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
+        let state = &tree.state.downcast_ref::<State<Key>>().reorder;
+
+        if state.is_picked() {
+            reorder::draw(
+                state,
+                &Axis::Vertical,
+                &self.children,
+                tree,
+                renderer,
+                theme,
+                style,
+                layout,
+                viewport,
+            );
+
+            return;
+        }
+
         for ((child, state), c_layout) in self
             .children
             .iter()
@@ -401,6 +485,24 @@ where
             translation,
         )
     }
+
+    fn inspect(&self, tree: &Tree, layout: Layout<'_>) -> inspect::Node {
+        inspect::Node::new::<Self>(self.id(), self.size(), layout)
+            .with_padding(self.padding)
+            .with_children(
+                self.children
+                    .iter()
+                    .zip(&tree.children)
+                    .zip(layout.children())
+                    .map(|((child, state), c_layout)| {
+                        child.as_widget().inspect(
+                            state,
+                            c_layout
+                                .with_virtual_offset(layout.virtual_offset()),
+                        )
+                    }),
+            )
+    }
 }
 
 impl<'a, Key, Message, Theme, Renderer>
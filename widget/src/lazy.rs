@@ -16,6 +16,7 @@ use crate::core::layout::{self, Layout};
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::widget::inspect;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::widget::Widget;
 use crate::core::Element;
@@ -342,6 +343,15 @@ where
             );
         });
     }
+
+    fn inspect(&self, tree: &Tree, layout: Layout<'_>) -> inspect::Node {
+        self.with_element(|element| {
+            inspect::Node::new::<Self>(self.id(), self.size(), layout)
+                .with_children([element
+                    .as_widget()
+                    .inspect(&tree.children[0], layout)])
+        })
+    }
 }
 
 #[self_referencing]
@@ -6,6 +6,7 @@ use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::widget;
+use crate::core::widget::inspect;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
     self, Clipboard, Element, Length, Point, Rectangle, Shell, Size, Vector,
@@ -534,6 +535,21 @@ where
             )
         });
     }
+
+    fn inspect(&self, tree: &Tree, layout: Layout<'_>) -> inspect::Node {
+        let node = inspect::Node::new::<Self>(self.id(), self.size(), layout);
+        let tree = tree.state.downcast_ref::<Rc<RefCell<Option<Tree>>>>();
+
+        let Some(tree) = tree.borrow().as_ref().map(|tree| {
+            self.with_element(|element| {
+                element.as_widget().inspect(&tree.children[0], layout)
+            })
+        }) else {
+            return node;
+        };
+
+        node.with_children([tree])
+    }
 }
 
 struct Overlay<'a, 'b, Message, Theme, Renderer, Event, S>(
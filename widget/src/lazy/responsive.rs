@@ -3,6 +3,7 @@ use crate::core::layout::{self, Layout};
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::widget::inspect;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
     self, Clipboard, Element, Length, Point, Rectangle, Shell, Size, Vector,
@@ -377,6 +378,26 @@ where
         );
         ret
     }
+
+    fn inspect(&self, tree: &Tree, layout: Layout<'_>) -> inspect::Node {
+        let node = inspect::Node::new::<Self>(self.id(), self.size(), layout);
+
+        let content = self.content.borrow();
+        let state = tree.state.downcast_ref::<State>().tree.borrow();
+
+        // The contents are only known once laid out
+        let Some(content_layout) = content.layout.as_ref() else {
+            return node;
+        };
+
+        node.with_children([content.element.as_widget().inspect(
+            &state,
+            Layout::with_offset(
+                layout.position() - Point::ORIGIN,
+                content_layout,
+            ),
+        )])
+    }
 }
 
 impl<'a, Message, Theme, Renderer>
@@ -10,6 +10,7 @@ pub use iced_runtime::core;
 
 mod column;
 mod mouse_area;
+mod reorder;
 mod row;
 mod space;
 mod stack;
@@ -19,6 +20,9 @@ pub mod button;
 pub mod checkbox;
 pub mod combo_box;
 pub mod container;
+pub mod dialog;
+pub mod dnd_destination;
+pub mod dnd_source;
 pub mod keyed;
 pub mod list;
 pub mod overlay;
@@ -28,6 +32,7 @@ pub mod progress_bar;
 pub mod radio;
 pub mod rule;
 pub mod scrollable;
+pub mod shortcuts;
 pub mod slider;
 pub mod text;
 pub mod text_editor;
@@ -57,6 +62,10 @@ pub use combo_box::ComboBox;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use dnd_destination::DndDestination;
+#[doc(no_inline)]
+pub use dnd_source::DndSource;
+#[doc(no_inline)]
 pub use list::List;
 #[doc(no_inline)]
 pub use mouse_area::MouseArea;
@@ -75,6 +84,8 @@ pub use rule::Rule;
 #[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
+pub use shortcuts::Shortcuts;
+#[doc(no_inline)]
 pub use slider::Slider;
 #[doc(no_inline)]
 pub use space::Space;
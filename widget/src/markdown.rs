@@ -231,20 +231,26 @@ impl Item {
 
         match self {
             Item::Heading(level, heading) => {
-                container(rich_text(heading.spans(style)).size(match level {
-                    pulldown_cmark::HeadingLevel::H1 => h1_size,
-                    pulldown_cmark::HeadingLevel::H2 => h2_size,
-                    pulldown_cmark::HeadingLevel::H3 => h3_size,
-                    pulldown_cmark::HeadingLevel::H4 => h4_size,
-                    pulldown_cmark::HeadingLevel::H5 => h5_size,
-                    pulldown_cmark::HeadingLevel::H6 => h6_size,
-                }))
-                .padding(padding::top(if index > 0 {
-                    text_size / 2.0
-                } else {
-                    Pixels::ZERO
-                }))
-                .into()
+                let heading =
+                    rich_text(heading.spans(style)).size(match level {
+                        pulldown_cmark::HeadingLevel::H1 => h1_size,
+                        pulldown_cmark::HeadingLevel::H2 => h2_size,
+                        pulldown_cmark::HeadingLevel::H3 => h3_size,
+                        pulldown_cmark::HeadingLevel::H4 => h4_size,
+                        pulldown_cmark::HeadingLevel::H5 => h5_size,
+                        pulldown_cmark::HeadingLevel::H6 => h6_size,
+                    });
+
+                #[cfg(feature = "a11y")]
+                let heading = heading.heading(*level as usize);
+
+                container(heading)
+                    .padding(padding::top(if index > 0 {
+                        text_size / 2.0
+                    } else {
+                        Pixels::ZERO
+                    }))
+                    .into()
             }
             Item::Paragraph(paragraph) => {
                 rich_text(paragraph.spans(style)).size(text_size).into()
@@ -8,7 +8,7 @@ use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::touch;
-use crate::core::widget::{tree, Operation, Tree};
+use crate::core::widget::{inspect, tree, Operation, Tree};
 use crate::core::{
     Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Size, Vector,
     Widget,
@@ -372,6 +372,14 @@ where
         );
         return ret;
     }
+
+    fn inspect(&self, tree: &Tree, layout: Layout<'_>) -> inspect::Node {
+        inspect::Node::new::<Self>(self.id(), self.size(), layout)
+            .with_children([self
+                .content
+                .as_widget()
+                .inspect(&tree.children[0], layout)])
+    }
 }
 
 impl<'a, Message, Theme, Renderer> From<MouseArea<'a, Message, Theme, Renderer>>
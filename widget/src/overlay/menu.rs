@@ -149,6 +149,7 @@ where
 #[derive(Debug)]
 pub struct State {
     tree: Tree,
+    bounds: Option<Rectangle>,
 }
 
 impl State {
@@ -156,8 +157,15 @@ impl State {
     pub fn new() -> Self {
         Self {
             tree: Tree::empty(),
+            bounds: None,
         }
     }
+
+    /// Returns the bounds of the [`Menu`] the last time it was laid out,
+    /// if any.
+    pub fn bounds(&self) -> Option<Rectangle> {
+        self.bounds
+    }
 }
 
 impl Default for State {
@@ -173,6 +181,7 @@ where
 {
     position: Point,
     state: &'a mut Tree,
+    bounds: &'a mut Option<Rectangle>,
     list: Scrollable<'a, Message, Theme, Renderer>,
     width: f32,
     target_height: f32,
@@ -229,6 +238,7 @@ where
         Self {
             position,
             state: &mut state.tree,
+            bounds: &mut state.bounds,
             list,
             width,
             target_height,
@@ -265,11 +275,15 @@ where
         let node = self.list.layout(self.state, renderer, &limits);
         let size = node.size();
 
-        node.move_to(if space_below > space_above {
+        let node = node.move_to(if space_below > space_above {
             self.position + Vector::new(0.0, self.target_height)
         } else {
             self.position - Vector::new(0.0, size.height)
-        })
+        });
+
+        *self.bounds = Some(node.bounds());
+
+        node
     }
 
     fn on_event(
@@ -544,6 +558,7 @@ where
                     vertical_alignment: alignment::Vertical::Center,
                     shaping: self.text_shaping,
                     wrapping: text::Wrapping::default(),
+                    ellipsize: text::Ellipsize::default(),
                 },
                 Point::new(bounds.x + self.padding.left, bounds.center_y()),
                 if is_selected {
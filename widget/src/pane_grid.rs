@@ -85,6 +85,7 @@ use crate::core::mouse;
 use crate::core::overlay::{self, Group};
 use crate::core::renderer;
 use crate::core::touch;
+use crate::core::widget::inspect;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
     self, Background, Border, Clipboard, Color, Element, Layout, Length,
@@ -92,8 +93,17 @@ use crate::core::{
 };
 use log::trace;
 
+#[cfg(feature = "a11y")]
+use crate::core::id::IdEq;
+#[cfg(feature = "a11y")]
+use crate::core::widget::Id;
+#[cfg(feature = "a11y")]
+use std::collections::BTreeMap;
+
 const DRAG_DEADBAND_DISTANCE: f32 = 10.0;
 const THICKNESS_RATIO: f32 = 25.0;
+#[cfg(feature = "a11y")]
+const RATIO_STEP: f32 = 0.05;
 
 /// A collection of panes distributed using either vertical or horizontal splits
 /// to completely fill the space available.
@@ -305,11 +315,11 @@ where
     Renderer: core::Renderer,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<state::Action>()
+        tree::Tag::of::<Memory>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(state::Action::Idle)
+        tree::State::new(Memory::new(&self.contents))
     }
 
     fn children(&self) -> Vec<Tree> {
@@ -320,6 +330,8 @@ where
     }
 
     fn diff(&mut self, tree: &mut Tree) {
+        tree.state.downcast_mut::<Memory>().diff(&self.contents);
+
         match &mut self.contents {
             Contents::All(contents, _) => {
                 let ids = contents.iter().map(|_| None).collect(); // TODO
@@ -413,7 +425,8 @@ where
     ) -> event::Status {
         let mut event_status = event::Status::Ignored;
 
-        let action = tree.state.downcast_mut::<state::Action>();
+        let memory = tree.state.downcast_mut::<Memory>();
+        let action = &mut memory.action;
         let node = self.contents.layout();
 
         let on_drag = if self.drag_enabled() {
@@ -578,6 +591,42 @@ where
                     }
                 }
             }
+            #[cfg(feature = "a11y")]
+            Event::A11y(ref target, ref request) => {
+                use iced_accessibility::accesskit::{Action, ActionData};
+
+                let split = memory.splits.iter().find_map(|(split, id)| {
+                    IdEq::eq(target, id).then_some(*split)
+                });
+
+                if let (Some(split), Some((_, on_resize))) =
+                    (split, &self.on_resize)
+                {
+                    let splits = node
+                        .split_regions(self.spacing, layout.bounds().size());
+
+                    let ratio = splits.get(&split).and_then(|(_, _, ratio)| {
+                        match (request.action, &request.data) {
+                            (Action::Increment, _) => Some(ratio + RATIO_STEP),
+                            (Action::Decrement, _) => Some(ratio - RATIO_STEP),
+                            (
+                                Action::SetValue,
+                                Some(ActionData::NumericValue(value)),
+                            ) => Some(*value as f32),
+                            _ => None,
+                        }
+                    });
+
+                    if let Some(ratio) = ratio {
+                        shell.publish(on_resize(ResizeEvent {
+                            split,
+                            ratio: ratio.clamp(0.1, 0.9),
+                        }));
+
+                        event_status = event::Status::Captured;
+                    }
+                }
+            }
             _ => {}
         }
 
@@ -613,7 +662,7 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
-        let action = tree.state.downcast_ref::<state::Action>();
+        let action = &tree.state.downcast_ref::<Memory>().action;
 
         if action.picked_pane().is_some() {
             return mouse::Interaction::Grabbing;
@@ -680,7 +729,7 @@ where
         cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
-        let action = tree.state.downcast_ref::<state::Action>();
+        let action = &tree.state.downcast_ref::<Memory>().action;
         let node = self.contents.layout();
         let resize_leeway = self.on_resize.as_ref().map(|(leeway, _)| *leeway);
 
@@ -941,6 +990,153 @@ where
                 );
             });
     }
+
+    fn inspect(&self, tree: &Tree, layout: Layout<'_>) -> inspect::Node {
+        inspect::Node::new::<Self>(self.id(), self.size(), layout)
+            .with_children(
+                self.contents
+                    .iter()
+                    .zip(&tree.children)
+                    .zip(layout.children())
+                    .map(|(((_, content), tree), c_layout)| {
+                        content.inspect(
+                            tree,
+                            c_layout
+                                .with_virtual_offset(layout.virtual_offset()),
+                        )
+                    }),
+            )
+    }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::{
+            accesskit::{Action, NodeBuilder, Orientation, Rect, Role},
+            A11yNode, A11yTree,
+        };
+
+        fn rect(bounds: Rectangle) -> Rect {
+            Rect::new(
+                bounds.x as f64,
+                bounds.y as f64,
+                (bounds.x + bounds.width) as f64,
+                (bounds.y + bounds.height) as f64,
+            )
+        }
+
+        let memory = state.state.downcast_ref::<Memory>();
+        let bounds = layout.bounds();
+
+        let panes = self
+            .contents
+            .iter()
+            .zip(&state.children)
+            .zip(layout.children())
+            .filter_map(|(((pane, content), tree), c_layout)| {
+                let id = memory.panes.get(&pane)?;
+                let c_layout =
+                    c_layout.with_virtual_offset(layout.virtual_offset());
+
+                let mut node = NodeBuilder::new(Role::Pane);
+                node.set_bounds(rect(c_layout.bounds()));
+
+                Some(A11yTree::node_with_child_tree(
+                    A11yNode::new(node, id.clone()),
+                    content.a11y_nodes(tree, c_layout, cursor),
+                ))
+            });
+
+        let splits = self
+            .contents
+            .layout()
+            .split_regions(self.spacing, bounds.size())
+            .into_iter()
+            .filter_map(|(split, (axis, region, ratio))| {
+                let id = memory.splits.get(&split)?;
+                let line = axis.split_line_bounds(region, ratio, self.spacing)
+                    + Vector::new(bounds.x, bounds.y);
+
+                let mut node = NodeBuilder::new(Role::Splitter);
+                node.set_bounds(rect(line));
+                node.set_orientation(match axis {
+                    Axis::Horizontal => Orientation::Horizontal,
+                    Axis::Vertical => Orientation::Vertical,
+                });
+                node.set_numeric_value(ratio as f64);
+                node.set_min_numeric_value(0.1);
+                node.set_max_numeric_value(0.9);
+                node.set_numeric_value_step(RATIO_STEP as f64);
+
+                if self.on_resize.is_some() {
+                    node.add_action(Action::Increment);
+                    node.add_action(Action::Decrement);
+                    node.add_action(Action::SetValue);
+                }
+
+                if cursor.is_over(line) {
+                    node.set_hovered();
+                }
+
+                Some(A11yTree::leaf(node, id.clone()))
+            });
+
+        A11yTree::join(panes.chain(splits))
+    }
+}
+
+/// The internal state of a [`PaneGrid`].
+#[derive(Debug)]
+struct Memory {
+    action: state::Action,
+    /// The accessibility ids of the panes.
+    #[cfg(feature = "a11y")]
+    panes: BTreeMap<Pane, Id>,
+    /// The accessibility ids of the splits.
+    #[cfg(feature = "a11y")]
+    splits: BTreeMap<Split, Id>,
+}
+
+impl Memory {
+    fn new<T>(contents: &Contents<'_, T>) -> Self {
+        let mut memory = Self {
+            action: state::Action::Idle,
+            #[cfg(feature = "a11y")]
+            panes: BTreeMap::new(),
+            #[cfg(feature = "a11y")]
+            splits: BTreeMap::new(),
+        };
+
+        memory.diff(contents);
+        memory
+    }
+
+    /// Keeps an accessibility id for each pane and split of the given
+    /// [`Contents`], so they stay the same between frames.
+    #[cfg(feature = "a11y")]
+    fn diff<T>(&mut self, contents: &Contents<'_, T>) {
+        self.panes
+            .retain(|pane, _| contents.iter().any(|(other, _)| other == *pane));
+
+        for (pane, _) in contents.iter() {
+            let _ = self.panes.entry(pane).or_insert_with(Id::unique);
+        }
+
+        let splits: Vec<Split> = contents.layout().splits().copied().collect();
+
+        self.splits.retain(|split, _| splits.contains(split));
+
+        for split in splits {
+            let _ = self.splits.entry(split).or_insert_with(Id::unique);
+        }
+    }
+
+    #[cfg(not(feature = "a11y"))]
+    fn diff<T>(&mut self, _contents: &Contents<'_, T>) {}
 }
 
 impl<'a, Message, Theme, Renderer> From<PaneGrid<'a, Message, Theme, Renderer>>
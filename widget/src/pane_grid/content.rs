@@ -5,9 +5,10 @@ use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
-use crate::core::widget::{self, Tree};
+use crate::core::widget::{self, inspect, Tree};
 use crate::core::{
-    self, Clipboard, Element, Layout, Point, Rectangle, Shell, Size, Vector,
+    self, Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Size,
+    Vector,
 };
 use crate::pane_grid::{Draggable, TitleBar};
 
@@ -240,6 +241,40 @@ where
         );
     }
 
+    #[cfg(feature = "a11y")]
+    pub(crate) fn a11y_nodes(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        if let Some(title_bar) = &self.title_bar {
+            let mut children = layout.children();
+            let title_bar_layout = children.next().unwrap();
+            let body_layout = children.next().unwrap();
+
+            iced_accessibility::A11yTree::join(
+                [
+                    title_bar.a11y_nodes(
+                        &tree.children[1],
+                        title_bar_layout,
+                        cursor,
+                    ),
+                    self.body.as_widget().a11y_nodes(
+                        body_layout,
+                        &tree.children[0],
+                        cursor,
+                    ),
+                ]
+                .into_iter(),
+            )
+        } else {
+            self.body
+                .as_widget()
+                .a11y_nodes(layout, &tree.children[0], cursor)
+        }
+    }
+
     pub(crate) fn on_event(
         &mut self,
         tree: &mut Tree,
@@ -426,6 +461,36 @@ where
             )
         }
     }
+
+    pub(crate) fn inspect(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+    ) -> inspect::Node {
+        let Some(title_bar) = &self.title_bar else {
+            return self.body.as_widget().inspect(&tree.children[0], layout);
+        };
+
+        let mut children = layout.children();
+        let node = inspect::Node::new::<Self>(
+            None,
+            Size::new(Length::Fill, Length::Fill),
+            layout,
+        );
+
+        let (Some(title_bar_layout), Some(body_layout)) =
+            (children.next(), children.next())
+        else {
+            return node;
+        };
+
+        node.with_children([
+            title_bar.inspect(&tree.children[1], title_bar_layout),
+            self.body
+                .as_widget()
+                .inspect(&tree.children[0], body_layout),
+        ])
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Draggable
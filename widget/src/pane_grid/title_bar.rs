@@ -4,10 +4,10 @@ use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
-use crate::core::widget::Tree;
+use crate::core::widget::{inspect, Tree};
 use crate::core::{
-    self, Clipboard, Element, Layout, Padding, Point, Rectangle, Shell, Size,
-    Vector,
+    self, Clipboard, Element, Layout, Length, Padding, Point, Rectangle, Shell,
+    Size, Vector,
 };
 use crate::pane_grid::controls::Controls;
 
@@ -436,6 +436,71 @@ where
         }
     }
 
+    #[cfg(feature = "a11y")]
+    pub(crate) fn a11y_nodes(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        let mut children = layout.children();
+        let padded = children.next().unwrap();
+
+        let mut children = padded.children();
+        let title_layout = children.next().unwrap();
+        let mut show_title = true;
+
+        let controls = if let Some(controls) = &self.controls {
+            let controls_layout = children.next().unwrap();
+
+            if title_layout.bounds().width + controls_layout.bounds().width
+                > padded.bounds().width
+            {
+                if let Some(compact) = controls.compact.as_ref() {
+                    let compact_layout = children.next().unwrap();
+
+                    compact.as_widget().a11y_nodes(
+                        compact_layout,
+                        &tree.children[2],
+                        cursor,
+                    )
+                } else {
+                    show_title = false;
+
+                    controls.full.as_widget().a11y_nodes(
+                        controls_layout,
+                        &tree.children[1],
+                        cursor,
+                    )
+                }
+            } else {
+                controls.full.as_widget().a11y_nodes(
+                    controls_layout,
+                    &tree.children[1],
+                    cursor,
+                )
+            }
+        } else {
+            iced_accessibility::A11yTree::default()
+        };
+
+        if show_title {
+            iced_accessibility::A11yTree::join(
+                [
+                    self.content.as_widget().a11y_nodes(
+                        title_layout,
+                        &tree.children[0],
+                        cursor,
+                    ),
+                    controls,
+                ]
+                .into_iter(),
+            )
+        } else {
+            controls
+        }
+    }
+
     pub(crate) fn on_event(
         &mut self,
         tree: &mut Tree,
@@ -650,4 +715,46 @@ where
                 })
             })
     }
+
+    pub(crate) fn inspect(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+    ) -> inspect::Node {
+        let node = inspect::Node::new::<Self>(
+            None,
+            Size::new(Length::Fill, Length::Shrink),
+            layout,
+        )
+        .with_padding(self.padding);
+
+        let Some(padded) = layout.children().next() else {
+            return node;
+        };
+
+        let mut children = padded.children();
+
+        let title = children.next().map(|title_layout| {
+            self.content
+                .as_widget()
+                .inspect(&tree.children[0], title_layout)
+        });
+
+        // The compact controls are only laid out when they are shown
+        let controls = self.controls.as_ref().and_then(|controls| {
+            let controls_layout = children.next()?;
+
+            Some(match (&controls.compact, children.next()) {
+                (Some(compact), Some(compact_layout)) => compact
+                    .as_widget()
+                    .inspect(&tree.children[2], compact_layout),
+                _ => controls
+                    .full
+                    .as_widget()
+                    .inspect(&tree.children[1], controls_layout),
+            })
+        });
+
+        node.with_children(title.into_iter().chain(controls))
+    }
 }
@@ -72,7 +72,9 @@ use crate::core::renderer;
 use crate::core::text::paragraph;
 use crate::core::text::{self, Text};
 use crate::core::touch;
+use crate::core::widget::inspect;
 use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Id;
 use crate::core::{
     Background, Border, Clipboard, Color, Element, Layout, Length, Padding,
     Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
@@ -82,6 +84,11 @@ use crate::overlay::menu::{self, Menu};
 use std::borrow::Borrow;
 use std::f32;
 
+#[cfg(feature = "a11y")]
+use crate::core::id::IdEq;
+#[cfg(feature = "a11y")]
+use std::borrow::Cow;
+
 /// A widget for selecting a single value from a list of options.
 ///
 /// # Example
@@ -160,6 +167,13 @@ pub struct PickList<
     Theme: Catalog,
     Renderer: text::Renderer,
 {
+    id: Id,
+    #[cfg(feature = "a11y")]
+    name: Option<Cow<'a, str>>,
+    #[cfg(feature = "a11y")]
+    description: Option<iced_accessibility::Description<'a>>,
+    #[cfg(feature = "a11y")]
+    label: Option<Vec<iced_accessibility::accesskit::NodeId>>,
     on_select: Box<dyn Fn(T) -> Message + 'a>,
     on_open: Option<Message>,
     on_close: Option<Message>,
@@ -172,6 +186,7 @@ pub struct PickList<
     text_line_height: text::LineHeight,
     text_shaping: text::Shaping,
     text_wrap: text::Wrapping,
+    text_ellipsize: text::Ellipsize,
     font: Option<Renderer::Font>,
     handle: Handle<Renderer::Font>,
     class: <Theme as Catalog>::Class<'a>,
@@ -196,6 +211,13 @@ where
         on_select: impl Fn(T) -> Message + 'a,
     ) -> Self {
         Self {
+            id: Id::unique(),
+            #[cfg(feature = "a11y")]
+            name: None,
+            #[cfg(feature = "a11y")]
+            description: None,
+            #[cfg(feature = "a11y")]
+            label: None,
             on_select: Box::new(on_select),
             on_open: None,
             on_close: None,
@@ -208,6 +230,7 @@ where
             text_line_height: text::LineHeight::default(),
             text_shaping: text::Shaping::Advanced,
             text_wrap: text::Wrapping::default(),
+            text_ellipsize: text::Ellipsize::default(),
             font: None,
             handle: Handle::default(),
             class: <Theme as Catalog>::default(),
@@ -260,6 +283,13 @@ where
         self
     }
 
+    /// Sets the [`text::Ellipsize`] strategy of the selected label of the
+    /// [`PickList`].
+    pub fn text_ellipsize(mut self, ellipsize: text::Ellipsize) -> Self {
+        self.text_ellipsize = ellipsize;
+        self
+    }
+
     /// Sets the font of the [`PickList`].
     pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
         self.font = Some(font.into());
@@ -328,6 +358,43 @@ where
         self.menu_class = class.into();
         self
     }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the name of the [`PickList`].
+    ///
+    /// By default, the placeholder is used.
+    pub fn name(mut self, name: impl Into<Cow<'a, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`PickList`].
+    pub fn description_widget(
+        mut self,
+        description: &impl iced_accessibility::Describes,
+    ) -> Self {
+        self.description = Some(iced_accessibility::Description::Id(
+            description.description(),
+        ));
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`PickList`].
+    pub fn description(mut self, description: impl Into<Cow<'a, str>>) -> Self {
+        self.description =
+            Some(iced_accessibility::Description::Text(description.into()));
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the label of the [`PickList`].
+    pub fn label(mut self, label: &dyn iced_accessibility::Labels) -> Self {
+        self.label =
+            Some(label.label().into_iter().map(|l| l.into()).collect());
+        self
+    }
 }
 
 impl<'a, T, L, V, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -370,6 +437,14 @@ where
 
         state.options.resize_with(options.len(), Default::default);
 
+        #[cfg(feature = "a11y")]
+        {
+            state.option_ids.resize_with(options.len(), Id::unique);
+            state.option_height =
+                f32::from(self.text_line_height.to_absolute(text_size))
+                    + self.padding.vertical();
+        }
+
         let option_text = Text {
             content: "",
             bounds: Size::new(
@@ -383,6 +458,7 @@ where
             vertical_alignment: alignment::Vertical::Center,
             shaping: self.text_shaping,
             wrapping: self.text_wrap,
+            ellipsize: text::Ellipsize::None,
         };
 
         for (option, paragraph) in options.iter().zip(state.options.iter_mut())
@@ -536,6 +612,86 @@ where
 
                 event::Status::Ignored
             }
+            #[cfg(feature = "a11y")]
+            Event::A11y(target, request) if IdEq::eq(&target, &self.id) => {
+                use iced_accessibility::accesskit::{Action, ActionData};
+
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+                let options = self.options.borrow();
+                let selected = self.selected.as_ref().map(Borrow::borrow);
+                let position =
+                    options.iter().position(|option| Some(option) == selected);
+
+                let next_option = match (request.action, request.data) {
+                    (Action::Expand | Action::Default, _) if !state.is_open => {
+                        state.is_open = true;
+                        state.hovered_option = position;
+
+                        if let Some(on_open) = &self.on_open {
+                            shell.publish(on_open.clone());
+                        }
+
+                        return event::Status::Captured;
+                    }
+                    (Action::Collapse | Action::Default, _)
+                        if state.is_open =>
+                    {
+                        state.is_open = false;
+
+                        if let Some(on_close) = &self.on_close {
+                            shell.publish(on_close.clone());
+                        }
+
+                        return event::Status::Captured;
+                    }
+                    (Action::Increment, _) => match position {
+                        Some(position) => options.get(position + 1),
+                        None => options.first(),
+                    },
+                    (Action::Decrement, _) => match position {
+                        Some(position) => position
+                            .checked_sub(1)
+                            .and_then(|position| options.get(position)),
+                        None => options.last(),
+                    },
+                    (Action::SetValue, Some(ActionData::Value(value))) => {
+                        options
+                            .iter()
+                            .find(|option| option.to_string() == *value)
+                    }
+                    _ => return event::Status::Ignored,
+                };
+
+                if let Some(next_option) = next_option {
+                    shell.publish((self.on_select)(next_option.clone()));
+                }
+
+                event::Status::Captured
+            }
+            #[cfg(feature = "a11y")]
+            Event::A11y(target, request)
+                if request.action
+                    == iced_accessibility::accesskit::Action::Default =>
+            {
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+                let Some(option) = state
+                    .option_ids
+                    .iter()
+                    .position(|id| IdEq::eq(&target, id))
+                    .and_then(|index| self.options.borrow().get(index))
+                else {
+                    return event::Status::Ignored;
+                };
+
+                state.is_open = false;
+                shell.publish((self.on_select)(option.clone()));
+
+                event::Status::Captured
+            }
             _ => event::Status::Ignored,
         }
     }
@@ -657,6 +813,7 @@ where
                     vertical_alignment: alignment::Vertical::Center,
                     shaping,
                     wrapping: wrap,
+                    ellipsize: text::Ellipsize::None,
                 },
                 Point::new(
                     bounds.x + bounds.width - self.padding.right,
@@ -687,6 +844,7 @@ where
                     vertical_alignment: alignment::Vertical::Center,
                     shaping: self.text_shaping,
                     wrapping: self.text_wrap,
+                    ellipsize: self.text_ellipsize,
                 },
                 Point::new(bounds.x + self.padding.left, bounds.center_y()),
                 if is_selected {
@@ -740,6 +898,143 @@ where
             None
         }
     }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::{
+            accesskit::{Action, NodeBuilder, NodeId, Rect, Role},
+            A11yId, A11yNode, A11yTree,
+        };
+
+        let state = state.state.downcast_ref::<State<Renderer::Paragraph>>();
+
+        let rect = |bounds: Rectangle| {
+            Rect::new(
+                bounds.x as f64,
+                bounds.y as f64,
+                (bounds.x + bounds.width) as f64,
+                (bounds.y + bounds.height) as f64,
+            )
+        };
+
+        let bounds = layout.bounds();
+
+        let mut node = NodeBuilder::new(Role::ComboBox);
+        node.set_bounds(rect(bounds));
+        node.add_action(Action::Default);
+        node.add_action(if state.is_open {
+            Action::Collapse
+        } else {
+            Action::Expand
+        });
+        node.add_action(Action::Increment);
+        node.add_action(Action::Decrement);
+        node.add_action(Action::SetValue);
+        node.set_expanded(state.is_open);
+
+        match (self.name.as_ref(), self.placeholder.as_ref()) {
+            (Some(name), _) => node.set_name(name.clone()),
+            (None, Some(placeholder)) => node.set_name(placeholder.clone()),
+            (None, None) => {}
+        }
+
+        match self.description.as_ref() {
+            Some(iced_accessibility::Description::Id(id)) => {
+                node.set_described_by(
+                    id.iter().cloned().map(NodeId::from).collect::<Vec<_>>(),
+                );
+            }
+            Some(iced_accessibility::Description::Text(text)) => {
+                node.set_description(text.clone());
+            }
+            None => {}
+        }
+
+        if let Some(label) = self.label.as_ref() {
+            node.set_labelled_by(label.clone());
+        }
+
+        if cursor.is_over(bounds) {
+            node.set_hovered();
+        }
+
+        let selected = self.selected.as_ref().map(Borrow::borrow);
+
+        if let Some(selected) = selected {
+            node.set_value(selected.to_string());
+        }
+
+        if !state.is_open {
+            return A11yTree::leaf(node, self.id.clone());
+        }
+
+        // The options of the opened menu are exposed as a list box, laid
+        // out like the rows of the menu overlay
+        let menu_bounds = state.menu.bounds();
+
+        let mut list = NodeBuilder::new(Role::ListBox);
+
+        if let Some(menu_bounds) = menu_bounds {
+            list.set_bounds(rect(menu_bounds));
+        }
+
+        node.set_controls(vec![NodeId::from(A11yId::from(
+            state.list_id.clone(),
+        ))]);
+
+        let options = self
+            .options
+            .borrow()
+            .iter()
+            .zip(&state.option_ids)
+            .enumerate()
+            .map(|(index, (option, id))| {
+                let mut node = NodeBuilder::new(Role::ListBoxOption);
+                node.set_name(option.to_string());
+                node.add_action(Action::Default);
+                node.set_selected(Some(option) == selected);
+
+                if let Some(menu_bounds) = menu_bounds {
+                    node.set_bounds(rect(Rectangle {
+                        y: menu_bounds.y + index as f32 * state.option_height,
+                        height: state.option_height,
+                        ..menu_bounds
+                    }));
+                }
+
+                if state.hovered_option == Some(index) {
+                    node.set_hovered();
+                }
+
+                A11yTree::leaf(node, id.clone())
+            });
+
+        A11yTree::node_with_child_tree(
+            A11yNode::new(node, self.id.clone()),
+            A11yTree::node_with_child_tree(
+                A11yNode::new(list, state.list_id.clone()),
+                A11yTree::join(options),
+            ),
+        )
+    }
+
+    fn id(&self) -> Option<Id> {
+        Some(self.id.clone())
+    }
+
+    fn set_id(&mut self, id: Id) {
+        self.id = id;
+    }
+
+    fn inspect(&self, _tree: &Tree, layout: Layout<'_>) -> inspect::Node {
+        inspect::Node::new::<Self>(self.id(), self.size(), layout)
+            .with_padding(self.padding)
+    }
 }
 
 impl<'a, T, L, V, Message, Theme, Renderer>
@@ -768,6 +1063,12 @@ struct State<P: text::Paragraph> {
     hovered_option: Option<usize>,
     options: Vec<paragraph::Plain<P>>,
     placeholder: paragraph::Plain<P>,
+    #[cfg(feature = "a11y")]
+    list_id: Id,
+    #[cfg(feature = "a11y")]
+    option_ids: Vec<Id>,
+    #[cfg(feature = "a11y")]
+    option_height: f32,
 }
 
 impl<P: text::Paragraph> State<P> {
@@ -780,6 +1081,12 @@ impl<P: text::Paragraph> State<P> {
             hovered_option: Option::default(),
             options: Vec::new(),
             placeholder: paragraph::Plain::default(),
+            #[cfg(feature = "a11y")]
+            list_id: Id::unique(),
+            #[cfg(feature = "a11y")]
+            option_ids: Vec::new(),
+            #[cfg(feature = "a11y")]
+            option_height: 0.0,
         }
     }
 }
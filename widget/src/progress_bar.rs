@@ -23,7 +23,7 @@ use crate::core::border::{self, Border};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
-use crate::core::widget::Tree;
+use crate::core::widget::{Id, Tree};
 use crate::core::{
     self, Background, Color, Element, Layout, Length, Rectangle, Size, Theme,
     Widget,
@@ -31,6 +31,9 @@ use crate::core::{
 
 use std::ops::RangeInclusive;
 
+#[cfg(feature = "a11y")]
+use std::borrow::Cow;
+
 /// A bar that displays progress.
 ///
 /// # Example
@@ -57,6 +60,13 @@ pub struct ProgressBar<'a, Theme = crate::Theme>
 where
     Theme: Catalog,
 {
+    id: Id,
+    #[cfg(feature = "a11y")]
+    name: Option<Cow<'a, str>>,
+    #[cfg(feature = "a11y")]
+    description: Option<iced_accessibility::Description<'a>>,
+    #[cfg(feature = "a11y")]
+    label: Option<Vec<iced_accessibility::accesskit::NodeId>>,
     range: RangeInclusive<f32>,
     value: f32,
     width: Length,
@@ -78,6 +88,13 @@ where
     ///   * the current value of the [`ProgressBar`]
     pub fn new(range: RangeInclusive<f32>, value: f32) -> Self {
         ProgressBar {
+            id: Id::unique(),
+            #[cfg(feature = "a11y")]
+            name: None,
+            #[cfg(feature = "a11y")]
+            description: None,
+            #[cfg(feature = "a11y")]
+            label: None,
             value: value.clamp(*range.start(), *range.end()),
             range,
             width: Length::Fill,
@@ -115,6 +132,41 @@ where
         self.class = class.into();
         self
     }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the name of the [`ProgressBar`].
+    pub fn name(mut self, name: impl Into<Cow<'a, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`ProgressBar`].
+    pub fn description_widget(
+        mut self,
+        description: &impl iced_accessibility::Describes,
+    ) -> Self {
+        self.description = Some(iced_accessibility::Description::Id(
+            description.description(),
+        ));
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`ProgressBar`].
+    pub fn description(mut self, description: impl Into<Cow<'a, str>>) -> Self {
+        self.description =
+            Some(iced_accessibility::Description::Text(description.into()));
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the label of the [`ProgressBar`].
+    pub fn label(mut self, label: &dyn iced_accessibility::Labels) -> Self {
+        self.label =
+            Some(label.label().into_iter().map(|l| l.into()).collect());
+        self
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -191,6 +243,67 @@ where
             );
         }
     }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        _state: &Tree,
+        _cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::{
+            accesskit::{NodeBuilder, NodeId, Rect, Role},
+            A11yTree,
+        };
+
+        let Rectangle {
+            x,
+            y,
+            width,
+            height,
+        } = layout.bounds();
+
+        let mut node = NodeBuilder::new(Role::ProgressIndicator);
+        node.set_bounds(Rect::new(
+            x as f64,
+            y as f64,
+            (x + width) as f64,
+            (y + height) as f64,
+        ));
+        node.set_min_numeric_value(*self.range.start() as f64);
+        node.set_max_numeric_value(*self.range.end() as f64);
+        node.set_numeric_value(self.value as f64);
+
+        if let Some(name) = self.name.as_ref() {
+            node.set_name(name.clone());
+        }
+
+        match self.description.as_ref() {
+            Some(iced_accessibility::Description::Id(id)) => {
+                node.set_described_by(
+                    id.iter().cloned().map(NodeId::from).collect::<Vec<_>>(),
+                );
+            }
+            Some(iced_accessibility::Description::Text(text)) => {
+                node.set_description(text.clone());
+            }
+            None => {}
+        }
+
+        if let Some(label) = self.label.as_ref() {
+            node.set_labelled_by(label.clone());
+        }
+
+        A11yTree::leaf(node, self.id.clone())
+    }
+
+    fn id(&self) -> Option<Id> {
+        Some(self.id.clone())
+    }
+
+    fn set_id(&mut self, id: Id) {
+        self.id = id;
+    }
 }
 
 impl<'a, Message, Theme, Renderer> From<ProgressBar<'a, Theme>>
@@ -66,11 +66,17 @@ use crate::core::text;
 use crate::core::touch;
 use crate::core::widget;
 use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Id;
 use crate::core::{
     Background, Clipboard, Color, Element, Layout, Length, Pixels, Rectangle,
     Shell, Size, Theme, Widget,
 };
 
+#[cfg(feature = "a11y")]
+use crate::core::id::IdEq;
+#[cfg(feature = "a11y")]
+use std::borrow::Cow;
+
 /// A circular button representing a choice.
 ///
 /// # Example
@@ -135,6 +141,9 @@ where
     Theme: Catalog,
     Renderer: text::Renderer,
 {
+    id: Id,
+    #[cfg(feature = "a11y")]
+    description: Option<iced_accessibility::Description<'a>>,
     is_selected: bool,
     on_click: Message,
     label: String,
@@ -180,6 +189,9 @@ where
         F: FnOnce(V) -> Message,
     {
         Radio {
+            id: Id::unique(),
+            #[cfg(feature = "a11y")]
+            description: None,
             is_selected: Some(value) == selected,
             on_click: f(value),
             label: label.into(),
@@ -263,6 +275,26 @@ where
         self.class = class.into();
         self
     }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`Radio`] button.
+    pub fn description_widget(
+        mut self,
+        description: &impl iced_accessibility::Describes,
+    ) -> Self {
+        self.description = Some(iced_accessibility::Description::Id(
+            description.description(),
+        ));
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`Radio`] button.
+    pub fn description(mut self, description: impl Into<Cow<'a, str>>) -> Self {
+        self.description =
+            Some(iced_accessibility::Description::Text(description.into()));
+        self
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -316,6 +348,7 @@ where
                     alignment::Vertical::Top,
                     self.text_shaping,
                     self.text_wrapping,
+                    text::Ellipsize::None,
                 )
             },
         )
@@ -341,6 +374,16 @@ where
                     return event::Status::Captured;
                 }
             }
+            #[cfg(feature = "a11y")]
+            Event::A11y(target, request)
+                if IdEq::eq(&target, &self.id)
+                    && request.action
+                        == iced_accessibility::accesskit::Action::Default =>
+            {
+                shell.publish(self.on_click.clone());
+
+                return event::Status::Captured;
+            }
             _ => {}
         }
 
@@ -439,6 +482,65 @@ where
             );
         }
     }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        _state: &Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::{
+            accesskit::{Action, NodeBuilder, NodeId, Rect, Role},
+            A11yTree,
+        };
+
+        let bounds = layout.bounds();
+        let Rectangle {
+            x,
+            y,
+            width,
+            height,
+        } = bounds;
+
+        let mut node = NodeBuilder::new(Role::RadioButton);
+        node.set_bounds(Rect::new(
+            x as f64,
+            y as f64,
+            (x + width) as f64,
+            (y + height) as f64,
+        ));
+        node.add_action(Action::Focus);
+        node.add_action(Action::Default);
+        node.set_name(self.label.clone());
+        node.set_selected(self.is_selected);
+
+        match self.description.as_ref() {
+            Some(iced_accessibility::Description::Id(id)) => {
+                node.set_described_by(
+                    id.iter().cloned().map(NodeId::from).collect::<Vec<_>>(),
+                );
+            }
+            Some(iced_accessibility::Description::Text(text)) => {
+                node.set_description(text.clone());
+            }
+            None => {}
+        }
+
+        if cursor.is_over(bounds) {
+            node.set_hovered();
+        }
+
+        A11yTree::leaf(node, self.id.clone())
+    }
+
+    fn id(&self) -> Option<Id> {
+        Some(self.id.clone())
+    }
+
+    fn set_id(&mut self, id: Id) {
+        self.id = id;
+    }
 }
 
 impl<'a, Message, Theme, Renderer> From<Radio<'a, Message, Theme, Renderer>>
@@ -0,0 +1,538 @@
+//! Reorder the children of a flex container by dragging them.
+use crate::core::event::{self, Event};
+use crate::core::layout::flex::Axis;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::time::Instant;
+use crate::core::touch;
+use crate::core::widget::Tree;
+use crate::core::window;
+use crate::core::{Element, Layout, Point, Rectangle, Shell, Vector};
+use crate::scrollable;
+
+/// The distance the cursor has to travel before a child is picked up.
+const DRAG_THRESHOLD: f32 = 8.0;
+
+/// How fast the placeholder gap moves towards its destination.
+const GAP_SPEED: f32 = 15.0;
+
+/// The state of a reorderable container.
+#[derive(Debug, Default)]
+pub(crate) struct State {
+    drag: Option<Drag>,
+}
+
+#[derive(Debug)]
+struct Drag {
+    index: usize,
+    origin: Point,
+    position: Point,
+    is_picked: bool,
+    target: usize,
+    offsets: Vec<f32>,
+    last_frame: Option<Instant>,
+}
+
+impl State {
+    /// Returns whether a child is currently being dragged.
+    pub(crate) fn is_picked(&self) -> bool {
+        self.drag.as_ref().is_some_and(|drag| drag.is_picked)
+    }
+
+    fn picked(&self) -> Option<&Drag> {
+        self.drag.as_ref().filter(|drag| drag.is_picked)
+    }
+}
+
+impl Drag {
+    fn update_target(&mut self, axis: &Axis, layout: Layout<'_>) {
+        let Some(picked) = layout.children().nth(self.index) else {
+            return;
+        };
+
+        let center = main(axis, picked.bounds().center() - Point::ORIGIN)
+            + main(axis, self.position - self.origin);
+
+        self.target = layout
+            .children()
+            .enumerate()
+            .filter(|(index, child)| {
+                *index != self.index
+                    && main(axis, child.bounds().center() - Point::ORIGIN)
+                        < center
+            })
+            .count();
+    }
+
+    fn animate(
+        &mut self,
+        now: Instant,
+        axis: &Axis,
+        spacing: f32,
+        layout: Layout<'_>,
+    ) -> bool {
+        let Some(picked) = layout.children().nth(self.index) else {
+            return false;
+        };
+
+        let elapsed = self
+            .last_frame
+            .map(|last_frame| now.duration_since(last_frame).as_secs_f32())
+            .unwrap_or_default();
+
+        self.last_frame = Some(now);
+
+        let size = picked.bounds().size();
+        let gap = main(axis, Vector::new(size.width, size.height)) + spacing;
+        let step = (elapsed * GAP_SPEED).min(1.0);
+
+        let mut is_animating = false;
+
+        for (index, offset) in self.offsets.iter_mut().enumerate() {
+            let goal = if self.index < index && index <= self.target {
+                -gap
+            } else if self.target <= index && index < self.index {
+                gap
+            } else {
+                0.0
+            };
+
+            *offset += (goal - *offset) * step;
+
+            if (goal - *offset).abs() < 0.5 {
+                *offset = goal;
+            } else {
+                is_animating = true;
+            }
+        }
+
+        is_animating
+    }
+}
+
+/// Arms a drag of the child under the cursor when the given [`Event`] is a
+/// press.
+///
+/// It should only be called when the children of the container did not
+/// capture the [`Event`]; so interactive children keep their own drags.
+pub(crate) fn press(
+    state: &mut State,
+    event: &Event,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+) {
+    if !matches!(
+        event,
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+    ) {
+        return;
+    }
+
+    let Some(position) = cursor.position() else {
+        return;
+    };
+
+    state.drag = layout
+        .children()
+        .position(|child| child.bounds().contains(position))
+        .map(|index| Drag {
+            index,
+            origin: position,
+            position,
+            is_picked: false,
+            target: index,
+            offsets: vec![0.0; layout.children().count()],
+            last_frame: None,
+        });
+}
+
+/// Processes an [`Event`] for a reorderable container, producing the
+/// message of `on_reorder` when a dragged child is dropped.
+///
+/// Children should receive an unavailable cursor while a child is picked.
+pub(crate) fn update<Message>(
+    state: &mut State,
+    axis: &Axis,
+    spacing: f32,
+    event: &Event,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    viewport: &Rectangle,
+    shell: &mut Shell<'_, Message>,
+    on_reorder: &dyn Fn(usize, usize) -> Message,
+) -> event::Status {
+    match event {
+        Event::Mouse(mouse::Event::CursorMoved { .. })
+        | Event::Touch(touch::Event::FingerMoved { .. }) => {
+            let Some(drag) = &mut state.drag else {
+                return event::Status::Ignored;
+            };
+
+            let Some(position) = cursor.position() else {
+                return event::Status::Ignored;
+            };
+
+            if !drag.is_picked {
+                if position.distance(drag.origin) < DRAG_THRESHOLD {
+                    return event::Status::Ignored;
+                }
+
+                drag.is_picked = true;
+            }
+
+            drag.position = position;
+            drag.update_target(axis, layout);
+
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+
+            event::Status::Captured
+        }
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+        | Event::Touch(
+            touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. },
+        ) => match state.drag.take() {
+            Some(drag) if drag.is_picked => {
+                if drag.index != drag.target {
+                    shell.publish(on_reorder(drag.index, drag.target));
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        },
+        Event::Window(window::Event::RedrawRequested(now)) => {
+            let Some(drag) = state.drag.as_mut().filter(|drag| drag.is_picked)
+            else {
+                return event::Status::Ignored;
+            };
+
+            // A parent scrollable may have scrolled without the cursor moving
+            if let Some(position) = cursor.position() {
+                drag.position = position;
+                drag.update_target(axis, layout);
+            }
+
+            let is_animating = drag.animate(*now, axis, spacing, layout);
+
+            shell.request_auto_scroll();
+
+            let position = main(axis, drag.position - Point::ORIGIN);
+            let start = main(axis, viewport.position() - Point::ORIGIN);
+            let end = start
+                + main(axis, Vector::new(viewport.width, viewport.height));
+
+            let is_near_edge = position < start + scrollable::AUTO_SCROLL_EDGE
+                || position > end - scrollable::AUTO_SCROLL_EDGE;
+
+            if is_animating || is_near_edge {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+
+            event::Status::Ignored
+        }
+        _ => event::Status::Ignored,
+    }
+}
+
+/// Draws the children of a reorderable container while one of them is
+/// being dragged, opening a gap where it would be dropped.
+pub(crate) fn draw<Message, Theme, Renderer>(
+    state: &State,
+    axis: &Axis,
+    children: &[Element<'_, Message, Theme, Renderer>],
+    tree: &Tree,
+    renderer: &mut Renderer,
+    theme: &Theme,
+    style: &renderer::Style,
+    layout: Layout<'_>,
+    viewport: &Rectangle,
+) where
+    Renderer: crate::core::Renderer,
+{
+    let Some(drag) = state.picked() else {
+        return;
+    };
+
+    let mut picked = None;
+
+    for (index, ((child, state), c_layout)) in children
+        .iter()
+        .zip(&tree.children)
+        .zip(layout.children())
+        .enumerate()
+    {
+        let c_layout = c_layout.with_virtual_offset(layout.virtual_offset());
+
+        if index == drag.index {
+            picked = Some((child, state, c_layout));
+            continue;
+        }
+
+        let translation =
+            vector(axis, drag.offsets.get(index).copied().unwrap_or_default());
+
+        if !(c_layout.bounds() + translation).intersects(viewport) {
+            continue;
+        }
+
+        renderer.with_translation(translation, |renderer| {
+            child.as_widget().draw(
+                state,
+                renderer,
+                theme,
+                style,
+                c_layout,
+                mouse::Cursor::Unavailable,
+                viewport,
+            );
+        });
+    }
+
+    if let Some((child, state, c_layout)) = picked {
+        let translation = vector(axis, main(axis, drag.position - drag.origin));
+
+        renderer.with_translation(translation, |renderer| {
+            renderer.with_layer(c_layout.bounds(), |renderer| {
+                child.as_widget().draw(
+                    state,
+                    renderer,
+                    theme,
+                    style,
+                    c_layout,
+                    mouse::Cursor::Unavailable,
+                    viewport,
+                );
+            });
+        });
+    }
+}
+
+fn main(axis: &Axis, vector: Vector) -> f32 {
+    match axis {
+        Axis::Horizontal => vector.x,
+        Axis::Vertical => vector.y,
+    }
+}
+
+fn vector(axis: &Axis, amount: f32) -> Vector {
+    match axis {
+        Axis::Horizontal => Vector::new(amount, 0.0),
+        Axis::Vertical => Vector::new(0.0, amount),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::layout;
+    use crate::core::time::Duration;
+    use crate::core::Size;
+
+    /// A vertical layout of three children of 20 pixels, without spacing.
+    fn column() -> layout::Node {
+        layout::Node::with_children(
+            Size::new(100.0, 60.0),
+            (0..3)
+                .map(|index| {
+                    layout::Node::new(Size::new(100.0, 20.0))
+                        .move_to(Point::new(0.0, index as f32 * 20.0))
+                })
+                .collect(),
+        )
+    }
+
+    fn drag(index: usize) -> Drag {
+        Drag {
+            index,
+            origin: Point::new(50.0, 10.0 + index as f32 * 20.0),
+            position: Point::new(50.0, 10.0 + index as f32 * 20.0),
+            is_picked: true,
+            target: index,
+            offsets: vec![0.0; 3],
+            last_frame: None,
+        }
+    }
+
+    fn moved(position: Point) -> Event {
+        Event::Mouse(mouse::Event::CursorMoved { position })
+    }
+
+    #[test]
+    fn target_follows_the_center_of_the_dragged_child() {
+        let node = column();
+        let layout = Layout::new(&node);
+        let mut drag = drag(0);
+
+        drag.position = Point::new(50.0, 25.0);
+        drag.update_target(&Axis::Vertical, layout);
+        assert_eq!(drag.target, 0);
+
+        drag.position = Point::new(50.0, 45.0);
+        drag.update_target(&Axis::Vertical, layout);
+        assert_eq!(drag.target, 1);
+
+        drag.position = Point::new(50.0, 100.0);
+        drag.update_target(&Axis::Vertical, layout);
+        assert_eq!(drag.target, 2);
+
+        let mut drag = self::drag(2);
+
+        drag.position = Point::new(50.0, 5.0);
+        drag.update_target(&Axis::Vertical, layout);
+        assert_eq!(drag.target, 0);
+    }
+
+    #[test]
+    fn gap_moves_towards_the_target() {
+        let node = column();
+        let layout = Layout::new(&node);
+        let now = Instant::now();
+
+        let mut drag = drag(0);
+        drag.target = 2;
+
+        // The first frame only records its time
+        assert!(drag.animate(now, &Axis::Vertical, 5.0, layout));
+        assert_eq!(drag.offsets, [0.0, 0.0, 0.0]);
+
+        assert!(drag.animate(
+            now + Duration::from_millis(20),
+            &Axis::Vertical,
+            5.0,
+            layout
+        ));
+        assert!(drag.offsets[1] < 0.0 && drag.offsets[1] > -25.0);
+        assert_eq!(drag.offsets[1], drag.offsets[2]);
+        assert_eq!(drag.offsets[0], 0.0);
+
+        assert!(!drag.animate(
+            now + Duration::from_secs(1),
+            &Axis::Vertical,
+            5.0,
+            layout
+        ));
+        assert_eq!(drag.offsets, [0.0, -25.0, -25.0]);
+
+        // Dragging back closes the gap
+        drag.target = 0;
+
+        assert!(!drag.animate(
+            now + Duration::from_secs(2),
+            &Axis::Vertical,
+            5.0,
+            layout
+        ));
+        assert_eq!(drag.offsets, [0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn children_are_only_picked_past_the_threshold() {
+        let node = column();
+        let layout = Layout::new(&node);
+        let viewport = Rectangle::with_size(Size::new(100.0, 60.0));
+        let on_reorder = |from: usize, to: usize| (from, to);
+
+        let mut state = State::default();
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        let origin = Point::new(50.0, 10.0);
+
+        press(
+            &mut state,
+            &Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            layout,
+            mouse::Cursor::Available(origin),
+        );
+
+        assert!(state.drag.is_some());
+        assert!(!state.is_picked());
+
+        let position = origin + Vector::new(0.0, DRAG_THRESHOLD / 2.0);
+
+        let status = update(
+            &mut state,
+            &Axis::Vertical,
+            0.0,
+            &moved(position),
+            layout,
+            mouse::Cursor::Available(position),
+            &viewport,
+            &mut shell,
+            &on_reorder,
+        );
+
+        assert_eq!(status, event::Status::Ignored);
+        assert!(!state.is_picked());
+
+        let position = Point::new(50.0, 45.0);
+
+        let status = update(
+            &mut state,
+            &Axis::Vertical,
+            0.0,
+            &moved(position),
+            layout,
+            mouse::Cursor::Available(position),
+            &viewport,
+            &mut shell,
+            &on_reorder,
+        );
+
+        assert_eq!(status, event::Status::Captured);
+        assert!(state.is_picked());
+
+        let status = update(
+            &mut state,
+            &Axis::Vertical,
+            0.0,
+            &Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+            layout,
+            mouse::Cursor::Available(position),
+            &viewport,
+            &mut shell,
+            &on_reorder,
+        );
+
+        assert_eq!(status, event::Status::Captured);
+        assert!(state.drag.is_none());
+        assert_eq!(messages, [(0, 1)]);
+    }
+
+    #[test]
+    fn releasing_below_the_threshold_is_ignored() {
+        let node = column();
+        let layout = Layout::new(&node);
+        let viewport = Rectangle::with_size(Size::new(100.0, 60.0));
+        let position = Point::new(50.0, 30.0);
+
+        let mut state = State::default();
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        press(
+            &mut state,
+            &Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            layout,
+            mouse::Cursor::Available(position),
+        );
+
+        let status = update(
+            &mut state,
+            &Axis::Vertical,
+            0.0,
+            &Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+            layout,
+            mouse::Cursor::Available(position),
+            &viewport,
+            &mut shell,
+            &|from: usize, to: usize| (from, to),
+        );
+
+        assert_eq!(status, event::Status::Ignored);
+        assert!(state.drag.is_none());
+        assert!(messages.is_empty());
+    }
+}
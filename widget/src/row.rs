@@ -1,15 +1,16 @@
 //! Distribute content horizontally.
 use crate::core::alignment::{self, Alignment};
 use crate::core::event::{self, Event};
-use crate::core::layout::{self, Layout};
+use crate::core::layout::{self, flex::Axis, Layout};
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
-use crate::core::widget::{Operation, Tree};
+use crate::core::widget::{inspect, tree, Operation, Tree};
 use crate::core::{
     Clipboard, Element, Length, Padding, Pixels, Rectangle, Shell, Size,
     Vector, Widget,
 };
+use crate::reorder;
 
 /// A container that distributes its contents horizontally.
 ///
@@ -42,6 +43,7 @@ pub struct Row<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
     align: Alignment,
     clip: bool,
     children: Vec<Element<'a, Message, Theme, Renderer>>,
+    on_reorder: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
 }
 
 impl<'a, Message, Theme, Renderer> Row<'a, Message, Theme, Renderer>
@@ -85,6 +87,7 @@ where
             align: Alignment::Start,
             clip: false,
             children,
+            on_reorder: None,
         }
     }
 
@@ -129,6 +132,23 @@ where
         self
     }
 
+    /// Sets the message that should be produced when a child of the
+    /// [`Row`] is dragged to a new position.
+    ///
+    /// The closure receives the index of the child and the index it should
+    /// be moved to, once it is removed from its original position.
+    ///
+    /// A parent [`Scrollable`] scrolls while dragging near its edges.
+    ///
+    /// [`Scrollable`]: crate::Scrollable
+    pub fn on_reorder(
+        mut self,
+        on_reorder: impl Fn(usize, usize) -> Message + 'a,
+    ) -> Self {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
     /// Adds an [`Element`] to the [`Row`].
     pub fn push(
         mut self,
@@ -167,8 +187,16 @@ where
     /// Turns the [`Row`] into a [`Wrapping`] row.
     ///
     /// The original alignment of the [`Row`] is preserved per row wrapped.
+    ///
+    /// A [`Wrapping`] row cannot be reordered; any [`Row::on_reorder`] is
+    /// discarded.
     pub fn wrap(self) -> Wrapping<'a, Message, Theme, Renderer> {
-        Wrapping { row: self }
+        Wrapping {
+            row: Row {
+                on_reorder: None,
+                ..self
+            },
+        }
     }
 }
 
@@ -199,6 +227,23 @@ impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
 where
     Renderer: crate::core::Renderer,
 {
+    fn tag(&self) -> tree::Tag {
+        // Only reorderable containers are stateful
+        if self.on_reorder.is_some() {
+            tree::Tag::of::<reorder::State>()
+        } else {
+            tree::Tag::stateless()
+        }
+    }
+
+    fn state(&self) -> tree::State {
+        if self.on_reorder.is_some() {
+            tree::State::new(reorder::State::default())
+        } else {
+            tree::State::None
+        }
+    }
+
     fn children(&self) -> Vec<Tree> {
         self.children.iter().map(Tree::new).collect()
     }
@@ -268,7 +313,35 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
-        self.children
+        let mut status = event::Status::Ignored;
+
+        let cursor = if let Some(on_reorder) = &self.on_reorder {
+            let state = tree.state.downcast_mut::<reorder::State>();
+            let was_picked = state.is_picked();
+
+            status = reorder::update(
+                state,
+                &Axis::Horizontal,
+                self.spacing,
+                &event,
+                layout,
+                cursor,
+                viewport,
+                shell,
+                on_reorder.as_ref(),
+            );
+
+            if was_picked || state.is_picked() {
+                mouse::Cursor::Unavailable
+            } else {
+                cursor
+            }
+        } else {
+            cursor
+        };
+
+        let status = self
+            .children
             .iter_mut()
             .zip(&mut tree.children)
             .zip(layout.children())
@@ -284,7 +357,18 @@ where
                     viewport,
                 )
             })
-            .fold(event::Status::Ignored, event::Status::merge)
+            .fold(status, event::Status::merge);
+
+        if self.on_reorder.is_some() && status == event::Status::Ignored {
+            reorder::press(
+                tree.state.downcast_mut::<reorder::State>(),
+                &event,
+                layout,
+                cursor,
+            );
+        }
+
+        status
     }
 
     fn mouse_interaction(
@@ -295,6 +379,12 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
+        if self.on_reorder.is_some()
+            && tree.state.downcast_ref::<reorder::State>().is_picked()
+        {
+            return mouse::Interaction::Grabbing;
+        }
+
         self.children
             .iter()
             .zip(&tree.children)
@@ -329,6 +419,26 @@ where
                 viewport
             };
 
+            if self.on_reorder.is_some() {
+                let state = tree.state.downcast_ref::<reorder::State>();
+
+                if state.is_picked() {
+                    reorder::draw(
+                        state,
+                        &Axis::Horizontal,
+                        &self.children,
+                        tree,
+                        renderer,
+                        theme,
+                        style,
+                        layout,
+                        viewport,
+                    );
+
+                    return;
+                }
+            }
+
             for ((child, state), c_layout) in self
                 .children
                 .iter()
@@ -410,6 +520,24 @@ where
             );
         }
     }
+
+    fn inspect(&self, tree: &Tree, layout: Layout<'_>) -> inspect::Node {
+        inspect::Node::new::<Self>(self.id(), self.size(), layout)
+            .with_padding(self.padding)
+            .with_children(
+                self.children
+                    .iter()
+                    .zip(&tree.children)
+                    .zip(layout.children())
+                    .map(|((child, state), c_layout)| {
+                        child.as_widget().inspect(
+                            state,
+                            c_layout
+                                .with_virtual_offset(layout.virtual_offset()),
+                        )
+                    }),
+            )
+    }
 }
 
 impl<'a, Message, Theme, Renderer> From<Row<'a, Message, Theme, Renderer>>
@@ -445,6 +573,14 @@ impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
 where
     Renderer: crate::core::Renderer,
 {
+    fn tag(&self) -> tree::Tag {
+        self.row.tag()
+    }
+
+    fn state(&self) -> tree::State {
+        self.row.state()
+    }
+
     fn children(&self) -> Vec<Tree> {
         self.row.children()
     }
@@ -603,6 +739,10 @@ where
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
         self.row.overlay(tree, layout, renderer, translation)
     }
+
+    fn inspect(&self, tree: &Tree, layout: Layout<'_>) -> inspect::Node {
+        self.row.inspect(tree, layout)
+    }
 }
 
 impl<'a, Message, Theme, Renderer> From<Wrapping<'a, Message, Theme, Renderer>>
@@ -616,3 +756,20 @@ where
         Self::new(row)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_reorderable_rows_are_stateful() {
+        let row = Row::<'_, (), crate::Theme, ()>::new();
+
+        assert_eq!(Widget::tag(&row), tree::Tag::stateless());
+        assert!(matches!(Widget::state(&row), tree::State::None));
+
+        let row = row.on_reorder(|_, _| ());
+
+        assert_eq!(Widget::tag(&row), tree::Tag::of::<reorder::State>());
+    }
+}
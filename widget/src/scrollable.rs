@@ -34,6 +34,7 @@ use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::time::{Duration, Instant};
 use crate::core::touch;
+use crate::core::widget::inspect;
 use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
@@ -815,6 +816,9 @@ where
             }
         }
 
+        // Only the auto scroll requested by our content is meant for us
+        let is_auto_scroll_requested_outside = shell.take_auto_scroll_request();
+
         let content_status = if state.last_scrolled.is_some()
             && matches!(event, Event::Mouse(mouse::Event::WheelScrolled { .. }))
         {
@@ -886,6 +890,12 @@ where
             )
         };
 
+        let is_auto_scroll_requested = shell.take_auto_scroll_request();
+
+        if is_auto_scroll_requested_outside {
+            shell.request_auto_scroll();
+        }
+
         if matches!(
             event,
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
@@ -1030,6 +1040,28 @@ where
                     shell,
                 );
 
+                // Scroll while the content is dragging something near the edges
+                if let Some(cursor_position) =
+                    cursor.position().filter(|_| is_auto_scroll_requested)
+                {
+                    state.scroll(
+                        self.direction
+                            .align(auto_scroll(bounds, cursor_position)),
+                        bounds,
+                        content_bounds,
+                    );
+
+                    if notify_viewport(
+                        state,
+                        &self.on_scroll,
+                        bounds,
+                        content_bounds,
+                        shell,
+                    ) {
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    }
+                }
+
                 event::Status::Ignored
             }
             _ => event::Status::Ignored,
@@ -1495,6 +1527,31 @@ where
             dnd_rectangles.append(&mut my_dnd_rectangles);
         }
     }
+
+    fn inspect(&self, tree: &Tree, layout: Layout<'_>) -> inspect::Node {
+        let node = inspect::Node::new::<Self>(self.id(), self.size(), layout);
+
+        let Some(c_layout) = layout.children().next() else {
+            return node;
+        };
+
+        let state = tree.state.downcast_ref::<State>();
+        let translation = state.translation(
+            self.direction,
+            layout.bounds(),
+            c_layout.bounds(),
+        );
+
+        node.with_children([self
+            .content
+            .as_widget()
+            .inspect(
+                &tree.children[0],
+                c_layout
+                    .with_virtual_offset(translation + layout.virtual_offset()),
+            )
+            .translate(Vector::new(-translation.x, -translation.y))])
+    }
 }
 
 impl<'a, Message, Theme, Renderer>
@@ -1530,6 +1587,35 @@ pub fn scroll_by<T>(id: Id, offset: AbsoluteOffset) -> Task<T> {
     task::effect(Action::widget(operation::scrollable::scroll_by(id, offset)))
 }
 
+/// The distance to the edges of a [`Scrollable`] where dragging something
+/// in its content scrolls it.
+pub(crate) const AUTO_SCROLL_EDGE: f32 = 40.0;
+
+/// The maximum amount of pixels a [`Scrollable`] scrolls per frame while
+/// dragging something in its content.
+const AUTO_SCROLL_SPEED: f32 = 20.0;
+
+fn auto_scroll(bounds: Rectangle, cursor_position: Point) -> Vector {
+    let delta = |position: f32, start: f32, length: f32| {
+        let speed = |depth: f32| {
+            (depth / AUTO_SCROLL_EDGE).min(1.0) * AUTO_SCROLL_SPEED
+        };
+
+        if position < start + AUTO_SCROLL_EDGE {
+            -speed(start + AUTO_SCROLL_EDGE - position)
+        } else if position > start + length - AUTO_SCROLL_EDGE {
+            speed(position - (start + length - AUTO_SCROLL_EDGE))
+        } else {
+            0.0
+        }
+    };
+
+    Vector::new(
+        delta(cursor_position.x, bounds.x, bounds.width),
+        delta(cursor_position.y, bounds.y, bounds.height),
+    )
+}
+
 fn notify_scroll<Message>(
     state: &mut State,
     on_scroll: &Option<Box<dyn Fn(Viewport) -> Message + '_>>,
@@ -2318,3 +2404,29 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn auto_scroll_speeds_up_towards_the_edges() {
+        let bounds =
+            Rectangle::new(Point::new(10.0, 10.0), Size::new(200.0, 200.0));
+
+        assert_eq!(auto_scroll(bounds, bounds.center()), Vector::ZERO);
+
+        let near_top = auto_scroll(bounds, Point::new(110.0, 40.0));
+        let at_top = auto_scroll(bounds, Point::new(110.0, 10.0));
+        let past_top = auto_scroll(bounds, Point::new(110.0, -100.0));
+
+        assert_eq!(near_top.x, 0.0);
+        assert!(near_top.y < 0.0 && near_top.y > at_top.y);
+        assert_eq!(at_top.y, -AUTO_SCROLL_SPEED);
+        assert_eq!(past_top.y, -AUTO_SCROLL_SPEED);
+
+        let at_right = auto_scroll(bounds, Point::new(210.0, 110.0));
+
+        assert_eq!(at_right, Vector::new(AUTO_SCROLL_SPEED, 0.0));
+    }
+}
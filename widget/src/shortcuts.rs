@@ -0,0 +1,678 @@
+//! Bind keyboard shortcuts to messages.
+//!
+//! A [`Registry`] declares the shortcuts of an application, can report its
+//! [`Conflict`]s and lists its [`Binding`]s for display. Its bindings take
+//! effect in the [`Shortcuts`] widget produced by [`Registry::view`], which
+//! normally wraps the whole contents of a window.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::keyboard::{Modifiers, Shortcut};
+//! use iced::widget::shortcuts::{Binding, Registry};
+//! use iced::widget::text;
+//! use iced::window;
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Save,
+//!     SaveAll,
+//! }
+//!
+//! fn view(shortcuts: &Registry<Message>, window: window::Id) -> Element<'_, Message> {
+//!     shortcuts.view(window, text("Hello!")).into()
+//! }
+//!
+//! let shortcuts = Registry::new()
+//!     .bind(
+//!         Binding::new(Shortcut::new(Modifiers::COMMAND, "s"), Message::Save)
+//!             .description("Save"),
+//!     )
+//!     .bind(
+//!         Binding::new(
+//!             Shortcut::new(Modifiers::COMMAND, "k").then(Modifiers::empty(), "s"),
+//!             Message::SaveAll,
+//!         )
+//!         .description("Save all"),
+//!     );
+//! ```
+use crate::core::event::{self, Event};
+use crate::core::keyboard::shortcut::Stroke;
+use crate::core::keyboard::{self, key, Key, Shortcut};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
+use crate::core::touch;
+use crate::core::widget::{inspect, tree, Operation, Tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Rectangle, Shell, Size, Vector, Widget,
+};
+
+/// A set of keyboard shortcuts bound to messages.
+#[derive(Debug, Clone)]
+pub struct Registry<Message> {
+    bindings: Vec<Binding<Message>>,
+}
+
+impl<Message> Registry<Message> {
+    /// Creates an empty [`Registry`].
+    pub fn new() -> Self {
+        Self {
+            bindings: Vec::new(),
+        }
+    }
+
+    /// Adds a [`Binding`] to the [`Registry`].
+    pub fn bind(mut self, binding: Binding<Message>) -> Self {
+        self.push(binding);
+        self
+    }
+
+    /// Adds a [`Binding`] to the [`Registry`] in place.
+    pub fn push(&mut self, binding: Binding<Message>) {
+        self.bindings.push(binding);
+    }
+
+    /// Returns all the [`Binding`]s of the [`Registry`]; for instance, to
+    /// display them in a help screen.
+    pub fn bindings(&self) -> &[Binding<Message>] {
+        &self.bindings
+    }
+
+    /// Returns the [`Binding`]s of the [`Registry`] active in the window with
+    /// the given [`window::Id`].
+    pub fn bindings_for(
+        &self,
+        window: window::Id,
+    ) -> impl Iterator<Item = &Binding<Message>> {
+        self.bindings
+            .iter()
+            .filter(move |binding| binding.is_active_in(window))
+    }
+
+    /// Returns the [`Conflict`]s of the [`Registry`].
+    ///
+    /// Two bindings conflict when they can be active in the same window and
+    /// the [`Shortcut`] of one starts with the [`Shortcut`] of the other;
+    /// in which case, only one of them can ever be triggered.
+    pub fn conflicts(&self) -> Vec<Conflict> {
+        let mut conflicts = Vec::new();
+
+        for (first, a) in self.bindings.iter().enumerate() {
+            for (second, b) in self.bindings.iter().enumerate().skip(first + 1)
+            {
+                let overlaps = match (a.window, b.window) {
+                    (Some(a), Some(b)) => a == b,
+                    _ => true,
+                };
+
+                let shadows = a.shortcut.starts_with(b.shortcut.strokes())
+                    || b.shortcut.starts_with(a.shortcut.strokes());
+
+                if overlaps && shadows {
+                    conflicts.push(Conflict { first, second });
+                }
+            }
+        }
+
+        conflicts
+    }
+
+    /// Wraps the given content of the window with the given [`window::Id`]
+    /// in a [`Shortcuts`] widget handling the bindings of the [`Registry`].
+    pub fn view<'a, Theme, Renderer>(
+        &'a self,
+        window: window::Id,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Shortcuts<'a, Message, Theme, Renderer> {
+        Shortcuts {
+            registry: self,
+            window,
+            content: content.into(),
+        }
+    }
+
+    fn find(
+        &self,
+        window: window::Id,
+        strokes: &[Stroke],
+        priority: Option<bool>,
+    ) -> Match<'_, Message> {
+        let mut is_prefix = false;
+
+        for binding in self.bindings_for(window) {
+            if priority.is_some_and(|priority| priority != binding.priority) {
+                continue;
+            }
+
+            if binding.shortcut.strokes() == strokes {
+                return Match::Exact(&binding.message);
+            }
+
+            is_prefix |= binding.shortcut.starts_with(strokes);
+        }
+
+        if is_prefix {
+            Match::Prefix
+        } else {
+            Match::None
+        }
+    }
+}
+
+impl<Message> Default for Registry<Message> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A [`Shortcut`] bound to a message.
+#[derive(Debug, Clone)]
+pub struct Binding<Message> {
+    /// The [`Shortcut`] of the [`Binding`].
+    pub shortcut: Shortcut,
+    /// The message produced when the [`Shortcut`] is triggered.
+    pub message: Message,
+    /// A human-readable description of the [`Binding`].
+    pub description: String,
+    /// The window the [`Binding`] is limited to, if any.
+    pub window: Option<window::Id>,
+    /// Whether the [`Binding`] takes priority over focused widgets.
+    pub priority: bool,
+}
+
+impl<Message> Binding<Message> {
+    /// Creates a new [`Binding`] of the given [`Shortcut`] to the given
+    /// message.
+    pub fn new(shortcut: Shortcut, message: Message) -> Self {
+        Self {
+            shortcut,
+            message,
+            description: String::new(),
+            window: None,
+            priority: false,
+        }
+    }
+
+    /// Sets the description of the [`Binding`].
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// Limits the [`Binding`] to the window with the given [`window::Id`].
+    pub fn window(mut self, window: window::Id) -> Self {
+        self.window = Some(window);
+        self
+    }
+
+    /// Sets whether the [`Binding`] takes priority over focused widgets.
+    ///
+    /// By default, a [`Binding`] is only triggered by key presses that no
+    /// widget captured; for instance, typing in a text input.
+    pub fn priority(mut self, priority: bool) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    fn is_active_in(&self, window: window::Id) -> bool {
+        self.window.is_none_or(|scope| scope == window)
+    }
+}
+
+/// Two conflicting [`Binding`]s of a [`Registry`], by index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Conflict {
+    /// The index of the first [`Binding`].
+    pub first: usize,
+    /// The index of the second [`Binding`].
+    pub second: usize,
+}
+
+enum Match<'a, Message> {
+    Exact(&'a Message),
+    Prefix,
+    None,
+}
+
+/// A widget that triggers the [`Binding`]s of a [`Registry`].
+#[allow(missing_debug_implementations)]
+pub struct Shortcuts<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    registry: &'a Registry<Message>,
+    window: window::Id,
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+/// The time a chord waits for its next [`Stroke`] before being abandoned.
+const CHORD_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// The local state of [`Shortcuts`].
+#[derive(Debug, Default)]
+struct State {
+    pending: Option<Pending>,
+}
+
+/// The [`Stroke`]s of a chord waiting for its next [`Stroke`].
+#[derive(Debug)]
+struct Pending {
+    strokes: Vec<Stroke>,
+    last_stroke_at: Instant,
+}
+
+impl Pending {
+    fn new(strokes: Vec<Stroke>) -> Self {
+        Self {
+            strokes,
+            last_stroke_at: Instant::now(),
+        }
+    }
+
+    fn is_expired(&self) -> bool {
+        self.last_stroke_at.elapsed() >= CHORD_TIMEOUT
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Shortcuts<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+{
+    /// Handles the given [`Stroke`] with the bindings of the given priority,
+    /// returning whether it was consumed.
+    fn trigger(
+        &self,
+        state: &mut State,
+        stroke: Stroke,
+        priority: bool,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool {
+        match self.registry.find(
+            self.window,
+            std::slice::from_ref(&stroke),
+            Some(priority),
+        ) {
+            Match::Exact(message) => {
+                shell.publish(message.clone());
+                true
+            }
+            Match::Prefix => {
+                state.pending = Some(Pending::new(vec![stroke]));
+                true
+            }
+            Match::None => false,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Shortcuts<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Message: Clone,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_mut(&mut self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        // A pending chord is abandoned as soon as the user moves on
+        if matches!(
+            event,
+            Event::Window(window::Event::Unfocused)
+                | Event::Mouse(mouse::Event::ButtonPressed(_))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        ) {
+            tree.state.downcast_mut::<State>().pending = None;
+        }
+
+        let stroke = match &event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modifiers,
+                ..
+            }) => Some(Stroke::new(*modifiers, key.clone()))
+                .filter(|stroke| !stroke.is_modifier()),
+            _ => None,
+        };
+
+        let Some(stroke) = stroke else {
+            return self.content.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event,
+                layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+
+        // A pending chord consumes the next stroke, whether it completes it
+        // or not; Escape cancels it
+        if let Some(Pending { mut strokes, .. }) =
+            state.pending.take().filter(|pending| !pending.is_expired())
+        {
+            if *stroke.key() == Key::Named(key::Named::Escape) {
+                return event::Status::Captured;
+            }
+
+            strokes.push(stroke);
+
+            match self.registry.find(self.window, &strokes, None) {
+                Match::Exact(message) => shell.publish(message.clone()),
+                Match::Prefix => state.pending = Some(Pending::new(strokes)),
+                Match::None => {}
+            }
+
+            return event::Status::Captured;
+        }
+
+        if self.trigger(state, stroke.clone(), true, shell) {
+            return event::Status::Captured;
+        }
+
+        if let event::Status::Captured = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        ) {
+            return event::Status::Captured;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if self.trigger(state, stroke, false, shell) {
+            event::Status::Captured
+        } else {
+            event::Status::Ignored
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            renderer_style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            translation,
+        )
+    }
+
+    fn drag_destinations(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        dnd_rectangles: &mut crate::core::clipboard::DndDestinationRectangles,
+    ) {
+        if let Some(state) = state.children.first() {
+            self.content.as_widget().drag_destinations(
+                state,
+                layout,
+                renderer,
+                dnd_rectangles,
+            );
+        }
+    }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        self.content.as_widget().a11y_nodes(
+            layout,
+            state.children.first().unwrap_or(&Tree::empty()),
+            cursor,
+        )
+    }
+
+    fn inspect(&self, tree: &Tree, layout: Layout<'_>) -> inspect::Node {
+        inspect::Node::new::<Self>(self.id(), self.size(), layout)
+            .with_children([self
+                .content
+                .as_widget()
+                .inspect(&tree.children[0], layout)])
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Shortcuts<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Theme: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(
+        shortcuts: Shortcuts<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(shortcuts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::keyboard::Modifiers;
+
+    fn stroke(key: &str) -> Stroke {
+        Stroke::new(Modifiers::CTRL, key)
+    }
+
+    #[test]
+    fn prefixes_conflict() {
+        let registry = Registry::new()
+            .bind(Binding::new(Shortcut::new(Modifiers::CTRL, "k"), "clear"))
+            .bind(Binding::new(
+                Shortcut::new(Modifiers::CTRL, "k").then(Modifiers::CTRL, "s"),
+                "save all",
+            ))
+            .bind(Binding::new(Shortcut::new(Modifiers::CTRL, "s"), "save"));
+
+        assert_eq!(
+            registry.conflicts(),
+            vec![Conflict {
+                first: 0,
+                second: 1
+            }]
+        );
+    }
+
+    #[test]
+    fn bindings_of_different_windows_do_not_conflict() {
+        let (a, b) = (window::Id::unique(), window::Id::unique());
+
+        let registry = Registry::new()
+            .bind(
+                Binding::new(Shortcut::new(Modifiers::CTRL, "w"), "close a")
+                    .window(a),
+            )
+            .bind(
+                Binding::new(Shortcut::new(Modifiers::CTRL, "w"), "close b")
+                    .window(b),
+            )
+            .bind(Binding::new(Shortcut::new(Modifiers::CTRL, "w"), "close"));
+
+        assert_eq!(
+            registry.conflicts(),
+            vec![
+                Conflict {
+                    first: 0,
+                    second: 2
+                },
+                Conflict {
+                    first: 1,
+                    second: 2
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn chords_are_found_stroke_by_stroke() {
+        let window = window::Id::unique();
+
+        let registry = Registry::new().bind(Binding::new(
+            Shortcut::new(Modifiers::CTRL, "k").then(Modifiers::CTRL, "s"),
+            "save all",
+        ));
+
+        assert!(matches!(
+            registry.find(window, &[stroke("k")], None),
+            Match::Prefix
+        ));
+        assert!(matches!(
+            registry.find(window, &[stroke("K"), stroke("s")], None),
+            Match::Exact(&"save all")
+        ));
+        assert!(matches!(
+            registry.find(window, &[stroke("k"), stroke("x")], None),
+            Match::None
+        ));
+    }
+
+    #[test]
+    fn bindings_are_found_by_window_and_priority() {
+        let (a, b) = (window::Id::unique(), window::Id::unique());
+
+        let registry = Registry::new()
+            .bind(
+                Binding::new(Shortcut::new(Modifiers::CTRL, "q"), "quit")
+                    .priority(true),
+            )
+            .bind(
+                Binding::new(Shortcut::new(Modifiers::CTRL, "w"), "close")
+                    .window(a),
+            );
+
+        assert!(matches!(
+            registry.find(b, &[stroke("q")], Some(true)),
+            Match::Exact(&"quit")
+        ));
+        assert!(matches!(
+            registry.find(b, &[stroke("q")], Some(false)),
+            Match::None
+        ));
+        assert!(matches!(
+            registry.find(a, &[stroke("w")], None),
+            Match::Exact(&"close")
+        ));
+        assert!(matches!(
+            registry.find(b, &[stroke("w")], None),
+            Match::None
+        ));
+    }
+}
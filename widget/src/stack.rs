@@ -5,7 +5,7 @@ use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
-use crate::core::widget::{Operation, Tree};
+use crate::core::widget::{inspect, Operation, Tree};
 use crate::core::{
     Clipboard, Element, Layout, Length, Rectangle, Shell, Size, Vector, Widget,
 };
@@ -368,6 +368,23 @@ where
             translation,
         )
     }
+
+    fn inspect(&self, tree: &Tree, layout: Layout<'_>) -> inspect::Node {
+        inspect::Node::new::<Self>(self.id(), self.size(), layout)
+            .with_children(
+                self.children
+                    .iter()
+                    .zip(&tree.children)
+                    .zip(layout.children())
+                    .map(|((child, state), c_layout)| {
+                        child.as_widget().inspect(
+                            state,
+                            c_layout
+                                .with_virtual_offset(layout.virtual_offset()),
+                        )
+                    }),
+            )
+    }
 }
 
 impl<'a, Message, Theme, Renderer> From<Stack<'a, Message, Theme, Renderer>>
@@ -1,7 +1,9 @@
 //! Draw and interact with text.
 mod rich;
 
-pub use crate::core::text::{Fragment, Highlighter, IntoFragment, Span};
+pub use crate::core::text::{
+    Decoration, Fragment, Highlighter, IntoFragment, Span,
+};
 pub use crate::core::widget::text::*;
 pub use rich::Rich;
 
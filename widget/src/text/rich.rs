@@ -3,16 +3,22 @@ use crate::core::event;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
-use crate::core::text::{Paragraph, Span};
+use crate::core::text::{Decoration, Paragraph, Span};
 use crate::core::widget::text::{
-    self, Catalog, LineHeight, Shaping, Style, StyleFn, Wrapping,
+    self, Catalog, Ellipsize, LineHeight, Shaping, Style, StyleFn, Wrapping,
 };
 use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Id;
 use crate::core::{
     self, Clipboard, Color, Element, Event, Layout, Length, Pixels, Point,
     Rectangle, Shell, Size, Vector, Widget,
 };
 
+use std::borrow::Cow;
+
+#[cfg(feature = "a11y")]
+use crate::core::id::IdEq;
+
 /// A bunch of [`Rich`] text.
 #[allow(missing_debug_implementations)]
 pub struct Rich<'a, Link, Theme = crate::Theme, Renderer = crate::Renderer>
@@ -21,6 +27,9 @@ where
     Theme: Catalog,
     Renderer: core::text::Renderer,
 {
+    id: Id,
+    #[cfg(feature = "a11y")]
+    heading: Option<usize>,
     spans: Box<dyn AsRef<[Span<'a, Link, Renderer::Font>]> + 'a>,
     size: Option<Pixels>,
     line_height: LineHeight,
@@ -30,6 +39,8 @@ where
     align_x: alignment::Horizontal,
     align_y: alignment::Vertical,
     wrapping: Wrapping,
+    ellipsize: Ellipsize,
+    selectable: bool,
     class: Theme::Class<'a>,
 }
 
@@ -43,6 +54,9 @@ where
     /// Creates a new empty [`Rich`] text.
     pub fn new() -> Self {
         Self {
+            id: Id::unique(),
+            #[cfg(feature = "a11y")]
+            heading: None,
             spans: Box::new([]),
             size: None,
             line_height: LineHeight::default(),
@@ -52,6 +66,8 @@ where
             align_x: alignment::Horizontal::Left,
             align_y: alignment::Vertical::Top,
             wrapping: Wrapping::default(),
+            ellipsize: Ellipsize::default(),
+            selectable: false,
             class: Theme::default(),
         }
     }
@@ -126,6 +142,23 @@ where
         self
     }
 
+    /// Sets the [`Ellipsize`] strategy of the [`Rich`] text.
+    pub fn ellipsize(mut self, ellipsize: Ellipsize) -> Self {
+        self.ellipsize = ellipsize;
+        self
+    }
+
+    /// Sets whether the contents of the [`Rich`] text can be selected and
+    /// copied by the user.
+    ///
+    /// A selectable [`Rich`] text supports dragging, double-clicking a word
+    /// and triple-clicking a line. The selection can be copied to the
+    /// clipboard with `Ctrl+C`.
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = selectable;
+        self
+    }
+
     /// Sets the default style of the [`Rich`] text.
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
@@ -161,6 +194,14 @@ where
         self.class = class.into();
         self
     }
+
+    #[cfg(feature = "a11y")]
+    /// Exposes the [`Rich`] text as a heading of the given level, starting
+    /// at 1.
+    pub fn heading(mut self, level: usize) -> Self {
+        self.heading = Some(level);
+        self
+    }
 }
 
 impl<'a, Link, Theme, Renderer> Default for Rich<'a, Link, Theme, Renderer>
@@ -178,7 +219,13 @@ where
 struct State<Link, P: Paragraph> {
     spans: Vec<Span<'static, Link, P::Font>>,
     span_pressed: Option<usize>,
+    hovered_link: Option<usize>,
+    content: String,
+    selection: text::Selection,
     paragraph: P,
+    /// The accessibility ids of the spans; only links use them.
+    #[cfg(feature = "a11y")]
+    span_ids: Vec<Id>,
 }
 
 impl<'a, Link, Theme, Renderer> Widget<Link, Theme, Renderer>
@@ -196,7 +243,12 @@ where
         tree::State::new(State::<Link, _> {
             spans: Vec::new(),
             span_pressed: None,
+            hovered_link: None,
+            content: String::new(),
+            selection: text::Selection::default(),
             paragraph: Renderer::Paragraph::default(),
+            #[cfg(feature = "a11y")]
+            span_ids: Vec::new(),
         })
     }
 
@@ -213,9 +265,17 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
+        let state = tree
+            .state
+            .downcast_mut::<State<Link, Renderer::Paragraph>>();
+
+        #[cfg(feature = "a11y")]
+        state
+            .span_ids
+            .resize_with(self.spans.as_ref().as_ref().len(), Id::unique);
+
         layout(
-            tree.state
-                .downcast_mut::<State<Link, Renderer::Paragraph>>(),
+            state,
             renderer,
             limits,
             self.width,
@@ -227,6 +287,7 @@ where
             self.align_x,
             self.align_y,
             self.wrapping,
+            self.ellipsize,
         )
     }
 
@@ -237,7 +298,7 @@ where
         theme: &Theme,
         defaults: &renderer::Style,
         layout: Layout<'_>,
-        cursor: mouse::Cursor,
+        _cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
         let state = tree
@@ -245,106 +306,46 @@ where
             .downcast_ref::<State<Link, Renderer::Paragraph>>();
 
         let style = theme.style(&self.class);
-
-        let hovered_span = cursor
-            .position_in(layout.bounds())
-            .and_then(|position| state.paragraph.hit_span(position));
-
-        for (index, span) in self.spans.as_ref().as_ref().iter().enumerate() {
-            let is_hovered_link =
-                span.link.is_some() && Some(index) == hovered_span;
-
-            if span.highlight.is_some()
-                || span.underline
-                || span.strikethrough
-                || is_hovered_link
-            {
-                let translation = layout.position() - Point::ORIGIN;
-                let regions = state.paragraph.span_bounds(index);
-
-                if let Some(highlight) = span.highlight {
-                    for bounds in &regions {
-                        let bounds = Rectangle::new(
-                            bounds.position()
-                                - Vector::new(
-                                    span.padding.left,
-                                    span.padding.top,
-                                ),
-                            bounds.size()
-                                + Size::new(
-                                    span.padding.horizontal(),
-                                    span.padding.vertical(),
-                                ),
-                        );
-
-                        renderer.fill_quad(
-                            renderer::Quad {
-                                bounds: bounds + translation,
-                                border: highlight.border,
-                                ..Default::default()
-                            },
-                            highlight.background,
-                        );
-                    }
-                }
-
-                if span.underline || span.strikethrough || is_hovered_link {
-                    let size = span
-                        .size
-                        .or(self.size)
-                        .unwrap_or(renderer.default_size());
-
-                    let line_height = span
-                        .line_height
-                        .unwrap_or(self.line_height)
-                        .to_absolute(size);
-
-                    let color = span
-                        .color
-                        .or(style.color)
-                        .unwrap_or(defaults.text_color);
-
-                    let baseline = translation
-                        + Vector::new(
-                            0.0,
-                            size.0 + (line_height.0 - size.0) / 2.0,
-                        );
-
-                    if span.underline || is_hovered_link {
-                        for bounds in &regions {
-                            renderer.fill_quad(
-                                renderer::Quad {
-                                    bounds: Rectangle::new(
-                                        bounds.position() + baseline
-                                            - Vector::new(0.0, size.0 * 0.08),
-                                        Size::new(bounds.width, 1.0),
-                                    ),
-                                    ..Default::default()
-                                },
-                                color,
-                            );
-                        }
-                    }
-
-                    if span.strikethrough {
-                        for bounds in &regions {
-                            renderer.fill_quad(
-                                renderer::Quad {
-                                    bounds: Rectangle::new(
-                                        bounds.position() + baseline
-                                            - Vector::new(0.0, size.0 / 2.0),
-                                        Size::new(bounds.width, 1.0),
-                                    ),
-                                    ..Default::default()
-                                },
-                                color,
-                            );
-                        }
-                    }
-                }
+        let spans = self.spans.as_ref().as_ref();
+
+        for (index, span) in spans.iter().enumerate() {
+            let Some(highlight) = span.highlight else {
+                continue;
+            };
+
+            let translation = layout.position() - Point::ORIGIN;
+
+            for bounds in state.paragraph.span_bounds(index) {
+                let bounds = Rectangle::new(
+                    bounds.position()
+                        - Vector::new(span.padding.left, span.padding.top),
+                    bounds.size()
+                        + Size::new(
+                            span.padding.horizontal(),
+                            span.padding.vertical(),
+                        ),
+                );
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: bounds + translation,
+                        border: highlight.border,
+                        ..Default::default()
+                    },
+                    highlight.background,
+                );
             }
         }
 
+        if self.selectable {
+            state.selection.draw(
+                renderer,
+                &state.paragraph,
+                layout.bounds(),
+                style.color.unwrap_or(defaults.text_color),
+            );
+        }
+
         text::draw(
             renderer,
             defaults,
@@ -362,11 +363,37 @@ where
         layout: Layout<'_>,
         cursor: mouse::Cursor,
         _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
+        clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Link>,
         _viewport: &Rectangle,
     ) -> event::Status {
         match event {
+            Event::Mouse(
+                mouse::Event::CursorMoved { .. } | mouse::Event::CursorLeft,
+            ) => {
+                let state = tree
+                    .state
+                    .downcast_mut::<State<Link, Renderer::Paragraph>>();
+
+                let hovered_link = cursor
+                    .position_in(layout.bounds())
+                    .and_then(|position| state.paragraph.hit_span(position))
+                    .filter(|&span| {
+                        self.spans
+                            .as_ref()
+                            .as_ref()
+                            .get(span)
+                            .map_or(false, |span| span.link.is_some())
+                    });
+
+                if state.hovered_link != hovered_link {
+                    state.hovered_link = hovered_link;
+
+                    // Hovered links are underlined by the paragraph, using
+                    // the metrics of their font
+                    shell.invalidate_layout();
+                }
+            }
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
                 if let Some(position) = cursor.position_in(layout.bounds()) {
                     let state = tree
@@ -415,9 +442,45 @@ where
                     }
                 }
             }
+            #[cfg(feature = "a11y")]
+            Event::A11y(ref target, ref request)
+                if request.action
+                    == iced_accessibility::accesskit::Action::Default =>
+            {
+                let state = tree
+                    .state
+                    .downcast_ref::<State<Link, Renderer::Paragraph>>();
+
+                if let Some(link) = state
+                    .span_ids
+                    .iter()
+                    .position(|id| IdEq::eq(target, id))
+                    .and_then(|span| self.spans.as_ref().as_ref().get(span))
+                    .and_then(|span| span.link.clone())
+                {
+                    shell.publish(link);
+
+                    return event::Status::Captured;
+                }
+            }
             _ => {}
         }
 
+        if self.selectable {
+            let state = tree
+                .state
+                .downcast_mut::<State<Link, Renderer::Paragraph>>();
+
+            return state.selection.update(
+                &event,
+                layout.bounds(),
+                cursor,
+                &state.paragraph,
+                &state.content,
+                clipboard,
+            );
+        }
+
         event::Status::Ignored
     }
 
@@ -443,10 +506,92 @@ where
                     return mouse::Interaction::Pointer;
                 }
             }
+
+            if self.selectable {
+                return mouse::Interaction::Text;
+            }
         }
 
         mouse::Interaction::None
     }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        _cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::{
+            accesskit::{Action, NodeBuilder, Rect, Role},
+            A11yNode, A11yTree,
+        };
+
+        fn rect(bounds: Rectangle) -> Rect {
+            Rect::new(
+                bounds.x as f64,
+                bounds.y as f64,
+                (bounds.x + bounds.width) as f64,
+                (bounds.y + bounds.height) as f64,
+            )
+        }
+
+        let state = state
+            .state
+            .downcast_ref::<State<Link, Renderer::Paragraph>>();
+
+        let mut node = NodeBuilder::new(if self.heading.is_some() {
+            Role::Heading
+        } else {
+            Role::Paragraph
+        });
+        node.set_bounds(rect(layout.bounds()));
+        node.set_name(state.content.clone());
+
+        if let Some(level) = self.heading {
+            node.set_level(level);
+        }
+
+        let translation = layout.position() - Point::ORIGIN;
+
+        let links = self
+            .spans
+            .as_ref()
+            .as_ref()
+            .iter()
+            .zip(&state.span_ids)
+            .enumerate()
+            .filter(|(_, (span, _))| span.link.is_some())
+            .map(|(index, (span, id))| {
+                let mut link = NodeBuilder::new(Role::Link);
+                link.set_name(span.text.to_string());
+                link.add_action(Action::Default);
+
+                if let Some(bounds) = state
+                    .paragraph
+                    .span_bounds(index)
+                    .into_iter()
+                    .reduce(|a, b| a.union(&b))
+                {
+                    link.set_bounds(rect(bounds + translation));
+                }
+
+                A11yTree::leaf(link, id.clone())
+            });
+
+        A11yTree::node_with_child_tree(
+            A11yNode::new(node, self.id.clone()),
+            A11yTree::join(links),
+        )
+    }
+
+    fn id(&self) -> Option<Id> {
+        Some(self.id.clone())
+    }
+
+    fn set_id(&mut self, id: Id) {
+        self.id = id;
+    }
 }
 
 fn layout<Link, Renderer>(
@@ -462,6 +607,7 @@ fn layout<Link, Renderer>(
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
     wrapping: Wrapping,
+    ellipsize: Ellipsize,
 ) -> layout::Node
 where
     Link: Clone,
@@ -473,6 +619,23 @@ where
         let size = size.unwrap_or_else(|| renderer.default_size());
         let font = font.unwrap_or_else(|| renderer.default_font());
 
+        // Underline the hovered link, unless it is already underlined
+        let spans = match state.hovered_link {
+            Some(index)
+                if spans.get(index).map_or(false, |span| {
+                    span.link.is_some() && span.underline.is_none()
+                }) =>
+            {
+                let mut spans = spans.to_vec();
+                spans[index].underline = Some(Decoration::default());
+
+                Cow::Owned(spans)
+            }
+            _ => Cow::Borrowed(spans),
+        };
+
+        let spans = spans.as_ref();
+
         let text_with_spans = || core::Text {
             content: spans,
             bounds,
@@ -483,12 +646,15 @@ where
             vertical_alignment,
             shaping: Shaping::Advanced,
             wrapping,
+            ellipsize,
         };
 
         if state.spans != spans {
             state.paragraph =
                 Renderer::Paragraph::with_spans(text_with_spans());
             state.spans = spans.iter().cloned().map(Span::to_static).collect();
+            state.content =
+                spans.iter().map(|span| span.text.as_ref()).collect();
         } else {
             match state.paragraph.compare(core::Text {
                 content: (),
@@ -500,6 +666,7 @@ where
                 vertical_alignment,
                 shaping: Shaping::Advanced,
                 wrapping,
+                ellipsize,
             }) {
                 core::text::Difference::None => {}
                 core::text::Difference::Bounds => {
@@ -50,13 +50,22 @@ use crate::core::{
     Background, Border, Color, Element, Length, Padding, Pixels, Point,
     Rectangle, Shell, Size, SmolStr, Theme, Vector,
 };
+use crate::dnd_destination;
 use crate::runtime::{task, Action as RuntimeAction, Task};
 
+use dnd::{DndEvent, OfferEvent};
+
 use std::cell::RefCell;
 use std::fmt;
 use std::ops::DerefMut;
+use std::path::PathBuf;
 use std::sync::Arc;
 
+#[cfg(feature = "a11y")]
+use crate::core::id::IdEq;
+#[cfg(feature = "a11y")]
+use std::borrow::Cow;
+
 pub use text::editor::{Action, Edit, Motion};
 
 /// The identifier of a [`TextEditor`].
@@ -114,7 +123,13 @@ pub struct TextEditor<
     Theme: Catalog,
     Renderer: text::Renderer,
 {
-    id: Option<Id>,
+    id: Id,
+    #[cfg(feature = "a11y")]
+    name: Option<Cow<'a, str>>,
+    #[cfg(feature = "a11y")]
+    description: Option<iced_accessibility::Description<'a>>,
+    #[cfg(feature = "a11y")]
+    label: Option<Vec<iced_accessibility::accesskit::NodeId>>,
     content: &'a Content<Renderer>,
     placeholder: Option<text::Fragment<'a>>,
     font: Option<Renderer::Font>,
@@ -127,6 +142,8 @@ pub struct TextEditor<
     class: Theme::Class<'a>,
     key_binding: Option<Box<dyn Fn(KeyPress) -> Option<Binding<Message>> + 'a>>,
     on_edit: Option<Box<dyn Fn(Action) -> Message + 'a>>,
+    on_drop_files: Option<Box<dyn Fn(Vec<PathBuf>) -> Message + 'a>>,
+    drop_text: bool,
     highlighter_settings: Highlighter::Settings,
     highlighter_format: fn(
         &Highlighter::Highlight,
@@ -143,7 +160,13 @@ where
     /// Creates new [`TextEditor`] with the given [`Content`].
     pub fn new(content: &'a Content<Renderer>) -> Self {
         Self {
-            id: None,
+            id: Id(widget::Id::unique()),
+            #[cfg(feature = "a11y")]
+            name: None,
+            #[cfg(feature = "a11y")]
+            description: None,
+            #[cfg(feature = "a11y")]
+            label: None,
             content,
             placeholder: None,
             font: None,
@@ -156,6 +179,8 @@ where
             class: Theme::default(),
             key_binding: None,
             on_edit: None,
+            on_drop_files: None,
+            drop_text: true,
             highlighter_settings: (),
             highlighter_format: |_highlight, _theme| {
                 highlighter::Format::default()
@@ -164,7 +189,7 @@ where
     }
 
     pub fn id(mut self, id: impl Into<Id>) -> Self {
-        self.id = Some(id.into());
+        self.id = id.into();
         self
     }
 }
@@ -216,6 +241,30 @@ where
         self
     }
 
+    /// Sets the message that should be produced when some files are dropped
+    /// into the [`TextEditor`].
+    ///
+    /// If this method is not called, dropped files are ignored. Dropped text
+    /// is inserted at the drop position, as long as the [`TextEditor`] is
+    /// enabled; see [`Self::drop_text`].
+    pub fn on_drop_files(
+        mut self,
+        on_drop_files: impl Fn(Vec<PathBuf>) -> Message + 'a,
+    ) -> Self {
+        self.on_drop_files = Some(Box::new(on_drop_files));
+        self
+    }
+
+    /// Sets whether text dropped into the [`TextEditor`] is inserted at the
+    /// drop position.
+    ///
+    /// It is enabled by default. The [`TextEditor`] is not a drop target at
+    /// all when text dropping is disabled and no files are accepted.
+    pub fn drop_text(mut self, drop_text: bool) -> Self {
+        self.drop_text = drop_text;
+        self
+    }
+
     /// Sets the [`Font`] of the [`TextEditor`].
     ///
     /// [`Font`]: text::Renderer::Font
@@ -251,6 +300,43 @@ where
         self
     }
 
+    #[cfg(feature = "a11y")]
+    /// Sets the name of the [`TextEditor`].
+    ///
+    /// By default, the placeholder is used.
+    pub fn name(mut self, name: impl Into<Cow<'a, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`TextEditor`].
+    pub fn description_widget(
+        mut self,
+        description: &impl iced_accessibility::Describes,
+    ) -> Self {
+        self.description = Some(iced_accessibility::Description::Id(
+            description.description(),
+        ));
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`TextEditor`].
+    pub fn description(mut self, description: impl Into<Cow<'a, str>>) -> Self {
+        self.description =
+            Some(iced_accessibility::Description::Text(description.into()));
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the label of the [`TextEditor`].
+    pub fn label(mut self, label: &dyn iced_accessibility::Labels) -> Self {
+        self.label =
+            Some(label.label().into_iter().map(|l| l.into()).collect());
+        self
+    }
+
     /// Highlights the [`TextEditor`] using the given syntax and theme.
     #[cfg(feature = "highlighter")]
     pub fn highlight(
@@ -282,6 +368,12 @@ where
     ) -> TextEditor<'a, H, Message, Theme, Renderer> {
         TextEditor {
             id: self.id,
+            #[cfg(feature = "a11y")]
+            name: self.name,
+            #[cfg(feature = "a11y")]
+            description: self.description,
+            #[cfg(feature = "a11y")]
+            label: self.label,
             content: self.content,
             placeholder: self.placeholder,
             font: self.font,
@@ -294,6 +386,8 @@ where
             class: self.class,
             key_binding: self.key_binding,
             on_edit: self.on_edit,
+            on_drop_files: self.on_drop_files,
+            drop_text: self.drop_text,
             highlighter_settings: settings,
             highlighter_format: to_format,
         }
@@ -447,6 +541,14 @@ where
     pub fn cursor_position(&self) -> (usize, usize) {
         self.0.borrow().editor.cursor_position()
     }
+
+    /// Returns the position where the current selection of the [`Content`]
+    /// started, if any.
+    ///
+    /// The other end of the selection is the cursor position.
+    pub fn selection_anchor(&self) -> Option<(usize, usize)> {
+        self.0.borrow().editor.selection_anchor()
+    }
 }
 
 impl<Renderer> Default for Content<Renderer>
@@ -479,9 +581,12 @@ pub struct State<Highlighter: text::Highlighter> {
     focus: Option<Focus>,
     last_click: Option<mouse::Click>,
     drag_click: Option<mouse::click::Kind>,
+    drop_offer: Option<DropOffer>,
     highlighter: RefCell<Highlighter>,
     highlighter_settings: Highlighter::Settings,
     highlighter_format_address: usize,
+    #[cfg(feature = "a11y")]
+    text_id: widget::Id,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -491,6 +596,12 @@ struct Focus {
     is_window_focused: bool,
 }
 
+#[derive(Debug, Clone, Copy)]
+struct DropOffer {
+    position: Point,
+    is_dropped: bool,
+}
+
 impl Focus {
     const CURSOR_BLINK_INTERVAL_MILLIS: u128 = 500;
 
@@ -552,11 +663,14 @@ where
             focus: None,
             last_click: None,
             drag_click: None,
+            drop_offer: None,
             highlighter: RefCell::new(Highlighter::new(
                 &self.highlighter_settings,
             )),
             highlighter_settings: self.highlighter_settings.clone(),
             highlighter_format_address: self.highlighter_format as usize,
+            #[cfg(feature = "a11y")]
+            text_id: widget::Id::unique(),
         })
     }
 
@@ -631,12 +745,81 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Highlighter>>();
+
+        if let Event::Dnd(DndEvent::Offer(id, offer_event)) = &event {
+            if id.is_none()
+                || *id != dnd_destination::destination_id(&self.id.0)
+            {
+                return event::Status::Ignored;
+            }
+
+            match offer_event {
+                OfferEvent::Enter { x, y, .. }
+                | OfferEvent::Motion { x, y } => {
+                    let text_bounds = layout.bounds().shrink(self.padding);
+
+                    state.drop_offer = Some(DropOffer {
+                        position: Point::new(
+                            *x as f32 - text_bounds.x,
+                            *y as f32 - text_bounds.y,
+                        ),
+                        is_dropped: false,
+                    });
+
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+                OfferEvent::Leave | OfferEvent::LeaveDestination => {
+                    // The data of a dropped offer is received after it leaves
+                    if state.drop_offer.is_some_and(|offer| !offer.is_dropped) {
+                        state.drop_offer = None;
+
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    }
+                }
+                OfferEvent::Drop => {
+                    if let Some(offer) = &mut state.drop_offer {
+                        offer.is_dropped = true;
+                    }
+                }
+                OfferEvent::Data { data, mime_type } => {
+                    let Some(offer) =
+                        state.drop_offer.take_if(|offer| offer.is_dropped)
+                    else {
+                        return event::Status::Ignored;
+                    };
+
+                    if mime_type == dnd_destination::URI_LIST_MIME_TYPE {
+                        if let Some(on_drop_files) = &self.on_drop_files {
+                            shell.publish(on_drop_files(
+                                dnd_destination::file_paths(data),
+                            ));
+                        }
+
+                        return event::Status::Captured;
+                    }
+
+                    let Some(on_edit) = self.on_edit.as_ref() else {
+                        return event::Status::Ignored;
+                    };
+
+                    state.focus = Some(Focus::now());
+
+                    shell.publish(on_edit(Action::Click(offer.position)));
+                    shell.publish(on_edit(Action::Edit(Edit::Paste(
+                        Arc::new(String::from_utf8_lossy(data).into_owned()),
+                    ))));
+                }
+                OfferEvent::SelectedAction(_) => {}
+            }
+
+            return event::Status::Captured;
+        }
+
         let Some(on_edit) = self.on_edit.as_ref() else {
             return event::Status::Ignored;
         };
 
-        let state = tree.state.downcast_mut::<State<Highlighter>>();
-
         match event {
             Event::Window(window::Event::Unfocused) => {
                 if let Some(focus) = &mut state.focus {
@@ -669,6 +852,45 @@ where
                     }
                 }
             }
+            #[cfg(feature = "a11y")]
+            Event::A11y(target, request) if IdEq::eq(&target, &self.id.0) => {
+                use iced_accessibility::accesskit::{
+                    Action as A11yAction, ActionData,
+                };
+
+                match (request.action, request.data) {
+                    (A11yAction::Focus, _) => {
+                        state.focus = Some(Focus::now());
+                    }
+                    (A11yAction::Blur, _) => {
+                        state.focus = None;
+                    }
+                    (A11yAction::SetValue, Some(ActionData::Value(value))) => {
+                        shell.publish(on_edit(Action::SelectAll));
+                        shell.publish(on_edit(Action::Edit(Edit::Paste(
+                            Arc::new(value.into_string()),
+                        ))));
+                    }
+                    (
+                        A11yAction::SetTextSelection,
+                        Some(ActionData::SetTextSelection(selection)),
+                    ) => {
+                        shell.publish(on_edit(Action::SelectRange {
+                            anchor: character_position(
+                                &self.content,
+                                selection.anchor.character_index,
+                            ),
+                            focus: character_position(
+                                &self.content,
+                                selection.focus.character_index,
+                            ),
+                        }));
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                return event::Status::Captured;
+            }
             _ => {}
         }
 
@@ -886,6 +1108,7 @@ where
                         horizontal_alignment: alignment::Horizontal::Left,
                         vertical_alignment: alignment::Vertical::Top,
                         shaping: text::Shaping::Advanced,
+                        ellipsize: text::Ellipsize::default(),
                         wrapping: self.wrapping,
                     },
                     text_bounds.position(),
@@ -948,6 +1171,36 @@ where
                 Cursor::Caret(_) => {}
             }
         }
+
+        // Show where some dragged text would be inserted
+        if let Some(position) = state
+            .drop_offer
+            .filter(|offer| !offer.is_dropped && !is_disabled)
+            .and_then(|offer| internal.editor.hit_test(offer.position))
+        {
+            let caret = Rectangle::new(
+                position + translation,
+                Size::new(
+                    1.0,
+                    self.line_height
+                        .to_absolute(
+                            self.text_size
+                                .unwrap_or_else(|| renderer.default_size()),
+                        )
+                        .into(),
+                ),
+            );
+
+            if let Some(clipped_caret) = text_bounds.intersection(&caret) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: clipped_caret,
+                        ..renderer::Quad::default()
+                    },
+                    style.value,
+                );
+            }
+        }
     }
 
     fn mouse_interaction(
@@ -980,14 +1233,160 @@ where
     ) {
         let state = tree.state.downcast_mut::<State<Highlighter>>();
 
-        operation.focusable(state, self.id.as_ref().map(|id| &id.0));
+        operation.focusable(state, Some(&self.id.0));
+    }
+
+    fn drag_destinations(
+        &self,
+        _state: &widget::Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        dnd_rectangles: &mut clipboard::DndDestinationRectangles,
+    ) {
+        let accepts_text = self.drop_text && self.on_edit.is_some();
+        let accepts_files = self.on_drop_files.is_some();
+
+        if !accepts_text && !accepts_files {
+            return;
+        }
+
+        if let Some(id) = dnd_destination::destination_id(&self.id.0) {
+            dnd_rectangles.push(dnd_destination::text_destination(
+                id,
+                layout.bounds(),
+                accepts_text,
+                accepts_files,
+            ));
+        }
+    }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &widget::Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::{
+            accesskit::{
+                Action, NodeBuilder, NodeId, Rect, Role, TextPosition,
+                TextSelection,
+            },
+            A11yId, A11yNode, A11yTree,
+        };
+        use unicode_segmentation::UnicodeSegmentation;
+
+        let state = state.state.downcast_ref::<State<Highlighter>>();
+
+        let rect = |bounds: Rectangle| {
+            Rect::new(
+                bounds.x as f64,
+                bounds.y as f64,
+                (bounds.x + bounds.width) as f64,
+                (bounds.y + bounds.height) as f64,
+            )
+        };
+
+        let bounds = layout.bounds();
+
+        let mut node = NodeBuilder::new(Role::MultilineTextInput);
+        node.set_bounds(rect(bounds));
+        node.add_action(Action::Focus);
+
+        if self.on_edit.is_some() {
+            node.add_action(Action::SetValue);
+            node.add_action(Action::SetTextSelection);
+        } else {
+            node.set_disabled();
+        }
+
+        match (self.name.as_ref(), self.placeholder.as_ref()) {
+            (Some(name), _) => node.set_name(name.clone()),
+            (None, Some(placeholder)) => {
+                node.set_name(placeholder.clone().into_owned());
+            }
+            (None, None) => {}
+        }
+
+        match self.description.as_ref() {
+            Some(iced_accessibility::Description::Id(id)) => {
+                node.set_described_by(
+                    id.iter().cloned().map(NodeId::from).collect::<Vec<_>>(),
+                );
+            }
+            Some(iced_accessibility::Description::Text(text)) => {
+                node.set_description(text.clone());
+            }
+            None => {}
+        }
+
+        if let Some(label) = self.label.as_ref() {
+            node.set_labelled_by(label.clone());
+        }
+
+        if cursor.is_over(bounds) {
+            node.set_hovered();
+        }
+
+        // The whole text is exposed as a single inline text box, with each
+        // grapheme (and line break) as a character
+        let mut value = String::new();
+        let mut character_lengths = Vec::new();
+
+        for (i, line) in self.content.lines().enumerate() {
+            if i > 0 {
+                value.push('\n');
+                character_lengths.push(1);
+            }
+
+            character_lengths.extend(line.graphemes(true).map(|grapheme| {
+                u8::try_from(grapheme.len()).unwrap_or(u8::MAX)
+            }));
+
+            value.push_str(&line);
+        }
+
+        node.set_value(value.clone());
+
+        let mut text = NodeBuilder::new(Role::InlineTextBox);
+        text.set_bounds(rect(bounds.shrink(self.padding)));
+        text.set_value(value);
+        text.set_character_lengths(character_lengths);
+
+        let position = |position| TextPosition {
+            node: NodeId::from(A11yId::from(state.text_id.clone())),
+            character_index: character_index(&self.content, position),
+        };
+
+        let focus = self.content.cursor_position();
+        let anchor = self.content.selection_anchor().unwrap_or(focus);
+
+        node.set_text_selection(TextSelection {
+            anchor: position(anchor),
+            focus: position(focus),
+        });
+
+        A11yTree::node_with_child_tree(
+            A11yNode::new(node, self.id.0.clone()),
+            A11yTree::leaf(text, state.text_id.clone()),
+        )
     }
 
     fn id(&self) -> Option<widget::Id> {
-        self.id.as_ref().map(|id| id.0.clone())
+        Some(self.id.0.clone())
     }
+
     fn set_id(&mut self, id: widget::Id) {
-        self.id = Some(Id(id));
+        self.id = Id(id);
+    }
+
+    fn inspect(
+        &self,
+        _tree: &widget::Tree,
+        layout: Layout<'_>,
+    ) -> widget::inspect::Node {
+        widget::inspect::Node::new::<Self>(self.id(), self.size(), layout)
+            .with_padding(self.padding)
     }
 }
 
@@ -1243,6 +1642,70 @@ fn motion(key: key::Named) -> Option<Motion> {
     }
 }
 
+/// Returns the index of the accessible character at the given line and
+/// column of the [`Content`].
+///
+/// Each grapheme and line break is a single accessible character.
+#[cfg(feature = "a11y")]
+fn character_index<Renderer>(
+    content: &Content<Renderer>,
+    (line, column): (usize, usize),
+) -> usize
+where
+    Renderer: text::Renderer,
+{
+    use unicode_segmentation::UnicodeSegmentation;
+
+    content
+        .lines()
+        .take(line + 1)
+        .enumerate()
+        .map(|(i, text)| {
+            if i < line {
+                text.graphemes(true).count() + 1
+            } else {
+                text.get(..column).unwrap_or(&*text).graphemes(true).count()
+            }
+        })
+        .sum()
+}
+
+/// Returns the line and column of the [`Content`] of the accessible
+/// character at the given index.
+///
+/// This is the inverse of [`character_index`]. Indices past the end are
+/// clamped to the end of the [`Content`].
+#[cfg(feature = "a11y")]
+fn character_position<Renderer>(
+    content: &Content<Renderer>,
+    mut index: usize,
+) -> (usize, usize)
+where
+    Renderer: text::Renderer,
+{
+    use unicode_segmentation::UnicodeSegmentation;
+
+    let mut end = (0, 0);
+
+    for (i, text) in content.lines().enumerate() {
+        let graphemes = text.graphemes(true).count();
+
+        if index <= graphemes {
+            let column = text
+                .grapheme_indices(true)
+                .nth(index)
+                .map_or(text.len(), |(column, _)| column);
+
+            return (i, column);
+        }
+
+        index -= graphemes + 1;
+        end = (i, text.len());
+    }
+
+    end
+}
+
 /// The possible status of a [`TextEditor`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
@@ -60,9 +60,19 @@ use crate::core::{
     Background, Border, Color, Element, Layout, Length, Padding, Pixels, Point,
     Rectangle, Shell, Size, Theme, Vector, Widget,
 };
+use crate::dnd_destination;
 use crate::runtime::task::{self, Task};
 use crate::runtime::Action;
 
+use dnd::{DndEvent, OfferEvent};
+
+use std::path::PathBuf;
+
+#[cfg(feature = "a11y")]
+use crate::core::id::IdEq;
+#[cfg(feature = "a11y")]
+use std::borrow::Cow;
+
 /// A field that can be filled with text.
 ///
 /// # Example
@@ -105,7 +115,13 @@ pub struct TextInput<
     Theme: Catalog,
     Renderer: text::Renderer,
 {
-    id: Option<Id>,
+    id: Id,
+    #[cfg(feature = "a11y")]
+    name: Option<Cow<'a, str>>,
+    #[cfg(feature = "a11y")]
+    description: Option<iced_accessibility::Description<'a>>,
+    #[cfg(feature = "a11y")]
+    label: Option<Vec<iced_accessibility::accesskit::NodeId>>,
     placeholder: String,
     value: Value,
     is_secure: bool,
@@ -118,6 +134,8 @@ pub struct TextInput<
     on_input: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_paste: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_submit: Option<Message>,
+    on_drop_files: Option<Box<dyn Fn(Vec<PathBuf>) -> Message + 'a>>,
+    drop_text: bool,
     icon: Option<Icon<Renderer::Font>>,
     class: Theme::Class<'a>,
 }
@@ -135,7 +153,13 @@ where
     /// its current value.
     pub fn new(placeholder: &str, value: &str) -> Self {
         TextInput {
-            id: None,
+            id: Id::unique(),
+            #[cfg(feature = "a11y")]
+            name: None,
+            #[cfg(feature = "a11y")]
+            description: None,
+            #[cfg(feature = "a11y")]
+            label: None,
             placeholder: String::from(placeholder),
             value: Value::new(value),
             is_secure: false,
@@ -148,6 +172,8 @@ where
             on_input: None,
             on_paste: None,
             on_submit: None,
+            on_drop_files: None,
+            drop_text: true,
             icon: None,
             class: Theme::default(),
         }
@@ -155,7 +181,7 @@ where
 
     /// Sets the [`Id`] of the [`TextInput`].
     pub fn id(mut self, id: impl Into<Id>) -> Self {
-        self.id = Some(id.into());
+        self.id = id.into();
         self
     }
 
@@ -223,6 +249,30 @@ where
         self
     }
 
+    /// Sets the message that should be produced when some files are dropped
+    /// into the [`TextInput`].
+    ///
+    /// If this method is not called, dropped files are ignored. Dropped text
+    /// is inserted at the drop position, as long as the [`TextInput`] is
+    /// enabled; see [`Self::drop_text`].
+    pub fn on_drop_files(
+        mut self,
+        on_drop_files: impl Fn(Vec<PathBuf>) -> Message + 'a,
+    ) -> Self {
+        self.on_drop_files = Some(Box::new(on_drop_files));
+        self
+    }
+
+    /// Sets whether text dropped into the [`TextInput`] is inserted at the
+    /// drop position.
+    ///
+    /// It is enabled by default. The [`TextInput`] is not a drop target at
+    /// all when text dropping is disabled and no files are accepted.
+    pub fn drop_text(mut self, drop_text: bool) -> Self {
+        self.drop_text = drop_text;
+        self
+    }
+
     /// Sets the [`Font`] of the [`TextInput`].
     ///
     /// [`Font`]: text::Renderer::Font
@@ -290,6 +340,43 @@ where
         self
     }
 
+    #[cfg(feature = "a11y")]
+    /// Sets the name of the [`TextInput`].
+    ///
+    /// By default, the placeholder is used.
+    pub fn name(mut self, name: impl Into<Cow<'a, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`TextInput`].
+    pub fn description_widget(
+        mut self,
+        description: &impl iced_accessibility::Describes,
+    ) -> Self {
+        self.description = Some(iced_accessibility::Description::Id(
+            description.description(),
+        ));
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the description of the [`TextInput`].
+    pub fn description(mut self, description: impl Into<Cow<'a, str>>) -> Self {
+        self.description =
+            Some(iced_accessibility::Description::Text(description.into()));
+        self
+    }
+
+    #[cfg(feature = "a11y")]
+    /// Sets the label of the [`TextInput`].
+    pub fn label(mut self, label: &dyn iced_accessibility::Labels) -> Self {
+        self.label =
+            Some(label.label().into_iter().map(|l| l.into()).collect());
+        self
+    }
+
     /// Lays out the [`TextInput`], overriding its [`Value`] if provided.
     ///
     /// [`Renderer`]: text::Renderer
@@ -321,6 +408,7 @@ where
             vertical_alignment: alignment::Vertical::Center,
             shaping: text::Shaping::Advanced,
             wrapping: text::Wrapping::default(),
+            ellipsize: text::Ellipsize::default(),
         };
 
         state.placeholder.update(placeholder_text);
@@ -346,6 +434,7 @@ where
                 vertical_alignment: alignment::Vertical::Center,
                 shaping: text::Shaping::Advanced,
                 wrapping: text::Wrapping::default(),
+                ellipsize: text::Ellipsize::default(),
             };
 
             state.icon.update(icon_text);
@@ -538,6 +627,31 @@ where
             (None, 0.0, false)
         };
 
+        // Show where some dragged text would be inserted
+        let cursor = match state.drop_offer {
+            Some(offer) if !offer.is_dropped && !is_disabled => {
+                let (drop_position, _) = measure_cursor_and_scroll_offset(
+                    state.value.raw(),
+                    text_bounds,
+                    offer.position,
+                );
+
+                Some((
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: (text_bounds.x + drop_position).floor(),
+                            y: text_bounds.y,
+                            width: 1.0,
+                            height: text_bounds.height,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.value,
+                ))
+            }
+            _ => cursor,
+        };
+
         let draw = |renderer: &mut Renderer, viewport| {
             let paragraph = if text.is_empty() {
                 state.placeholder.raw()
@@ -633,8 +747,8 @@ where
     ) {
         let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
-        operation.focusable(state, self.id.as_ref().map(|id| &id.0));
-        operation.text_input(state, self.id.as_ref().map(|id| &id.0));
+        operation.focusable(state, Some(&self.id.0));
+        operation.text_input(state, Some(&self.id.0));
     }
 
     fn on_event(
@@ -1165,6 +1279,165 @@ where
                     }
                 }
             }
+            Event::Dnd(DndEvent::Offer(id, offer_event))
+                if id.is_some()
+                    && id == dnd_destination::destination_id(&self.id.0) =>
+            {
+                let state = state::<Renderer>(tree);
+
+                match offer_event {
+                    OfferEvent::Enter { x, .. }
+                    | OfferEvent::Motion { x, .. } => {
+                        let text_bounds =
+                            layout.children().next().unwrap().bounds();
+
+                        let target = x as f32
+                            - text_bounds.x
+                            - alignment_offset(
+                                text_bounds.width,
+                                state.value.raw().min_width(),
+                                self.alignment,
+                            );
+
+                        let value = if self.is_secure {
+                            self.value.secure()
+                        } else {
+                            self.value.clone()
+                        };
+
+                        let position = if target > 0.0 {
+                            find_cursor_position(
+                                text_bounds,
+                                &value,
+                                state,
+                                target,
+                            )
+                        } else {
+                            None
+                        }
+                        .unwrap_or(0);
+
+                        state.drop_offer = Some(DropOffer {
+                            position,
+                            is_dropped: false,
+                        });
+
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    }
+                    OfferEvent::Leave | OfferEvent::LeaveDestination => {
+                        // The data of a dropped offer is received after it leaves
+                        if state
+                            .drop_offer
+                            .is_some_and(|offer| !offer.is_dropped)
+                        {
+                            state.drop_offer = None;
+
+                            shell.request_redraw(
+                                window::RedrawRequest::NextFrame,
+                            );
+                        }
+                    }
+                    OfferEvent::Drop => {
+                        if let Some(offer) = &mut state.drop_offer {
+                            offer.is_dropped = true;
+                        }
+                    }
+                    OfferEvent::Data { data, mime_type } => {
+                        let Some(offer) =
+                            state.drop_offer.take_if(|offer| offer.is_dropped)
+                        else {
+                            return event::Status::Ignored;
+                        };
+
+                        if mime_type == dnd_destination::URI_LIST_MIME_TYPE {
+                            if let Some(on_drop_files) = &self.on_drop_files {
+                                shell.publish(on_drop_files(
+                                    dnd_destination::file_paths(&data),
+                                ));
+                            }
+
+                            return event::Status::Captured;
+                        }
+
+                        let Some(on_input) = &self.on_input else {
+                            return event::Status::Ignored;
+                        };
+
+                        let content: String = String::from_utf8_lossy(&data)
+                            .chars()
+                            .filter(|c| !c.is_control())
+                            .collect();
+
+                        state.cursor.move_to(offer.position);
+
+                        let mut editor =
+                            Editor::new(&mut self.value, &mut state.cursor);
+
+                        editor.paste(Value::new(&content));
+
+                        let message = if let Some(paste) = &self.on_paste {
+                            (paste)(editor.contents())
+                        } else {
+                            (on_input)(editor.contents())
+                        };
+                        shell.publish(message);
+
+                        let now = Instant::now();
+
+                        state.is_focused = Some(Focus {
+                            updated_at: now,
+                            now,
+                            is_window_focused: true,
+                        });
+
+                        update_cache(state, &self.value);
+                    }
+                    OfferEvent::SelectedAction(_) => {}
+                }
+
+                return event::Status::Captured;
+            }
+            #[cfg(feature = "a11y")]
+            Event::A11y(target, request) if IdEq::eq(&target, &self.id.0) => {
+                use iced_accessibility::accesskit::{Action, ActionData};
+
+                let state = state::<Renderer>(tree);
+
+                match (request.action, request.data) {
+                    (Action::Focus, _) => {
+                        state.focus();
+                    }
+                    (Action::Blur, _) => {
+                        state.unfocus();
+                    }
+                    (Action::SetValue, Some(ActionData::Value(value))) => {
+                        let Some(on_input) = &self.on_input else {
+                            return event::Status::Ignored;
+                        };
+
+                        shell.publish(on_input(value.into_string()));
+                    }
+                    (
+                        Action::SetTextSelection,
+                        Some(ActionData::SetTextSelection(selection)),
+                    ) => {
+                        state.cursor.select_range(
+                            selection.anchor.character_index,
+                            selection.focus.character_index,
+                        );
+                    }
+                    (Action::Default, _) => {
+                        let Some(on_submit) = self.on_submit.clone() else {
+                            return event::Status::Ignored;
+                        };
+
+                        shell.publish(on_submit);
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                return event::Status::Captured;
+            }
             _ => {}
         }
 
@@ -1202,6 +1475,171 @@ where
             mouse::Interaction::default()
         }
     }
+
+    fn drag_destinations(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        dnd_rectangles: &mut clipboard::DndDestinationRectangles,
+    ) {
+        let accepts_text = self.drop_text && self.on_input.is_some();
+        let accepts_files = self.on_drop_files.is_some();
+
+        if !accepts_text && !accepts_files {
+            return;
+        }
+
+        if let Some(id) = dnd_destination::destination_id(&self.id.0) {
+            dnd_rectangles.push(dnd_destination::text_destination(
+                id,
+                layout.bounds(),
+                accepts_text,
+                accepts_files,
+            ));
+        }
+    }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::{
+            accesskit::{
+                Action, NodeBuilder, NodeId, Rect, Role, TextPosition,
+                TextSelection,
+            },
+            A11yId, A11yNode, A11yTree,
+        };
+
+        let state = state.state.downcast_ref::<State<Renderer::Paragraph>>();
+
+        let rect = |bounds: Rectangle| {
+            Rect::new(
+                bounds.x as f64,
+                bounds.y as f64,
+                (bounds.x + bounds.width) as f64,
+                (bounds.y + bounds.height) as f64,
+            )
+        };
+
+        let bounds = layout.bounds();
+        let value = if self.is_secure {
+            self.value.secure()
+        } else {
+            self.value.clone()
+        };
+
+        let mut node = NodeBuilder::new(if self.is_secure {
+            Role::PasswordInput
+        } else {
+            Role::TextInput
+        });
+        node.set_bounds(rect(bounds));
+        node.add_action(Action::Focus);
+        node.add_action(Action::SetTextSelection);
+
+        if self.on_input.is_some() {
+            node.add_action(Action::SetValue);
+        } else {
+            node.set_disabled();
+        }
+
+        if self.on_submit.is_some() {
+            node.add_action(Action::Default);
+        }
+
+        match self.name.as_ref() {
+            Some(name) => node.set_name(name.clone()),
+            None if !self.placeholder.is_empty() => {
+                node.set_name(self.placeholder.clone());
+            }
+            None => {}
+        }
+
+        match self.description.as_ref() {
+            Some(iced_accessibility::Description::Id(id)) => {
+                node.set_described_by(
+                    id.iter().cloned().map(NodeId::from).collect::<Vec<_>>(),
+                );
+            }
+            Some(iced_accessibility::Description::Text(text)) => {
+                node.set_description(text.clone());
+            }
+            None => {}
+        }
+
+        if let Some(label) = self.label.as_ref() {
+            node.set_labelled_by(label.clone());
+        }
+
+        if cursor.is_over(bounds) {
+            node.set_hovered();
+        }
+
+        node.set_value(value.to_string());
+
+        let Some(text_id) = state.text_id.clone() else {
+            return A11yTree::leaf(node, self.id.0.clone());
+        };
+
+        // The caret and the selection are positioned in an inline text box,
+        // with each grapheme as a character
+        let mut text = NodeBuilder::new(Role::InlineTextBox);
+        text.set_bounds(rect(
+            layout
+                .children()
+                .next()
+                .map_or(bounds, |text_layout| text_layout.bounds()),
+        ));
+        text.set_value(value.to_string());
+        text.set_character_lengths(
+            value
+                .grapheme_lengths()
+                .map(|length| u8::try_from(length).unwrap_or(u8::MAX))
+                .collect::<Vec<_>>(),
+        );
+
+        let (anchor, focus) = match state.cursor.state(&value) {
+            cursor::State::Index(index) => (index, index),
+            cursor::State::Selection { start, end } => (start, end),
+        };
+
+        let position = |character_index| TextPosition {
+            node: NodeId::from(A11yId::from(text_id.clone())),
+            character_index,
+        };
+
+        node.set_text_selection(TextSelection {
+            anchor: position(anchor),
+            focus: position(focus),
+        });
+
+        A11yTree::node_with_child_tree(
+            A11yNode::new(node, self.id.0.clone()),
+            A11yTree::leaf(text, text_id),
+        )
+    }
+
+    fn id(&self) -> Option<widget::Id> {
+        Some(self.id.0.clone())
+    }
+
+    fn set_id(&mut self, id: widget::Id) {
+        self.id = Id(id);
+    }
+
+    fn inspect(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+    ) -> widget::inspect::Node {
+        widget::inspect::Node::new::<Self>(self.id(), self.size(), layout)
+            .with_padding(self.padding)
+    }
 }
 
 impl<'a, Message, Theme, Renderer> From<TextInput<'a, Message, Theme, Renderer>>
@@ -1324,9 +1762,12 @@ pub struct State<P: text::Paragraph> {
     is_focused: Option<Focus>,
     is_dragging: bool,
     is_pasting: Option<Value>,
+    drop_offer: Option<DropOffer>,
     last_click: Option<mouse::Click>,
     cursor: Cursor,
     keyboard_modifiers: keyboard::Modifiers,
+    #[cfg(feature = "a11y")]
+    text_id: Option<widget::Id>,
     // TODO: Add stateful horizontal scrolling offset
 }
 
@@ -1343,10 +1784,20 @@ struct Focus {
     is_window_focused: bool,
 }
 
+#[derive(Debug, Clone, Copy)]
+struct DropOffer {
+    position: usize,
+    is_dropped: bool,
+}
+
 impl<P: text::Paragraph> State<P> {
     /// Creates a new [`State`], representing an unfocused [`TextInput`].
     pub fn new() -> Self {
-        Self::default()
+        Self {
+            #[cfg(feature = "a11y")]
+            text_id: Some(widget::Id::unique()),
+            ..Self::default()
+        }
     }
 
     /// Returns whether the [`TextInput`] is currently focused or not.
@@ -1522,6 +1973,7 @@ fn replace_paragraph<Renderer>(
         vertical_alignment: alignment::Vertical::Top,
         shaping: text::Shaping::Advanced,
         wrapping: text::Wrapping::default(),
+        ellipsize: text::Ellipsize::default(),
     });
 }
 
@@ -125,6 +125,12 @@ impl Value {
                 .collect(),
         }
     }
+
+    /// Returns the length in bytes of each grapheme of the [`Value`].
+    #[cfg(feature = "a11y")]
+    pub(crate) fn grapheme_lengths(&self) -> impl Iterator<Item = usize> + '_ {
+        self.graphemes.iter().map(String::len)
+    }
 }
 
 impl std::fmt::Display for Value {
@@ -6,7 +6,7 @@ use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::widget::tree::{self, Tree};
-use crate::core::widget::Operation;
+use crate::core::widget::{inspect, Operation};
 use crate::core::{
     Background, Clipboard, Color, Element, Layout, Length, Point, Rectangle,
     Shell, Size, Vector, Widget,
@@ -289,6 +289,11 @@ where
             })
             .map(|overlay| overlay::Element::new(Box::new(overlay)))
     }
+
+    fn inspect(&self, tree: &Tree, layout: Layout<'_>) -> inspect::Node {
+        inspect::Node::new::<Self>(self.id(), self.size(), layout)
+            .with_children([self.content.as_widget().inspect(tree, layout)])
+    }
 }
 
 impl<'a, Message, Theme, NewTheme, F, Renderer>
@@ -354,6 +354,7 @@ where
                         alignment::Vertical::Top,
                         self.text_shaping,
                         self.text_wrapping,
+                        text::Ellipsize::None,
                     )
                 } else {
                     layout::Node::new(crate::core::Size::ZERO)
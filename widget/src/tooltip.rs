@@ -28,7 +28,7 @@ use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::text;
-use crate::core::widget::{self, Widget};
+use crate::core::widget::{self, inspect, Id, Widget};
 use crate::core::{
     Clipboard, Element, Length, Padding, Pixels, Point, Rectangle, Shell, Size,
     Vector,
@@ -67,6 +67,7 @@ pub struct Tooltip<
     Theme: container::Catalog,
     Renderer: text::Renderer,
 {
+    id: Id,
     content: Element<'a, Message, Theme, Renderer>,
     tooltip: Element<'a, Message, Theme, Renderer>,
     position: Position,
@@ -93,6 +94,7 @@ where
         position: Position,
     ) -> Self {
         Tooltip {
+            id: Id::unique(),
             content: content.into(),
             tooltip: tooltip.into(),
             position,
@@ -203,16 +205,21 @@ where
     ) -> event::Status {
         let state = tree.state.downcast_mut::<State>();
 
-        let was_idle = *state == State::Idle;
+        let was_idle = state.status == Status::Idle;
 
-        *state = cursor
+        state.status = cursor
             .position_over(layout.bounds())
-            .map(|cursor_position| State::Hovered { cursor_position })
+            .map(|cursor_position| Status::Hovered { cursor_position })
             .unwrap_or_default();
 
-        let is_idle = *state == State::Idle;
+        let is_idle = state.status == Status::Idle;
 
         if was_idle != is_idle {
+            #[cfg(feature = "a11y")]
+            {
+                state.layout = None;
+            }
+
             shell.invalidate_layout();
         }
 
@@ -273,7 +280,7 @@ where
         renderer: &Renderer,
         translation: Vector,
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
-        let state = tree.state.downcast_ref::<State>();
+        let state = tree.state.downcast_mut::<State>();
 
         let mut children = tree.children.iter_mut();
 
@@ -284,11 +291,14 @@ where
             translation,
         );
 
-        let tooltip = if let State::Hovered { cursor_position } = *state {
+        let tooltip = if let Status::Hovered { cursor_position } = state.status
+        {
             Some(overlay::Element::new(Box::new(Overlay {
                 position: layout.position() + translation,
                 tooltip: &self.tooltip,
                 state: children.next().unwrap(),
+                #[cfg(feature = "a11y")]
+                layout: &mut state.layout,
                 cursor_position,
                 content_bounds: layout.bounds(),
                 snap_within_viewport: self.snap_within_viewport,
@@ -312,6 +322,90 @@ where
             None
         }
     }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &widget::Tree,
+        cursor: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::{
+            accesskit::{NodeBuilder, Rect, Role},
+            A11yId, A11yNode, A11yTree,
+        };
+
+        let mut content = self.content.as_widget().a11y_nodes(
+            layout,
+            &state.children[0],
+            cursor,
+        );
+
+        // The tooltip is only exposed while it is shown
+        let Some(node) = state.state.downcast_ref::<State>().layout.as_ref()
+        else {
+            return content;
+        };
+
+        let tooltip_layout = Layout::new(node);
+
+        let tooltip = self.tooltip.as_widget().a11y_nodes(
+            tooltip_layout.children().next().unwrap(),
+            &state.children[1],
+            cursor,
+        );
+
+        let Rectangle {
+            x,
+            y,
+            width,
+            height,
+        } = tooltip_layout.bounds();
+
+        let mut node = NodeBuilder::new(Role::Tooltip);
+        node.set_bounds(Rect::new(
+            x as f64,
+            y as f64,
+            (x + width) as f64,
+            (y + height) as f64,
+        ));
+
+        for root in content.root_mut() {
+            root.node_mut()
+                .set_described_by(vec![A11yId::Widget(self.id.clone()).into()]);
+        }
+
+        A11yTree::join(
+            [
+                content,
+                A11yTree::node_with_child_tree(
+                    A11yNode::new(node, self.id.clone()),
+                    tooltip,
+                ),
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn id(&self) -> Option<Id> {
+        Some(self.id.clone())
+    }
+
+    fn set_id(&mut self, id: Id) {
+        self.id = id;
+    }
+
+    fn inspect(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+    ) -> inspect::Node {
+        inspect::Node::new::<Self>(self.id(), self.size(), layout)
+            .with_children([self
+                .content
+                .as_widget()
+                .inspect(&tree.children[0], layout)])
+    }
 }
 
 impl<'a, Message, Theme, Renderer> From<Tooltip<'a, Message, Theme, Renderer>>
@@ -344,8 +438,16 @@ pub enum Position {
     FollowCursor,
 }
 
+#[derive(Debug, Clone, Default)]
+struct State {
+    status: Status,
+    /// The layout of the shown tooltip, kept for its accessibility nodes.
+    #[cfg(feature = "a11y")]
+    layout: Option<layout::Node>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
-enum State {
+enum Status {
     #[default]
     Idle,
     Hovered {
@@ -361,6 +463,8 @@ where
     position: Point,
     tooltip: &'b Element<'a, Message, Theme, Renderer>,
     state: &'b mut widget::Tree,
+    #[cfg(feature = "a11y")]
+    layout: &'b mut Option<layout::Node>,
     cursor_position: Point,
     content_bounds: Rectangle,
     snap_within_viewport: bool,
@@ -467,12 +571,19 @@ where
             }
         }
 
-        layout::Node::with_children(
+        let node = layout::Node::with_children(
             tooltip_bounds.size(),
             vec![tooltip_layout
                 .translate(Vector::new(self.padding, self.padding))],
         )
-        .translate(Vector::new(tooltip_bounds.x, tooltip_bounds.y))
+        .translate(Vector::new(tooltip_bounds.x, tooltip_bounds.y));
+
+        #[cfg(feature = "a11y")]
+        {
+            *self.layout = Some(node.clone());
+        }
+
+        node
     }
 
     fn draw(
@@ -186,7 +186,13 @@ where
                         ..
                     },
                 ..
-            } => _debug.toggle(),
+            } => {
+                if self.modifiers.shift_key() {
+                    _debug.toggle_outlines();
+                } else {
+                    _debug.toggle();
+                }
+            }
             _ => {}
         }
     }
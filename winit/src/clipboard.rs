@@ -240,6 +240,20 @@ impl crate::core::Clipboard for Clipboard {
         }
     }
 
+    fn read_mime_types(&self, kind: Kind) -> Vec<String> {
+        let mime_types = match (&self.state, kind) {
+            (State::Connected { clipboard, .. }, Kind::Standard) => {
+                clipboard.mime_types().ok()
+            }
+            (State::Connected { clipboard, .. }, Kind::Primary) => {
+                clipboard.primary_mime_types().and_then(|res| res.ok())
+            }
+            (State::Unavailable, _) => None,
+        };
+
+        mime_types.unwrap_or_default()
+    }
+
     fn write_data(
         &mut self,
         kind: Kind,
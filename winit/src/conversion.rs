@@ -10,7 +10,7 @@ use crate::core::keyboard;
 use crate::core::mouse;
 use crate::core::touch;
 use crate::core::window;
-use crate::core::{Event, Point, Size};
+use crate::core::{Event, Point, Rectangle, Size};
 
 /// Converts some [`window::Settings`] into some `WindowAttributes` from `winit`.
 pub fn window_attributes(
@@ -38,7 +38,13 @@ pub fn window_attributes(
         .with_transparent(settings.transparent)
         .with_window_icon(settings.icon.and_then(icon))
         .with_window_level(window_level(settings.level))
-        .with_visible(settings.visible);
+        .with_visible(settings.visible)
+        .with_maximized(
+            settings
+                .geometry
+                .as_ref()
+                .is_some_and(|geometry| geometry.maximized),
+        );
 
     if let Some(position) =
         position(primary_monitor.as_ref(), settings.size, settings.position)
@@ -347,6 +353,19 @@ pub fn window_level(level: window::Level) -> winit::window::WindowLevel {
     }
 }
 
+/// Converts a [`window::CursorGrab`] to a [`winit`] cursor grab mode.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn cursor_grab(
+    cursor_grab: window::CursorGrab,
+) -> winit::window::CursorGrabMode {
+    match cursor_grab {
+        window::CursorGrab::None => winit::window::CursorGrabMode::None,
+        window::CursorGrab::Confined => winit::window::CursorGrabMode::Confined,
+        window::CursorGrab::Locked => winit::window::CursorGrabMode::Locked,
+    }
+}
+
 /// Converts a [`window::Position`] to a [`winit`] logical position for a given monitor.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -423,6 +442,126 @@ pub fn position(
     }
 }
 
+/// Converts a [`winit`] monitor handle to a [`window::Monitor`].
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn monitor(monitor: &winit::monitor::MonitorHandle) -> window::Monitor {
+    let position = monitor.position().unwrap_or_default();
+    let video_mode = monitor.current_video_mode();
+
+    let size = video_mode
+        .as_ref()
+        .map(|video_mode| video_mode.size())
+        .unwrap_or_default();
+
+    let work_area = monitor_work_area(monitor).unwrap_or(Rectangle {
+        x: position.x,
+        y: position.y,
+        width: size.width as i32,
+        height: size.height as i32,
+    });
+
+    window::Monitor {
+        name: monitor.name().map(Into::into),
+        position: Point::new(position.x, position.y),
+        size: Size::new(size.width, size.height),
+        work_area,
+        scale_factor: monitor.scale_factor(),
+        refresh_rate: video_mode
+            .and_then(|video_mode| video_mode.refresh_rate_millihertz())
+            .map(std::num::NonZeroU32::get),
+    }
+}
+
+/// Applies the saved [`window::Geometry`] of the given [`window::Settings`],
+/// if any, validating its position against the available monitors.
+///
+/// Returns the physical position to place the window at, if still visible.
+/// Otherwise, the window is centered instead; on the monitor it was last on,
+/// if still connected.
+pub fn restore_geometry(
+    settings: &mut window::Settings,
+    monitors: impl Iterator<Item = winit::monitor::MonitorHandle>,
+) -> (
+    Option<winit::monitor::MonitorHandle>,
+    Option<winit::dpi::PhysicalPosition<i32>>,
+) {
+    let Some(geometry) = settings.geometry.as_ref() else {
+        return (None, None);
+    };
+
+    let monitors: Vec<_> = monitors.collect();
+
+    let available: Vec<_> = monitors.iter().map(monitor).collect();
+    let position = geometry.position_on(&available);
+
+    settings.size = geometry.size;
+
+    if let Some(position) = position {
+        settings.position = window::Position::Default;
+
+        return (
+            None,
+            Some(winit::dpi::PhysicalPosition::new(position.x, position.y)),
+        );
+    }
+
+    settings.position = window::Position::Centered;
+
+    let monitor = geometry.monitor.as_ref().and_then(|name| {
+        available
+            .iter()
+            .position(|candidate| candidate.name.as_ref() == Some(name))
+            .map(|index| monitors[index].clone())
+    });
+
+    (monitor, None)
+}
+
+/// Returns the work area of a [`winit`] monitor handle, in physical pixels.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+#[cfg(target_os = "windows")]
+fn monitor_work_area(
+    monitor: &winit::monitor::MonitorHandle,
+) -> Option<Rectangle<i32>> {
+    use winapi::shared::windef::HMONITOR;
+    use winapi::um::winuser::{GetMonitorInfoW, MONITORINFO};
+    use winit::platform::windows::MonitorHandleExtWindows;
+
+    // SAFETY: `MONITORINFO` is plain data, and `GetMonitorInfoW` only
+    // writes to it once `cbSize` is set.
+    let work = unsafe {
+        let mut info: MONITORINFO = std::mem::zeroed();
+        info.cbSize = std::mem::size_of::<MONITORINFO>() as u32;
+
+        if GetMonitorInfoW(monitor.hmonitor() as HMONITOR, &mut info) == 0 {
+            return None;
+        }
+
+        info.rcWork
+    };
+
+    Some(Rectangle {
+        x: work.left,
+        y: work.top,
+        width: work.right - work.left,
+        height: work.bottom - work.top,
+    })
+}
+
+/// Returns the work area of a [`winit`] monitor handle, in physical pixels.
+///
+/// The platform does not report it.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+#[cfg(not(target_os = "windows"))]
+fn monitor_work_area(
+    _monitor: &winit::monitor::MonitorHandle,
+) -> Option<Rectangle<i32>> {
+    None
+}
+
 /// Converts a [`window::Mode`] to a [`winit`] fullscreen mode.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -458,6 +597,9 @@ pub fn mode(mode: Option<winit::window::Fullscreen>) -> window::Mode {
 
 /// Converts a [`mouse::Interaction`] to a [`winit`] cursor icon.
 ///
+/// A [`mouse::Interaction::Custom`] cursor falls back to the default icon;
+/// use [`custom_cursor`] to convert its image.
+///
 /// [`winit`]: https://github.com/rust-windowing/winit
 pub fn mouse_interaction(
     interaction: mouse::Interaction,
@@ -494,9 +636,27 @@ pub fn mouse_interaction(
         Interaction::Hide => {
             return None;
         }
+        Interaction::Custom(_) => winit::window::CursorIcon::Default,
     })
 }
 
+/// Converts a [`mouse::CustomCursor`] to a [`winit`] custom cursor source.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn custom_cursor(
+    cursor: &mouse::CustomCursor,
+) -> Result<winit::window::CustomCursorSource, winit::window::BadImage> {
+    let hotspot = cursor.hotspot();
+
+    winit::window::CustomCursor::from_rgba(
+        cursor.pixels().to_vec(),
+        cursor.width(),
+        cursor.height(),
+        hotspot.x,
+        hotspot.y,
+    )
+}
+
 /// Converts a `MouseButton` from [`winit`] to an [`iced`] mouse button.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
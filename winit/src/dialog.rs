@@ -0,0 +1,163 @@
+//! Show native dialogs.
+#[cfg(all(feature = "xdg-portal", target_os = "linux"))]
+mod portal;
+#[cfg(all(feature = "xdg-portal", target_os = "linux"))]
+mod zenity;
+
+#[cfg(all(feature = "xdg-portal", feature = "wayland", target_os = "linux"))]
+mod foreign;
+
+#[cfg(all(feature = "xdg-portal", target_os = "linux"))]
+pub use portal::Portal;
+
+use crate::runtime::dialog::{Action, Error};
+
+use winit::raw_window_handle::{
+    HasDisplayHandle, HasWindowHandle, RawWindowHandle,
+};
+
+/// Runs the given dialog [`Action`] in the background, parented to the
+/// given window.
+///
+/// The [`Parent`] can be obtained with [`parent`].
+pub(crate) fn run(action: Action, parent: Parent) {
+    #[cfg(all(feature = "xdg-portal", target_os = "linux"))]
+    {
+        let _ = std::thread::spawn(move || match action {
+            Action::Message {
+                dialog, channel, ..
+            } => {
+                let _ =
+                    channel.send(zenity::message(&dialog, parent.x11_window()));
+            }
+            action => match Portal::session() {
+                Ok(portal) => portal.run(action, parent.identifier()),
+                Err(error) => {
+                    log::warn!("XDG desktop portal is unavailable: {error}");
+
+                    fail(action, Error::Unavailable);
+                }
+            },
+        });
+    }
+
+    #[cfg(not(all(feature = "xdg-portal", target_os = "linux")))]
+    {
+        let _ = parent.identifier();
+        let _ = parent.x11_window();
+
+        fail(action, Error::Unavailable);
+    }
+}
+
+/// The parent window of a dialog, as identified to the XDG desktop portal.
+#[derive(Debug, Default)]
+pub(crate) struct Parent {
+    identifier: String,
+    x11_window: Option<u64>,
+    #[cfg(all(
+        feature = "xdg-portal",
+        feature = "wayland",
+        target_os = "linux"
+    ))]
+    _exported: Option<foreign::Exported>,
+}
+
+impl Parent {
+    /// Returns the identifier of the [`Parent`]; empty if the window could
+    /// not be identified.
+    pub fn identifier(&self) -> &str {
+        &self.identifier
+    }
+
+    /// Returns the X11 window id of the [`Parent`], if it is an X11 window.
+    pub fn x11_window(&self) -> Option<u64> {
+        self.x11_window
+    }
+
+    fn x11(window: u64) -> Self {
+        Self {
+            identifier: format!("x11:{window:x}"),
+            x11_window: Some(window),
+            ..Self::default()
+        }
+    }
+}
+
+/// Returns the [`Parent`] of the given dialog [`Action`] shown for the
+/// given window.
+///
+/// Wayland surfaces are exported with `xdg-foreign` for as long as the
+/// [`Parent`] lives. Dialogs of windows that cannot be identified will not
+/// be modal.
+pub(crate) fn parent(
+    action: &Action,
+    window: &(impl HasWindowHandle + HasDisplayHandle),
+) -> Parent {
+    #[cfg(not(all(
+        feature = "xdg-portal",
+        feature = "wayland",
+        target_os = "linux"
+    )))]
+    let _ = action;
+
+    let Ok(handle) = window.window_handle() else {
+        return Parent::default();
+    };
+
+    match handle.as_raw() {
+        RawWindowHandle::Xlib(handle) => Parent::x11(handle.window as u64),
+        RawWindowHandle::Xcb(handle) => {
+            Parent::x11(u64::from(handle.window.get()))
+        }
+        #[cfg(all(
+            feature = "xdg-portal",
+            feature = "wayland",
+            target_os = "linux"
+        ))]
+        // Message dialogs are shown with `zenity`, which can only be attached
+        // to X11 windows; so there is no need to export the surface
+        RawWindowHandle::Wayland(handle)
+            if !matches!(action, Action::Message { .. }) =>
+        {
+            use winit::raw_window_handle::RawDisplayHandle;
+
+            let Ok(RawDisplayHandle::Wayland(display)) =
+                window.display_handle().map(|display| display.as_raw())
+            else {
+                return Parent::default();
+            };
+
+            // SAFETY: Both handles belong to the given window, which is alive
+            let exported = unsafe {
+                foreign::Exported::new(display.display, handle.surface)
+            };
+
+            Parent {
+                identifier: exported
+                    .as_ref()
+                    .map(|exported| format!("wayland:{}", exported.handle()))
+                    .unwrap_or_default(),
+                _exported: exported,
+                ..Parent::default()
+            }
+        }
+        _ => Parent::default(),
+    }
+}
+
+/// Completes the given dialog [`Action`] with an [`Error`].
+fn fail(action: Action, error: Error) {
+    match action {
+        Action::OpenFile { channel, .. } => {
+            let _ = channel.send(Err(error));
+        }
+        Action::SaveFile { channel, .. }
+        | Action::PickFolder { channel, .. } => {
+            let _ = channel.send(Err(error));
+        }
+        Action::Message { channel, .. } => {
+            let _ = channel.send(Err(error));
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use wayland_backend::client::{Backend, ObjectId};
+use wayland_client::globals::{registry_queue_init, GlobalListContents};
+use wayland_client::protocol::wl_registry::{self, WlRegistry};
+use wayland_client::protocol::wl_surface::WlSurface;
+use wayland_client::{Connection, Dispatch, Proxy, QueueHandle};
+use wayland_protocols::xdg::foreign::zv2::client::zxdg_exported_v2::{
+    self, ZxdgExportedV2,
+};
+use wayland_protocols::xdg::foreign::zv2::client::zxdg_exporter_v2::{
+    self, ZxdgExporterV2,
+};
+
+use std::ffi::c_void;
+use std::fmt;
+use std::ptr::NonNull;
+
+/// A Wayland surface exported with the `xdg-foreign` protocol, so other
+/// clients (like the XDG desktop portal) can parent their surfaces to it.
+///
+/// The handle stays valid until the [`Exported`] surface is dropped.
+pub struct Exported {
+    connection: Connection,
+    exported: ZxdgExportedV2,
+    handle: String,
+}
+
+impl Exported {
+    /// Exports the given Wayland surface of the given display.
+    ///
+    /// # Safety
+    /// The pointers must point to a live `wl_display` and a `wl_surface` of
+    /// that display.
+    pub unsafe fn new(
+        display: NonNull<c_void>,
+        surface: NonNull<c_void>,
+    ) -> Option<Self> {
+        let connection = Connection::from_backend(
+            Backend::from_foreign_display(display.as_ptr().cast()),
+        );
+
+        let (globals, mut queue) =
+            registry_queue_init::<State>(&connection).ok()?;
+        let queue_handle = queue.handle();
+
+        let exporter: ZxdgExporterV2 =
+            match globals.bind(&queue_handle, 1..=1, ()) {
+                Ok(exporter) => exporter,
+                Err(error) => {
+                    log::warn!(
+                        "The compositor cannot export surfaces: {error}"
+                    );
+
+                    return None;
+                }
+            };
+
+        let surface =
+            ObjectId::from_ptr(WlSurface::interface(), surface.as_ptr().cast())
+                .ok()?;
+        let surface = WlSurface::from_id(&connection, surface).ok()?;
+
+        let exported = exporter.export_toplevel(&surface, &queue_handle, ());
+        exporter.destroy();
+
+        // The compositor sends the handle right after the request
+        let mut state = State::default();
+        let _ = queue.roundtrip(&mut state).ok()?;
+
+        let Some(handle) = state.handle else {
+            exported.destroy();
+
+            return None;
+        };
+
+        Some(Self {
+            connection,
+            exported,
+            handle,
+        })
+    }
+
+    /// Returns the handle of the [`Exported`] surface.
+    pub fn handle(&self) -> &str {
+        &self.handle
+    }
+}
+
+impl Drop for Exported {
+    fn drop(&mut self) {
+        self.exported.destroy();
+
+        let _ = self.connection.flush();
+    }
+}
+
+impl fmt::Debug for Exported {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Exported")
+            .field("handle", &self.handle)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Default)]
+struct State {
+    handle: Option<String>,
+}
+
+impl Dispatch<WlRegistry, GlobalListContents> for State {
+    fn event(
+        _state: &mut Self,
+        _registry: &WlRegistry,
+        _event: wl_registry::Event,
+        _data: &GlobalListContents,
+        _connection: &Connection,
+        _queue_handle: &QueueHandle<Self>,
+    ) {
+    }
+}
+
+impl Dispatch<ZxdgExporterV2, ()> for State {
+    fn event(
+        _state: &mut Self,
+        _exporter: &ZxdgExporterV2,
+        _event: zxdg_exporter_v2::Event,
+        _data: &(),
+        _connection: &Connection,
+        _queue_handle: &QueueHandle<Self>,
+    ) {
+    }
+}
+
+impl Dispatch<ZxdgExportedV2, ()> for State {
+    fn event(
+        state: &mut Self,
+        _exported: &ZxdgExportedV2,
+        event: zxdg_exported_v2::Event,
+        _data: &(),
+        _connection: &Connection,
+        _queue_handle: &QueueHandle<Self>,
+    ) {
+        if let zxdg_exported_v2::Event::Handle { handle } = event {
+            state.handle = Some(handle);
+        }
+    }
+}
@@ -0,0 +1,442 @@
+use crate::runtime::dialog::{Action, Error, FileDialog};
+
+use zbus::blocking::Connection;
+use zbus::zvariant::{OwnedObjectPath, OwnedValue, Value};
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::atomic::{self, AtomicU64};
+
+const DESTINATION: &str = "org.freedesktop.portal.Desktop";
+const PATH: &str = "/org/freedesktop/portal/desktop";
+
+#[zbus::proxy(
+    interface = "org.freedesktop.portal.FileChooser",
+    default_service = "org.freedesktop.portal.Desktop",
+    default_path = "/org/freedesktop/portal/desktop"
+)]
+trait FileChooser {
+    fn open_file(
+        &self,
+        parent_window: &str,
+        title: &str,
+        options: HashMap<&str, Value<'_>>,
+    ) -> zbus::Result<OwnedObjectPath>;
+
+    fn save_file(
+        &self,
+        parent_window: &str,
+        title: &str,
+        options: HashMap<&str, Value<'_>>,
+    ) -> zbus::Result<OwnedObjectPath>;
+}
+
+#[zbus::proxy(
+    interface = "org.freedesktop.portal.Request",
+    default_service = "org.freedesktop.portal.Desktop"
+)]
+trait Request {
+    #[zbus(signal)]
+    fn response(
+        &self,
+        response: u32,
+        results: HashMap<String, OwnedValue>,
+    ) -> zbus::Result<()>;
+}
+
+/// A client of the `org.freedesktop.portal.FileChooser` D-Bus interface.
+///
+/// Any [`Connection`] and destination can be used; for instance, to talk to
+/// a mock portal service in a private bus.
+#[derive(Debug, Clone)]
+pub struct Portal {
+    connection: Connection,
+    destination: String,
+}
+
+impl Portal {
+    /// Connects to the XDG desktop portal of the session bus.
+    pub fn session() -> zbus::Result<Self> {
+        Ok(Self::new(Connection::session()?, DESTINATION))
+    }
+
+    /// Creates a [`Portal`] talking to the given destination over the given
+    /// [`Connection`].
+    pub fn new(connection: Connection, destination: impl Into<String>) -> Self {
+        Self {
+            connection,
+            destination: destination.into(),
+        }
+    }
+
+    /// Shows a dialog to pick files to open, or a folder if `directory` is
+    /// true.
+    ///
+    /// Returns `None` if the dialog was cancelled.
+    pub fn open_file(
+        &self,
+        parent: &str,
+        dialog: &FileDialog,
+        directory: bool,
+    ) -> Result<Option<Vec<PathBuf>>, Error> {
+        let mut options = options(dialog);
+        let _ = options.insert("multiple", Value::from(dialog.multiple));
+        let _ = options.insert("directory", Value::from(directory));
+
+        self.request(Method::OpenFile, parent, &dialog.title, options)
+    }
+
+    /// Shows a dialog to pick a file to save to.
+    ///
+    /// Returns `None` if the dialog was cancelled.
+    pub fn save_file(
+        &self,
+        parent: &str,
+        dialog: &FileDialog,
+    ) -> Result<Option<PathBuf>, Error> {
+        let mut options = options(dialog);
+
+        if let Some(file_name) = &dialog.file_name {
+            let _ =
+                options.insert("current_name", Value::from(file_name.as_str()));
+        }
+
+        let paths =
+            self.request(Method::SaveFile, parent, &dialog.title, options)?;
+
+        Ok(paths.and_then(|paths| paths.into_iter().next()))
+    }
+
+    pub(super) fn run(&self, action: Action, parent: &str) {
+        match action {
+            Action::OpenFile {
+                dialog, channel, ..
+            } => {
+                let _ = channel.send(
+                    self.open_file(parent, &dialog, false)
+                        .map(Option::unwrap_or_default),
+                );
+            }
+            Action::SaveFile {
+                dialog, channel, ..
+            } => {
+                let _ = channel.send(self.save_file(parent, &dialog));
+            }
+            Action::PickFolder {
+                dialog, channel, ..
+            } => {
+                let _ = channel.send(
+                    self.open_file(
+                        parent,
+                        &FileDialog {
+                            multiple: false,
+                            ..dialog
+                        },
+                        true,
+                    )
+                    .map(|paths| {
+                        paths.and_then(|paths| paths.into_iter().next())
+                    }),
+                );
+            }
+            Action::Message { channel, .. } => {
+                // The XDG desktop portal has no message dialogs
+                let _ = channel.send(Err(Error::Unavailable));
+            }
+        }
+    }
+
+    fn request(
+        &self,
+        method: Method,
+        parent: &str,
+        title: &str,
+        mut options: HashMap<&str, Value<'_>>,
+    ) -> Result<Option<Vec<PathBuf>>, Error> {
+        static NEXT_TOKEN: AtomicU64 = AtomicU64::new(0);
+
+        let token = format!(
+            "iced{}",
+            NEXT_TOKEN.fetch_add(1, atomic::Ordering::Relaxed)
+        );
+
+        // Subscribe to the response before making the request, to avoid
+        // missing it; the handle path is predictable on a message bus.
+        let expected = self.connection.unique_name().map(|name| {
+            format!(
+                "{PATH}/request/{}/{token}",
+                name.trim_start_matches(':').replace('.', "_")
+            )
+        });
+
+        let mut responses = match &expected {
+            Some(path) => Some(self.responses(path).map_err(backend)?),
+            None => None,
+        };
+
+        let _ = options.insert("handle_token", Value::from(token.as_str()));
+
+        let chooser = FileChooserProxyBlocking::builder(&self.connection)
+            .destination(self.destination.as_str())
+            .map_err(backend)?
+            .build()
+            .map_err(backend)?;
+
+        let handle = match method {
+            Method::OpenFile => chooser.open_file(parent, title, options),
+            Method::SaveFile => chooser.save_file(parent, title, options),
+        }
+        .map_err(backend)?;
+
+        if expected.as_deref() != Some(handle.as_str()) {
+            responses = Some(self.responses(handle.as_str()).map_err(backend)?);
+        }
+
+        let response = responses
+            .and_then(|mut responses| responses.next())
+            .ok_or_else(|| backend("the portal request was closed"))?;
+
+        let arguments = response.args().map_err(backend)?;
+
+        match *arguments.response() {
+            0 => uris(arguments.results()).map(Some),
+            1 => Ok(None),
+            _ => Err(backend("the portal interaction was ended")),
+        }
+    }
+
+    fn responses(&self, path: &str) -> zbus::Result<ResponseIterator> {
+        RequestProxyBlocking::builder(&self.connection)
+            .destination(self.destination.clone())?
+            .path(path.to_owned())?
+            .build()?
+            .receive_response()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Method {
+    OpenFile,
+    SaveFile,
+}
+
+fn options(dialog: &FileDialog) -> HashMap<&'static str, Value<'static>> {
+    let mut options = HashMap::new();
+
+    if !dialog.filters.is_empty() {
+        let filters: Vec<(String, Vec<(u32, String)>)> = dialog
+            .filters
+            .iter()
+            .map(|filter| {
+                (
+                    filter.name.clone(),
+                    filter
+                        .patterns
+                        .iter()
+                        .map(|pattern| (0, pattern.clone()))
+                        .collect(),
+                )
+            })
+            .collect();
+
+        let _ = options.insert("filters", Value::from(filters));
+    }
+
+    if let Some(directory) = &dialog.directory {
+        use std::os::unix::ffi::OsStrExt;
+
+        let mut bytes = directory.as_os_str().as_bytes().to_vec();
+        bytes.push(0);
+
+        let _ = options.insert("current_folder", Value::from(bytes));
+    }
+
+    options
+}
+
+fn uris(results: &HashMap<String, OwnedValue>) -> Result<Vec<PathBuf>, Error> {
+    let Some(uris) = results.get("uris") else {
+        return Ok(Vec::new());
+    };
+
+    let uris: Vec<String> = uris
+        .try_clone()
+        .map(Value::from)
+        .and_then(Vec::try_from)
+        .map_err(backend)?;
+
+    Ok(uris
+        .iter()
+        .filter_map(|uri| url::Url::parse(uri).ok()?.to_file_path().ok())
+        .collect())
+}
+
+fn backend(error: impl fmt::Display) -> Error {
+    Error::Backend(error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use zbus::blocking::connection;
+    use zbus::names::BusName;
+
+    use std::os::unix::net::UnixStream;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::Duration;
+
+    /// A mock `org.freedesktop.portal.FileChooser` answering every request
+    /// with the same response.
+    struct Chooser {
+        response: u32,
+        uris: Vec<String>,
+        requests: Arc<Mutex<Vec<(String, String)>>>,
+    }
+
+    #[zbus::interface(name = "org.freedesktop.portal.FileChooser")]
+    impl Chooser {
+        fn open_file(
+            &self,
+            #[zbus(connection)] connection: &zbus::Connection,
+            parent_window: String,
+            title: String,
+            _options: HashMap<String, OwnedValue>,
+        ) -> OwnedObjectPath {
+            self.respond(connection, parent_window, title)
+        }
+
+        fn save_file(
+            &self,
+            #[zbus(connection)] connection: &zbus::Connection,
+            parent_window: String,
+            title: String,
+            _options: HashMap<String, OwnedValue>,
+        ) -> OwnedObjectPath {
+            self.respond(connection, parent_window, title)
+        }
+    }
+
+    impl Chooser {
+        fn respond(
+            &self,
+            connection: &zbus::Connection,
+            parent_window: String,
+            title: String,
+        ) -> OwnedObjectPath {
+            let mut requests = self.requests.lock().expect("Lock requests");
+            requests.push((parent_window, title));
+
+            let path = OwnedObjectPath::try_from(format!(
+                "{PATH}/request/mock/{}",
+                requests.len()
+            ))
+            .expect("Valid request path");
+
+            let connection = Connection::from(connection.clone());
+            let response = self.response;
+            let uris = self.uris.clone();
+            let signal = path.clone();
+
+            // Give the client time to subscribe to the response, since a
+            // peer-to-peer connection cannot predict the request path
+            let _ = thread::spawn(move || {
+                thread::sleep(Duration::from_millis(100));
+
+                let mut results = HashMap::new();
+                let _ = results.insert("uris", Value::from(uris));
+
+                connection
+                    .emit_signal(
+                        None::<BusName<'_>>,
+                        &signal,
+                        "org.freedesktop.portal.Request",
+                        "Response",
+                        &(response, results),
+                    )
+                    .expect("Emit response");
+            });
+
+            path
+        }
+    }
+
+    fn portal(
+        response: u32,
+        uris: &[&str],
+    ) -> (Portal, Arc<Mutex<Vec<(String, String)>>>, Connection) {
+        let (server, client) = UnixStream::pair().expect("Create socket pair");
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let chooser = Chooser {
+            response,
+            uris: uris.iter().map(ToString::to_string).collect(),
+            requests: Arc::clone(&requests),
+        };
+
+        let server = thread::spawn(move || {
+            connection::Builder::unix_stream(server)
+                .server(zbus::Guid::generate())
+                .expect("Create server")
+                .p2p()
+                .serve_at(PATH, chooser)
+                .expect("Serve mock portal")
+                .build()
+                .expect("Connect server")
+        });
+
+        let client = connection::Builder::unix_stream(client)
+            .p2p()
+            .build()
+            .expect("Connect client");
+
+        let server = server.join().expect("Join server");
+
+        (Portal::new(client, DESTINATION), requests, server)
+    }
+
+    #[test]
+    fn open_file_returns_picked_paths() {
+        let (portal, requests, _server) =
+            portal(0, &["file:///tmp/a.txt", "file:///tmp/b%20c.txt"]);
+
+        let paths = portal
+            .open_file("wayland:handle", &FileDialog::new("Open"), false)
+            .expect("Open file");
+
+        assert_eq!(
+            paths,
+            Some(vec![
+                PathBuf::from("/tmp/a.txt"),
+                PathBuf::from("/tmp/b c.txt")
+            ])
+        );
+
+        assert_eq!(
+            *requests.lock().expect("Lock requests"),
+            [(String::from("wayland:handle"), String::from("Open"))]
+        );
+    }
+
+    #[test]
+    fn cancelled_dialog_returns_none() {
+        let (portal, _requests, _server) = portal(1, &[]);
+
+        let path = portal
+            .save_file("", &FileDialog::new("Save").file_name("a.txt"))
+            .expect("Save file");
+
+        assert_eq!(path, None);
+    }
+
+    #[test]
+    fn ended_interaction_is_an_error() {
+        let (portal, _requests, _server) = portal(2, &[]);
+
+        let result = portal.open_file("", &FileDialog::new("Open"), true);
+
+        assert!(matches!(result, Err(Error::Backend(_))));
+    }
+}
@@ -0,0 +1,104 @@
+use crate::runtime::dialog::{Buttons, Error, Level, MessageDialog};
+
+use std::io;
+use std::process::{Command, Stdio};
+
+/// Shows a [`MessageDialog`] with `zenity`, since the XDG desktop portal has
+/// no message dialogs.
+///
+/// The dialog is modal to the given X11 window, if any.
+///
+/// Returns [`Error::Unavailable`] if `zenity` is not installed.
+pub fn message(
+    dialog: &MessageDialog,
+    parent: Option<u64>,
+) -> Result<bool, Error> {
+    let status = Command::new("zenity")
+        .args(arguments(dialog, parent))
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .status()
+        .map_err(|error| match error.kind() {
+            io::ErrorKind::NotFound => Error::Unavailable,
+            _ => Error::Backend(error.to_string()),
+        })?;
+
+    // `zenity` exits with 1 when cancelled and with 5 on timeout
+    match status.code() {
+        Some(0) => Ok(true),
+        Some(1 | 5) => Ok(false),
+        _ => Err(Error::Backend(format!("zenity failed with {status}"))),
+    }
+}
+
+fn arguments(dialog: &MessageDialog, parent: Option<u64>) -> Vec<String> {
+    let kind = match (dialog.buttons, dialog.level) {
+        (Buttons::Ok, Level::Info) => "--info",
+        (Buttons::Ok, Level::Warning) => "--warning",
+        (Buttons::Ok, Level::Error) => "--error",
+        (Buttons::OkCancel | Buttons::YesNo, _) => "--question",
+    };
+
+    let mut arguments = vec![kind.to_owned()];
+
+    if let Some(parent) = parent {
+        arguments.push("--modal".to_owned());
+        arguments.push(format!("--attach={parent}"));
+    }
+
+    // `--text` is Pango markup unless disabled
+    arguments.extend([
+        "--no-markup".to_owned(),
+        format!("--title={}", dialog.title),
+        format!("--text={}", dialog.description),
+    ]);
+
+    match dialog.buttons {
+        Buttons::Ok => {}
+        Buttons::OkCancel => {
+            arguments.push("--ok-label=Ok".to_owned());
+            arguments.push("--cancel-label=Cancel".to_owned());
+        }
+        Buttons::YesNo => {
+            arguments.push("--ok-label=Yes".to_owned());
+            arguments.push("--cancel-label=No".to_owned());
+        }
+    }
+
+    arguments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arguments_follow_level_and_buttons() {
+        let dialog = MessageDialog::new("Title", "<Description> & more")
+            .level(Level::Warning);
+
+        assert_eq!(
+            arguments(&dialog, None),
+            [
+                "--warning",
+                "--no-markup",
+                "--title=Title",
+                "--text=<Description> & more"
+            ]
+        );
+
+        assert_eq!(
+            arguments(&dialog.buttons(Buttons::YesNo), Some(42)),
+            [
+                "--question",
+                "--modal",
+                "--attach=42",
+                "--no-markup",
+                "--title=Title",
+                "--text=<Description> & more",
+                "--ok-label=Yes",
+                "--cancel-label=No",
+            ]
+        );
+    }
+}
@@ -0,0 +1,372 @@
+//! Run a single instance of an application.
+//!
+//! The primary instance listens on a Unix domain socket named after the
+//! identifier of the application, inside a directory only accessible by the
+//! current user. Any later instance forwards its command-line arguments
+//! through the socket and exits.
+use crate::futures::Subscription;
+
+use std::io;
+
+/// The role of the current process, as decided by [`acquire`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instance {
+    /// The current process is the primary instance of the application.
+    ///
+    /// The arguments forwarded by other instances are produced by [`listen`].
+    Primary,
+    /// Another instance of the application is running and the arguments
+    /// were forwarded to it.
+    Forwarded,
+}
+
+/// Acquires the single instance of the application with the given
+/// identifier, or forwards the given arguments to it if it is already
+/// running.
+pub fn acquire(
+    id: &str,
+    arguments: impl IntoIterator<Item = String>,
+) -> io::Result<Instance> {
+    #[cfg(unix)]
+    {
+        unix::acquire(id, arguments)
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = (id, arguments.into_iter());
+
+        log::warn!("Single-instance mode is only supported on Unix");
+
+        Ok(Instance::Primary)
+    }
+}
+
+/// Listens to the arguments forwarded to the primary instance of the
+/// application.
+///
+/// Arguments forwarded while the subscription is not active are kept until
+/// it starts again.
+pub fn listen() -> Subscription<Vec<String>> {
+    #[cfg(unix)]
+    {
+        Subscription::run(unix::arguments)
+    }
+
+    #[cfg(not(unix))]
+    {
+        Subscription::none()
+    }
+}
+
+#[cfg(unix)]
+mod unix {
+    use super::Instance;
+
+    use crate::futures::futures::channel::mpsc;
+    use crate::futures::futures::{SinkExt, Stream, StreamExt};
+    use crate::futures::stream;
+
+    use std::fs;
+    use std::io::{self, Read, Write};
+    use std::os::fd::AsRawFd;
+    use std::os::unix::fs::{DirBuilderExt, MetadataExt};
+    use std::os::unix::net::{UnixListener, UnixStream};
+    use std::path::{Path, PathBuf};
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    /// The arguments received by the primary instance, waiting for a
+    /// subscriber to deliver them to.
+    static RECEIVER: Mutex<Receiver> = Mutex::new(Receiver {
+        subscriber: None,
+        pending: Vec::new(),
+    });
+
+    struct Receiver {
+        subscriber: Option<mpsc::UnboundedSender<Vec<String>>>,
+        pending: Vec<Vec<String>>,
+    }
+
+    impl Receiver {
+        fn deliver(&mut self, arguments: Vec<String>) {
+            let arguments = match &self.subscriber {
+                Some(subscriber) => {
+                    match subscriber.unbounded_send(arguments) {
+                        Ok(()) => return,
+                        Err(error) => error.into_inner(),
+                    }
+                }
+                None => arguments,
+            };
+
+            // The subscription is gone; keep the arguments for the next one
+            self.subscriber = None;
+            self.pending.push(arguments);
+        }
+    }
+
+    pub fn acquire(
+        id: &str,
+        arguments: impl IntoIterator<Item = String>,
+    ) -> io::Result<Instance> {
+        acquire_at(&socket(id)?, arguments)
+    }
+
+    fn acquire_at(
+        path: &Path,
+        arguments: impl IntoIterator<Item = String>,
+    ) -> io::Result<Instance> {
+        let message = encode(arguments);
+
+        // Instances starting at the same time are serialized, so only one of
+        // them can ever bind the socket
+        let _lock = lock(path)?;
+
+        match forward(path, &message) {
+            Ok(()) => return Ok(Instance::Forwarded),
+            Err(error)
+                if matches!(
+                    error.kind(),
+                    io::ErrorKind::NotFound | io::ErrorKind::ConnectionRefused
+                ) => {}
+            Err(error) => return Err(error),
+        }
+
+        // Nobody is listening; the socket is a leftover of a previous
+        // instance that crashed
+        let _ = fs::remove_file(path);
+
+        let listener = UnixListener::bind(path)?;
+
+        let _ = std::thread::Builder::new()
+            .name(String::from("iced instance"))
+            .spawn(move || accept(listener))?;
+
+        Ok(Instance::Primary)
+    }
+
+    pub fn arguments() -> impl Stream<Item = Vec<String>> {
+        stream::channel(10, |mut output| async move {
+            let (sender, mut receiver) = mpsc::unbounded();
+
+            {
+                let mut state =
+                    RECEIVER.lock().expect("Lock instance receiver");
+
+                for arguments in state.pending.drain(..) {
+                    let _ = sender.unbounded_send(arguments);
+                }
+
+                state.subscriber = Some(sender);
+            }
+
+            while let Some(arguments) = receiver.next().await {
+                if output.send(arguments).await.is_err() {
+                    break;
+                }
+            }
+        })
+    }
+
+    /// Accepts the connections of other instances for as long as the
+    /// primary instance runs.
+    fn accept(listener: UnixListener) {
+        for connection in listener.incoming() {
+            let mut message = Vec::new();
+
+            let result = connection.and_then(|mut connection| {
+                connection.set_read_timeout(Some(Duration::from_secs(1)))?;
+                connection.read_to_end(&mut message)
+            });
+
+            if let Err(error) = result {
+                log::warn!("Failed to receive forwarded arguments: {error}");
+                continue;
+            }
+
+            RECEIVER
+                .lock()
+                .expect("Lock instance receiver")
+                .deliver(decode(&message));
+        }
+    }
+
+    fn forward(path: &Path, message: &[u8]) -> io::Result<()> {
+        let mut connection = UnixStream::connect(path)?;
+        connection.write_all(message)?;
+        connection.shutdown(std::net::Shutdown::Write)
+    }
+
+    /// Locks the file next to the socket at the given path, until the
+    /// returned [`fs::File`] is dropped.
+    fn lock(path: &Path) -> io::Result<fs::File> {
+        let file = fs::OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(path.with_extension("lock"))?;
+
+        // SAFETY: The file descriptor is valid for as long as `file` lives
+        if unsafe { libc::flock(file.as_raw_fd(), libc::LOCK_EX) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(file)
+    }
+
+    fn socket(id: &str) -> io::Result<PathBuf> {
+        let name = id.replace(['/', '\\'], "_");
+
+        let directory = match std::env::var_os("XDG_RUNTIME_DIR") {
+            Some(directory) => PathBuf::from(directory),
+            None => {
+                // SAFETY: `getuid` is always successful
+                let uid = unsafe { libc::getuid() };
+
+                // The temporary directory is shared with other users, who
+                // must not be able to bind the socket first
+                let directory =
+                    std::env::temp_dir().join(format!("iced-instance-{uid}"));
+
+                private_directory(&directory, uid)?;
+
+                directory
+            }
+        };
+
+        Ok(directory.join(format!("{name}.instance")))
+    }
+
+    /// Creates a directory only accessible by the user with the given id, or
+    /// checks that an existing one is.
+    fn private_directory(path: &Path, uid: libc::uid_t) -> io::Result<()> {
+        match fs::DirBuilder::new().mode(0o700).create(path) {
+            Ok(()) => {}
+            Err(error) if error.kind() == io::ErrorKind::AlreadyExists => {}
+            Err(error) => return Err(error),
+        }
+
+        let metadata = fs::symlink_metadata(path)?;
+
+        if !metadata.is_dir()
+            || metadata.uid() != uid
+            || metadata.mode() & 0o077 != 0
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                format!("{} is not a private directory", path.display()),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Encodes the arguments as a sequence of nul-terminated strings.
+    fn encode(arguments: impl IntoIterator<Item = String>) -> Vec<u8> {
+        let mut message = Vec::new();
+
+        for argument in arguments {
+            message.extend_from_slice(argument.as_bytes());
+            message.push(0);
+        }
+
+        message
+    }
+
+    fn decode(message: &[u8]) -> Vec<String> {
+        let mut arguments: Vec<String> = message
+            .split(|byte| *byte == 0)
+            .map(|argument| String::from_utf8_lossy(argument).into_owned())
+            .collect();
+
+        // Every argument is terminated, so the last split is always empty
+        let _ = arguments.pop();
+
+        arguments
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        use crate::futures::futures::executor;
+
+        #[test]
+        fn arguments_roundtrip() {
+            let arguments =
+                vec![String::from("--new-window"), String::new(), "ñ".into()];
+
+            assert_eq!(decode(&encode(arguments.clone())), arguments);
+            assert_eq!(decode(&encode(Vec::new())), Vec::<String>::new());
+        }
+
+        #[test]
+        fn arguments_are_forwarded_to_primary() {
+            let directory = std::env::temp_dir()
+                .join(format!("iced-instance-{}", std::process::id()));
+            fs::create_dir_all(&directory).expect("Create directory");
+
+            let path = directory.join("test.instance");
+
+            let primary = acquire_at(&path, vec![String::from("primary")])
+                .expect("Acquire primary instance");
+
+            assert_eq!(primary, Instance::Primary);
+
+            // Forwarded before anyone listens, so it must be kept
+            let forwarded =
+                acquire_at(&path, vec![String::from("a"), String::from("b")])
+                    .expect("Forward arguments");
+
+            assert_eq!(forwarded, Instance::Forwarded);
+
+            let first = executor::block_on(arguments().next());
+            assert_eq!(first, Some(vec![String::from("a"), String::from("b")]));
+
+            // A restarted subscription keeps receiving arguments
+            let forwarded = acquire_at(&path, vec![String::from("c")])
+                .expect("Forward arguments");
+
+            assert_eq!(forwarded, Instance::Forwarded);
+
+            let second = executor::block_on(arguments().next());
+            assert_eq!(second, Some(vec![String::from("c")]));
+
+            let _ = fs::remove_dir_all(&directory);
+        }
+
+        #[test]
+        fn only_private_directories_are_used() {
+            use std::os::unix::fs::PermissionsExt;
+
+            // SAFETY: `getuid` is always successful
+            let uid = unsafe { libc::getuid() };
+
+            let directory = std::env::temp_dir()
+                .join(format!("iced-instance-private-{}", std::process::id()));
+
+            private_directory(&directory, uid).expect("Create directory");
+
+            let mode = fs::metadata(&directory)
+                .expect("Read directory metadata")
+                .mode();
+
+            assert_eq!(mode & 0o777, 0o700);
+
+            // An existing private directory is reused
+            private_directory(&directory, uid).expect("Reuse directory");
+
+            fs::set_permissions(&directory, fs::Permissions::from_mode(0o777))
+                .expect("Share directory");
+
+            assert_eq!(
+                private_directory(&directory, uid)
+                    .map_err(|error| error.kind()),
+                Err(io::ErrorKind::PermissionDenied)
+            );
+
+            let _ = fs::remove_dir_all(&directory);
+        }
+    }
+}
@@ -28,8 +28,12 @@ pub use winit;
 pub mod a11y;
 pub mod clipboard;
 pub mod conversion;
+pub mod dialog;
+pub mod instance;
+pub mod notification;
 pub mod platform_specific;
 pub mod settings;
+pub mod tray;
 
 #[cfg(feature = "program")]
 pub mod program;
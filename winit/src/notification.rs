@@ -0,0 +1,81 @@
+//! Show desktop notifications.
+#[cfg(all(feature = "notifications", target_os = "linux"))]
+mod daemon;
+
+#[cfg(all(feature = "notifications", target_os = "linux"))]
+pub use daemon::Daemon;
+
+use crate::futures::Subscription;
+use crate::runtime::notification::{Action, Error, Event};
+
+/// Listens to the [`Event`]s of the notifications shown by the application.
+pub fn listen() -> Subscription<Event> {
+    #[cfg(all(feature = "notifications", target_os = "linux"))]
+    {
+        Subscription::run(daemon::events)
+    }
+
+    #[cfg(not(all(feature = "notifications", target_os = "linux")))]
+    {
+        Subscription::none()
+    }
+}
+
+/// Runs the given notification [`Action`] in the background.
+///
+/// Actions run in order in a single thread, through the shared daemon of
+/// the session bus.
+pub(crate) fn run(action: Action) {
+    #[cfg(all(feature = "notifications", target_os = "linux"))]
+    {
+        use std::sync::mpsc;
+        use std::sync::Mutex;
+
+        static WORKER: Mutex<Option<mpsc::Sender<Action>>> = Mutex::new(None);
+
+        let mut worker = WORKER.lock().expect("Lock notification worker");
+
+        let sender = worker.get_or_insert_with(|| {
+            let (sender, actions) = mpsc::channel();
+
+            let _ = std::thread::Builder::new()
+                .name(String::from("iced notifications"))
+                .spawn(move || {
+                    for action in actions {
+                        match Daemon::shared() {
+                            Ok(daemon) => daemon.run(action),
+                            Err(error) => {
+                                log::warn!(
+                                    "Notification daemon is unavailable: \
+                                    {error}"
+                                );
+
+                                fail(action, Error::Unavailable);
+                            }
+                        }
+                    }
+                });
+
+            sender
+        });
+
+        if let Err(mpsc::SendError(action)) = sender.send(action) {
+            fail(action, Error::Unavailable);
+        }
+    }
+
+    #[cfg(not(all(feature = "notifications", target_os = "linux")))]
+    {
+        fail(action, Error::Unavailable);
+    }
+}
+
+/// Completes the given notification [`Action`] with an [`Error`].
+fn fail(action: Action, error: Error) {
+    match action {
+        Action::Show { channel, .. } => {
+            let _ = channel.send(Err(error));
+        }
+        Action::Close(_) => {}
+    }
+}
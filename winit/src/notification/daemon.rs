@@ -0,0 +1,442 @@
+use crate::futures::futures::channel::mpsc;
+use crate::futures::futures::future;
+use crate::futures::futures::stream::{Stream, StreamExt};
+use crate::futures::futures::SinkExt;
+use crate::runtime::notification::{
+    Action, Error, Event, Id, Notification, Reason, Urgency,
+};
+
+use zbus::blocking::Connection;
+use zbus::zvariant::Value;
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+const DESTINATION: &str = "org.freedesktop.Notifications";
+const INTERFACE: &str = "org.freedesktop.Notifications";
+const PATH: &str = "/org/freedesktop/Notifications";
+
+/// The [`Daemon`] of the session bus, shared by the whole application.
+static SESSION: Mutex<Option<Daemon>> = Mutex::new(None);
+
+#[zbus::proxy(
+    interface = "org.freedesktop.Notifications",
+    default_service = "org.freedesktop.Notifications",
+    default_path = "/org/freedesktop/Notifications"
+)]
+trait Notifications {
+    fn notify(
+        &self,
+        app_name: &str,
+        replaces_id: u32,
+        app_icon: &str,
+        summary: &str,
+        body: &str,
+        actions: &[&str],
+        hints: HashMap<&str, Value<'_>>,
+        expire_timeout: i32,
+    ) -> zbus::Result<u32>;
+
+    fn close_notification(&self, id: u32) -> zbus::Result<()>;
+}
+
+/// A client of the `org.freedesktop.Notifications` D-Bus interface.
+///
+/// Any [`Connection`] and destination can be used; for instance, to talk to
+/// a mock notification daemon in a private bus.
+///
+/// Clones of a [`Daemon`] share its [`Connection`], as well as the
+/// notifications shown through it.
+#[derive(Debug, Clone)]
+pub struct Daemon {
+    connection: Connection,
+    destination: String,
+    shown: Arc<Mutex<Vec<Id>>>,
+}
+
+impl Daemon {
+    /// Connects to the notification daemon of the session bus.
+    pub fn session() -> zbus::Result<Self> {
+        Ok(Self::new(Connection::session()?, DESTINATION))
+    }
+
+    /// Returns the [`Daemon`] of the session bus shared by the whole
+    /// application, connecting to it if needed.
+    pub(super) fn shared() -> zbus::Result<Self> {
+        let mut session = SESSION.lock().expect("Lock session daemon");
+
+        if let Some(daemon) = session.as_ref() {
+            return Ok(daemon.clone());
+        }
+
+        let daemon = Self::session()?;
+        *session = Some(daemon.clone());
+
+        Ok(daemon)
+    }
+
+    /// Creates a [`Daemon`] talking to the given destination over the given
+    /// [`Connection`].
+    pub fn new(connection: Connection, destination: impl Into<String>) -> Self {
+        Self {
+            connection,
+            destination: destination.into(),
+            shown: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Shows the given [`Notification`], returning its [`Id`].
+    pub fn notify(&self, notification: &Notification) -> Result<Id, Error> {
+        let actions: Vec<&str> = notification
+            .actions
+            .iter()
+            .flat_map(|(key, label)| [key.as_str(), label.as_str()])
+            .collect();
+
+        let urgency: u8 = match notification.urgency {
+            Urgency::Low => 0,
+            Urgency::Normal => 1,
+            Urgency::Critical => 2,
+        };
+
+        let hints = HashMap::from([("urgency", Value::from(urgency))]);
+
+        let timeout = notification.timeout.map_or(-1, |timeout| {
+            i32::try_from(timeout.as_millis()).unwrap_or(i32::MAX)
+        });
+
+        let id = self
+            .proxy()
+            .and_then(|proxy| {
+                proxy.notify(
+                    &application_name(),
+                    0,
+                    notification.icon.as_deref().unwrap_or_default(),
+                    &notification.title,
+                    &notification.body,
+                    &actions,
+                    hints,
+                    timeout,
+                )
+            })
+            .map(Id)
+            .map_err(backend)?;
+
+        self.shown
+            .lock()
+            .expect("Lock shown notifications")
+            .push(id);
+
+        Ok(id)
+    }
+
+    /// Closes the notification with the given [`Id`].
+    pub fn close(&self, id: Id) -> Result<(), Error> {
+        self.proxy()
+            .and_then(|proxy| proxy.close_notification(id.0))
+            .map_err(backend)
+    }
+
+    pub(super) fn run(&self, action: Action) {
+        match action {
+            Action::Show {
+                notification,
+                channel,
+            } => {
+                let _ = channel.send(self.notify(&notification));
+            }
+            Action::Close(id) => {
+                if let Err(error) = self.close(id) {
+                    log::warn!("Failed to close notification: {error:?}");
+                }
+            }
+        }
+    }
+
+    /// Produces the [`Event`]s of the notifications shown through the
+    /// [`Daemon`].
+    pub fn events(&self) -> impl Stream<Item = Event> {
+        let daemon = self.clone();
+
+        crate::futures::stream::channel(100, |output| async move {
+            daemon.forward(output).await;
+        })
+    }
+
+    async fn forward(&self, mut output: mpsc::Sender<Event>) {
+        let events = match self.signals().await {
+            Ok(events) => events,
+            Err(error) => {
+                log::warn!("Notification daemon is unavailable: {error}");
+                return;
+            }
+        };
+
+        let mut events = std::pin::pin!(events);
+
+        while let Some(event) = events.next().await {
+            if output.send(event).await.is_err() {
+                break;
+            }
+        }
+    }
+
+    fn proxy(&self) -> zbus::Result<NotificationsProxyBlocking<'_>> {
+        NotificationsProxyBlocking::builder(&self.connection)
+            .destination(self.destination.as_str())?
+            .build()
+    }
+
+    async fn signals(&self) -> zbus::Result<impl Stream<Item = Event>> {
+        // Signals are matched by interface instead of by sender, so
+        // peer-to-peer connections (which have no senders) work as well
+        let rule = zbus::MatchRule::builder()
+            .msg_type(zbus::message::Type::Signal)
+            .interface(INTERFACE)?
+            .path(PATH)?
+            .build();
+
+        let messages = zbus::MessageStream::for_match_rule(
+            rule,
+            self.connection.inner(),
+            None,
+        )
+        .await?;
+
+        let shown = Arc::clone(&self.shown);
+
+        Ok(messages.filter_map(move |message| {
+            future::ready(
+                message
+                    .ok()
+                    .and_then(|message| event(&message))
+                    .filter(|event| forget_closed(&shown, event)),
+            )
+        }))
+    }
+}
+
+/// Produces the [`Event`]s of the notifications shown by the application.
+pub(super) fn events() -> impl Stream<Item = Event> {
+    crate::futures::stream::channel(100, |output| async move {
+        match Daemon::shared() {
+            Ok(daemon) => daemon.forward(output).await,
+            Err(error) => {
+                log::warn!("Notification daemon is unavailable: {error}");
+            }
+        }
+    })
+}
+
+/// Returns true if the [`Event`] belongs to a notification that is shown,
+/// forgetting the notification once it is closed.
+fn forget_closed(shown: &Mutex<Vec<Id>>, event: &Event) -> bool {
+    let mut shown = shown.lock().expect("Lock shown notifications");
+
+    let index = shown.iter().position(|id| *id == event.id());
+
+    if let (Some(index), Event::Closed { .. }) = (index, event) {
+        let _ = shown.remove(index);
+    }
+
+    index.is_some()
+}
+
+/// Decodes the [`Event`] of a signal of the notification daemon.
+fn event(message: &zbus::Message) -> Option<Event> {
+    let header = message.header();
+    let body = message.body();
+
+    match header.member()?.as_str() {
+        "ActionInvoked" => {
+            let (id, action): (u32, String) = body.deserialize().ok()?;
+
+            Some(Event::ActionInvoked { id: Id(id), action })
+        }
+        "NotificationClosed" => {
+            let (id, reason): (u32, u32) = body.deserialize().ok()?;
+
+            Some(Event::Closed {
+                id: Id(id),
+                reason: match reason {
+                    1 => Reason::Expired,
+                    2 => Reason::Dismissed,
+                    3 => Reason::Closed,
+                    _ => Reason::Unknown,
+                },
+            })
+        }
+        _ => None,
+    }
+}
+
+fn application_name() -> String {
+    std::env::current_exe()
+        .ok()
+        .and_then(|path| Some(path.file_stem()?.to_string_lossy().into_owned()))
+        .unwrap_or_default()
+}
+
+fn backend(error: impl fmt::Display) -> Error {
+    Error::Backend(error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::futures::futures::executor::block_on;
+
+    use zbus::blocking::connection;
+    use zbus::names::BusName;
+    use zbus::object_server::SignalContext;
+    use zbus::zvariant::OwnedValue;
+
+    use std::os::unix::net::UnixStream;
+    use std::thread;
+
+    /// A mock notification daemon, recording the title and the actions of
+    /// every notification.
+    #[derive(Default)]
+    struct Mock {
+        shown: Arc<Mutex<Vec<(String, Vec<String>)>>>,
+    }
+
+    #[zbus::interface(name = "org.freedesktop.Notifications")]
+    impl Mock {
+        #[allow(clippy::too_many_arguments)]
+        fn notify(
+            &self,
+            _app_name: String,
+            _replaces_id: u32,
+            _app_icon: String,
+            summary: String,
+            _body: String,
+            actions: Vec<String>,
+            _hints: HashMap<String, OwnedValue>,
+            _expire_timeout: i32,
+        ) -> u32 {
+            let mut shown = self.shown.lock().expect("Lock shown");
+            shown.push((summary, actions));
+
+            shown.len() as u32
+        }
+
+        async fn close_notification(
+            &self,
+            #[zbus(signal_context)] context: SignalContext<'_>,
+            id: u32,
+        ) -> zbus::fdo::Result<()> {
+            Self::notification_closed(&context, id, 3).await?;
+
+            Ok(())
+        }
+
+        #[zbus(signal)]
+        async fn notification_closed(
+            context: &SignalContext<'_>,
+            id: u32,
+            reason: u32,
+        ) -> zbus::Result<()>;
+    }
+
+    fn daemon() -> (Daemon, Connection, Arc<Mutex<Vec<(String, Vec<String>)>>>)
+    {
+        let (server, client) = UnixStream::pair().expect("Create socket pair");
+
+        let mock = Mock::default();
+        let shown = Arc::clone(&mock.shown);
+
+        let server = thread::spawn(move || {
+            connection::Builder::unix_stream(server)
+                .server(zbus::Guid::generate())
+                .expect("Create server")
+                .p2p()
+                .serve_at(PATH, mock)
+                .expect("Serve mock daemon")
+                .build()
+                .expect("Connect server")
+        });
+
+        let client = connection::Builder::unix_stream(client)
+            .p2p()
+            .build()
+            .expect("Connect client");
+
+        let server = server.join().expect("Join server");
+
+        (Daemon::new(client, DESTINATION), server, shown)
+    }
+
+    fn invoke(server: &Connection, id: u32, action: &str) {
+        server
+            .emit_signal(
+                None::<BusName<'_>>,
+                PATH,
+                INTERFACE,
+                "ActionInvoked",
+                &(id, action),
+            )
+            .expect("Invoke action");
+    }
+
+    #[test]
+    fn events_of_shown_notifications_are_produced() {
+        let (daemon, server, shown) = daemon();
+
+        let events = block_on(daemon.signals()).expect("Listen to signals");
+        let mut events = std::pin::pin!(events);
+
+        let id = daemon
+            .notify(&Notification::new("Hello").action("open", "Open"))
+            .expect("Show notification");
+
+        assert_eq!(id, Id(1));
+        assert_eq!(
+            *shown.lock().expect("Lock shown"),
+            [(
+                String::from("Hello"),
+                vec![String::from("open"), String::from("Open")]
+            )]
+        );
+
+        // Notifications of other applications are ignored
+        invoke(&server, 42, "other");
+        invoke(&server, 1, "open");
+
+        daemon.close(id).expect("Close notification");
+
+        assert_eq!(
+            block_on(events.next()),
+            Some(Event::ActionInvoked {
+                id,
+                action: String::from("open"),
+            })
+        );
+        assert_eq!(
+            block_on(events.next()),
+            Some(Event::Closed {
+                id,
+                reason: Reason::Closed,
+            })
+        );
+
+        // Closed notifications are forgotten
+        invoke(&server, 1, "late");
+
+        let id = daemon
+            .notify(&Notification::new("Again"))
+            .expect("Show notification");
+
+        invoke(&server, id.0, "open");
+
+        assert_eq!(
+            block_on(events.next()),
+            Some(Event::ActionInvoked {
+                id: Id(2),
+                action: String::from("open"),
+            })
+        );
+    }
+}
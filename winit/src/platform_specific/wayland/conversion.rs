@@ -1,4 +1,5 @@
 use cctk::sctk::{
+    output::OutputInfo,
     reexports::client::protocol::wl_pointer::AxisSource,
     seat::{
         keyboard::Modifiers,
@@ -10,6 +11,7 @@ use cctk::sctk::{
 use iced_runtime::core::{
     keyboard,
     mouse::{self, ScrollDelta},
+    window, Point, Rectangle, Size,
 };
 
 /// An error that occurred while running an application.
@@ -73,3 +75,26 @@ pub fn modifiers_to_native(mods: Modifiers) -> keyboard::Modifiers {
     // }
     native_mods
 }
+
+pub fn output_info_to_monitor(info: &OutputInfo) -> window::Monitor {
+    let mode = info.modes.iter().find(|mode| mode.current);
+    let (width, height) = mode.map_or((0, 0), |mode| mode.dimensions);
+
+    // Wayland does not expose the area reserved by panels to clients
+    let work_area = Rectangle {
+        x: info.location.0,
+        y: info.location.1,
+        width: width.max(0),
+        height: height.max(0),
+    };
+
+    window::Monitor {
+        name: info.name.clone(),
+        position: Point::new(info.location.0, info.location.1),
+        size: Size::new(width.max(0) as u32, height.max(0) as u32),
+        work_area,
+        scale_factor: f64::from(info.scale_factor),
+        refresh_rate: mode
+            .and_then(|mode| u32::try_from(mode.refresh_rate).ok()),
+    }
+}
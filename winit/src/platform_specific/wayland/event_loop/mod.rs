@@ -52,6 +52,8 @@ use tracing::error;
 use wayland_backend::client::Backend;
 use wayland_client::globals::GlobalError;
 use wayland_protocols::wp::keyboard_shortcuts_inhibit::zv1::client::zwp_keyboard_shortcuts_inhibit_manager_v1;
+use wayland_protocols::wp::pointer_constraints::zv1::client::zwp_pointer_constraints_v1::ZwpPointerConstraintsV1;
+use wayland_protocols::wp::relative_pointer::zv1::client::zwp_relative_pointer_manager_v1::ZwpRelativePointerManagerV1;
 use winit::{dpi::LogicalSize, event_loop::OwnedDisplayHandle};
 
 use self::state::SctkState;
@@ -178,9 +180,33 @@ impl SctkEventLoop {
                             ) => {
                                 if let Some(seat) = state.seats.get_mut(0) {
                                     seat.icon = Some(icon);
-                                    seat.set_cursor(&state.connection, icon);
+                                    seat.custom_cursor = None;
+                                    if !seat.cursor_hidden {
+                                        seat.set_cursor(&state.connection, icon);
+                                    }
                                 }
                             }
+                            crate::platform_specific::Action::SetCustomCursor(
+                                cursor,
+                            ) => {
+                                state.set_custom_cursor(cursor);
+                            }
+                            crate::platform_specific::Action::SetCursorVisible(
+                                visible,
+                            ) => {
+                                if let Some(seat) = state.seats.get_mut(0) {
+                                    seat.set_cursor_visible(
+                                        &state.connection,
+                                        visible,
+                                    );
+                                }
+                            }
+                            crate::platform_specific::Action::SetCursorGrab(
+                                surface,
+                                mode,
+                            ) => {
+                                state.set_cursor_grab(surface, mode);
+                            }
                             crate::platform_specific::Action::RequestRedraw(
                                 id,
                             ) => {
@@ -194,6 +220,11 @@ impl SctkEventLoop {
                             }
                             crate::Action::Dropped(id) => {
                                 _ = state.destroyed.remove(&id.inner());
+
+                                state.surface_outputs.retain(|surface, _| {
+                                    state.id_map.get(surface)
+                                        != Some(&id.inner())
+                                });
                             }
                             crate::Action::SubsurfaceResize(id, size) => {
                                 // reposition the surface
@@ -324,6 +355,21 @@ impl SctkEventLoop {
                     inhibitor: None,
                     inhibited: false,
 
+                    pointer_constraints: registry_state.bind_one::<ZwpPointerConstraintsV1, _, _>(
+                        &qh,
+                        1..=1,
+                        (),
+                    ).ok(),
+                    pointer_constraint: None,
+                    relative_pointer_manager: registry_state.bind_one::<ZwpRelativePointerManagerV1, _, _>(
+                        &qh,
+                        1..=1,
+                        (),
+                    ).ok(),
+                    relative_pointer: None,
+
+                    custom_cursors: Default::default(),
+
                     corner_radius_manager: registry_state.bind_one::<CosmicCornerRadiusManagerV1, _, _>(
                         &qh,
                         1..=1,
@@ -338,6 +384,7 @@ impl SctkEventLoop {
                     _cursor_surface: None,
                     _multipool: None,
                     outputs: Vec::new(),
+                    surface_outputs: HashMap::new(),
                     seats: Vec::new(),
                     windows: Vec::new(),
                     layer_surfaces: Vec::new(),
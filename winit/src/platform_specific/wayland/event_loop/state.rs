@@ -35,6 +35,7 @@ use wayland_backend::client::ObjectId;
 use winit::{
     dpi::{LogicalPosition, LogicalSize},
     platform::wayland::WindowExtWayland,
+    window::CursorGrabMode,
 };
 
 use cctk::{
@@ -60,6 +61,7 @@ use cctk::{
                     wl_output::WlOutput,
                     wl_region::WlRegion,
                     wl_seat::WlSeat,
+                    wl_shm,
                     wl_subsurface::WlSubsurface,
                     wl_surface::{self, WlSurface},
                     wl_touch::WlTouch,
@@ -89,13 +91,17 @@ use cctk::{
             },
             WaylandSurface,
         },
-        shm::{multi::MultiPool, Shm},
+        shm::{
+            multi::MultiPool,
+            slot::{Buffer, SlotPool},
+            Shm,
+        },
     },
     toplevel_info::ToplevelInfoState,
     toplevel_management::ToplevelManagerState,
 };
 use iced_runtime::{
-    core::{self, touch, Point},
+    core::{self, mouse, touch, Point},
     keyboard::Modifiers,
     platform_specific::{
         self,
@@ -114,6 +120,15 @@ use wayland_protocols::{
             zwp_keyboard_shortcuts_inhibit_manager_v1,
             zwp_keyboard_shortcuts_inhibitor_v1,
         },
+        pointer_constraints::zv1::client::{
+            zwp_confined_pointer_v1::ZwpConfinedPointerV1,
+            zwp_locked_pointer_v1::ZwpLockedPointerV1,
+            zwp_pointer_constraints_v1::{self, ZwpPointerConstraintsV1},
+        },
+        relative_pointer::zv1::client::{
+            zwp_relative_pointer_manager_v1::ZwpRelativePointerManagerV1,
+            zwp_relative_pointer_v1::ZwpRelativePointerV1,
+        },
         viewporter::client::wp_viewport::WpViewport,
     },
     xdg::shell::client::{xdg_surface::XdgSurface, xdg_toplevel::XdgToplevel},
@@ -137,6 +152,10 @@ pub(crate) struct SctkSeat {
     pub(crate) active_icon: Option<CursorIcon>,
     // Cursor icon set by application
     pub(crate) icon: Option<CursorIcon>,
+    // Whether the application hid the cursor
+    pub(crate) cursor_hidden: bool,
+    // Custom cursor set by application
+    pub(crate) custom_cursor: Option<mouse::CustomCursor>,
 }
 
 impl SctkSeat {
@@ -146,6 +165,133 @@ impl SctkSeat {
             self.active_icon = Some(icon);
         }
     }
+
+    pub(crate) fn set_custom_cursor(
+        &mut self,
+        cursor: &mouse::CustomCursor,
+        buffer: &Buffer,
+    ) {
+        let Some(ptr) = self.ptr.as_ref() else {
+            return;
+        };
+
+        let Some(serial) = ptr
+            .pointer()
+            .data::<PointerData>()
+            .and_then(PointerData::latest_enter_serial)
+        else {
+            return;
+        };
+
+        let surface = ptr.surface();
+        surface.set_buffer_scale(1);
+        surface.attach(Some(buffer.wl_buffer()), 0, 0);
+        surface.damage_buffer(
+            0,
+            0,
+            i32::from(cursor.width()),
+            i32::from(cursor.height()),
+        );
+        surface.commit();
+
+        let hotspot = cursor.hotspot();
+
+        ptr.pointer().set_cursor(
+            serial,
+            Some(surface),
+            i32::from(hotspot.x),
+            i32::from(hotspot.y),
+        );
+
+        self.active_icon = None;
+    }
+
+    pub(crate) fn set_cursor_visible(
+        &mut self,
+        conn: &Connection,
+        visible: bool,
+    ) {
+        self.cursor_hidden = !visible;
+
+        if visible {
+            self.set_cursor(conn, self.icon.unwrap_or(CursorIcon::Default));
+        } else if let Some(ptr) = self.ptr.as_ref() {
+            _ = ptr.hide_cursor();
+            self.active_icon = None;
+        }
+    }
+}
+
+/// The shared memory buffers of the custom cursors, cached by id.
+///
+/// Buffers are dropped once the application drops their cursor.
+#[derive(Default)]
+pub(crate) struct CustomCursors {
+    pool: Option<SlotPool>,
+    buffers: HashMap<mouse::custom_cursor::Id, Buffer>,
+}
+
+impl CustomCursors {
+    pub(crate) fn buffer(
+        &mut self,
+        shm: &Shm,
+        cursor: &mouse::CustomCursor,
+    ) -> Option<&Buffer> {
+        if !self.buffers.contains_key(&cursor.id()) {
+            self.buffers.retain(|id, _| id.cursor().is_some());
+
+            let width = i32::from(cursor.width());
+            let height = i32::from(cursor.height());
+            let stride = width * 4;
+
+            if self.pool.is_none() {
+                self.pool = SlotPool::new((stride * height) as usize, shm).ok();
+            }
+
+            let (buffer, canvas) = self
+                .pool
+                .as_mut()?
+                .create_buffer(width, height, stride, wl_shm::Format::Argb8888)
+                .ok()?;
+
+            // Wayland expects premultiplied BGRA pixels
+            for (pixel, rgba) in canvas
+                .chunks_exact_mut(4)
+                .zip(cursor.pixels().chunks_exact(4))
+            {
+                let alpha = u32::from(rgba[3]);
+                let premultiply =
+                    |channel: u8| (u32::from(channel) * alpha / 255) as u8;
+
+                pixel.copy_from_slice(&[
+                    premultiply(rgba[2]),
+                    premultiply(rgba[1]),
+                    premultiply(rgba[0]),
+                    rgba[3],
+                ]);
+            }
+
+            let _ = self.buffers.insert(cursor.id(), buffer);
+        }
+
+        self.buffers.get(&cursor.id())
+    }
+}
+
+/// An active constraint of the pointer to a surface.
+#[derive(Debug)]
+pub(crate) enum PointerConstraint {
+    Locked(ZwpLockedPointerV1),
+    Confined(ZwpConfinedPointerV1),
+}
+
+impl PointerConstraint {
+    fn destroy(&self) {
+        match self {
+            PointerConstraint::Locked(locked) => locked.destroy(),
+            PointerConstraint::Confined(confined) => confined.destroy(),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -430,6 +576,8 @@ pub struct SctkState {
 
     /// all present outputs
     pub(crate) outputs: Vec<WlOutput>,
+    /// the outputs each surface is on, in the order they were entered
+    pub(crate) surface_outputs: HashMap<ObjectId, Vec<WlOutput>>,
     // though (for now) only one seat will be active in an iced application at a time, all ought to be tracked
     // Active seat is the first seat in the list
     pub(crate) seats: Vec<SctkSeat>,
@@ -493,6 +641,13 @@ pub struct SctkState {
     pub(crate) inhibited: bool,
     pub(crate) inhibitor_manager: Option<zwp_keyboard_shortcuts_inhibit_manager_v1::ZwpKeyboardShortcutsInhibitManagerV1>,
 
+    pub(crate) pointer_constraints: Option<ZwpPointerConstraintsV1>,
+    pub(crate) pointer_constraint: Option<PointerConstraint>,
+    pub(crate) relative_pointer_manager: Option<ZwpRelativePointerManagerV1>,
+    pub(crate) relative_pointer: Option<ZwpRelativePointerV1>,
+
+    pub(crate) custom_cursors: CustomCursors,
+
     pub(crate) corner_radius_manager: Option<CosmicCornerRadiusManagerV1>,
     pub(crate) pending_corner_radius: HashMap<core::window::Id, CornerRadius>
 }
@@ -570,6 +725,92 @@ impl SctkState {
         }
     }
 
+    pub(crate) fn set_custom_cursor(&mut self, cursor: mouse::CustomCursor) {
+        let Some(seat) = self.seats.first_mut() else {
+            return;
+        };
+
+        // The pointer focus only tracks surfaces that are not winit windows,
+        // which create their own custom cursors
+        if seat.ptr_focus.is_none() {
+            return;
+        }
+
+        if let Some(buffer) =
+            self.custom_cursors.buffer(&self.shm_state, &cursor)
+        {
+            seat.set_custom_cursor(&cursor, buffer);
+        }
+
+        seat.custom_cursor = Some(cursor);
+    }
+
+    pub(crate) fn set_cursor_grab(
+        &mut self,
+        surface: WlSurface,
+        mode: CursorGrabMode,
+    ) {
+        if let Some(constraint) = self.pointer_constraint.take() {
+            constraint.destroy();
+        }
+
+        if let Some(relative_pointer) = self.relative_pointer.take() {
+            relative_pointer.destroy();
+        }
+
+        if mode == CursorGrabMode::None {
+            return;
+        }
+
+        let Some(manager) = self.pointer_constraints.as_ref() else {
+            log::warn!(
+                "Pointer constraints are not supported by the compositor"
+            );
+            return;
+        };
+
+        let Some(pointer) = self
+            .seats
+            .first()
+            .and_then(|seat| seat.ptr.as_ref())
+            .map(|ptr| ptr.pointer())
+        else {
+            return;
+        };
+
+        let lifetime = zwp_pointer_constraints_v1::Lifetime::Persistent;
+
+        self.pointer_constraint = Some(match mode {
+            CursorGrabMode::Locked => {
+                PointerConstraint::Locked(manager.lock_pointer(
+                    &surface,
+                    pointer,
+                    None,
+                    lifetime,
+                    &self.queue_handle,
+                    (),
+                ))
+            }
+            _ => PointerConstraint::Confined(manager.confine_pointer(
+                &surface,
+                pointer,
+                None,
+                lifetime,
+                &self.queue_handle,
+                (),
+            )),
+        });
+
+        self.relative_pointer =
+            self.relative_pointer_manager.as_ref().map(|manager| {
+                manager.get_relative_pointer(
+                    pointer,
+                    &self.queue_handle,
+                    surface,
+                )
+            });
+    }
+
     pub fn scale_factor_changed(
         &mut self,
         surface: &WlSurface,
@@ -3,6 +3,7 @@ use cctk::sctk::{
     compositor::CompositorHandler,
     delegate_compositor,
     reexports::client::{
+        backend::ObjectId,
         protocol::{wl_output, wl_surface},
         Connection, Proxy, QueueHandle,
     },
@@ -10,7 +11,9 @@ use cctk::sctk::{
 
 use crate::{
     event_loop::state::receive_frame,
-    platform_specific::wayland::event_loop::state::SctkState,
+    platform_specific::wayland::{
+        event_loop::state::SctkState, sctk_event::SctkEvent,
+    },
 };
 
 impl CompositorHandler for SctkState {
@@ -49,18 +52,52 @@ impl CompositorHandler for SctkState {
         &mut self,
         _: &Connection,
         _: &QueueHandle<Self>,
-        _: &wl_surface::WlSurface,
-        _: &wl_output::WlOutput,
+        surface: &wl_surface::WlSurface,
+        output: &wl_output::WlOutput,
     ) {
+        let outputs = self.surface_outputs.entry(surface.id()).or_default();
+        outputs.retain(|entered| entered != output);
+        outputs.push(output.clone());
+
+        self.surface_output_changed(surface.id());
     }
 
     fn surface_leave(
         &mut self,
         _: &Connection,
         _: &QueueHandle<Self>,
-        _: &wl_surface::WlSurface,
-        _: &wl_output::WlOutput,
+        surface: &wl_surface::WlSurface,
+        output: &wl_output::WlOutput,
     ) {
+        let Some(outputs) = self.surface_outputs.get_mut(&surface.id()) else {
+            return;
+        };
+
+        let was_current = outputs.last() == Some(output);
+        outputs.retain(|entered| entered != output);
+
+        if outputs.is_empty() {
+            let _ = self.surface_outputs.remove(&surface.id());
+        }
+
+        if was_current {
+            self.surface_output_changed(surface.id());
+        }
+    }
+}
+
+impl SctkState {
+    /// Reports the output the surface is on; that is, the last one it
+    /// entered and has not left yet.
+    pub(crate) fn surface_output_changed(&mut self, surface: ObjectId) {
+        let info = self
+            .surface_outputs
+            .get(&surface)
+            .and_then(|outputs| outputs.last())
+            .and_then(|output| self.output_state.info(output));
+
+        self.sctk_events
+            .push(SctkEvent::SurfaceOutput { surface, info });
     }
 }
 
@@ -33,6 +33,17 @@ impl OutputHandler for SctkState {
                 info,
             });
         }
+
+        let surfaces: Vec<_> = self
+            .surface_outputs
+            .iter()
+            .filter(|(_, outputs)| outputs.last() == Some(&output))
+            .map(|(surface, _)| surface.clone())
+            .collect();
+
+        for surface in surfaces {
+            self.surface_output_changed(surface);
+        }
     }
 
     fn output_destroyed(
@@ -41,6 +52,26 @@ impl OutputHandler for SctkState {
         _qh: &cctk::sctk::reexports::client::QueueHandle<Self>,
         output: cctk::sctk::reexports::client::protocol::wl_output::WlOutput,
     ) {
+        self.outputs.retain(|present| present != &output);
+
+        let surfaces: Vec<_> = self
+            .surface_outputs
+            .iter_mut()
+            .filter_map(|(surface, outputs)| {
+                let was_current = outputs.last() == Some(&output);
+                outputs.retain(|entered| entered != &output);
+
+                was_current.then(|| surface.clone())
+            })
+            .collect();
+
+        self.surface_outputs
+            .retain(|_, outputs| !outputs.is_empty());
+
+        for surface in surfaces {
+            self.surface_output_changed(surface);
+        }
+
         self.sctk_events.push(SctkEvent::RemovedOutput(output));
         // TODO clean up any layer surfaces on this output?
     }
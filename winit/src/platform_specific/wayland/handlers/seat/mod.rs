@@ -2,5 +2,7 @@
 pub mod keyboard;
 pub mod keyboard_shortcuts_inhibit;
 pub mod pointer;
+pub mod pointer_constraints;
+pub mod relative_pointer;
 pub mod seat;
 pub mod touch;
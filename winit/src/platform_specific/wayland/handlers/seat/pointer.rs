@@ -39,7 +39,29 @@ impl PointerHandler for SctkState {
 
         // track events, but only forward for the active seat
         for e in events {
-            if my_seat.active_icon != my_seat.icon {
+            let id =
+                winit::window::WindowId::from(e.surface.id().as_ptr() as u64);
+            let is_winit_window =
+                self.windows.iter().any(|w| w.window.id() == id);
+            // Hidden and custom cursors are reset when entering a surface
+            let is_entered = !is_winit_window
+                && matches!(e.kind, PointerEventKind::Enter { .. });
+
+            if my_seat.cursor_hidden {
+                if is_entered {
+                    if let Some(ptr) = my_seat.ptr.as_ref() {
+                        _ = ptr.hide_cursor();
+                    }
+                }
+            } else if let Some(cursor) = my_seat.custom_cursor.clone() {
+                if is_entered {
+                    if let Some(buffer) =
+                        self.custom_cursors.buffer(&self.shm_state, &cursor)
+                    {
+                        my_seat.set_custom_cursor(&cursor, buffer);
+                    }
+                }
+            } else if my_seat.active_icon != my_seat.icon {
                 // Restore cursor that was set by appliction, or default
                 my_seat.set_cursor(
                     conn,
@@ -48,10 +70,7 @@ impl PointerHandler for SctkState {
             }
 
             if is_active {
-                let id = winit::window::WindowId::from(
-                    e.surface.id().as_ptr() as u64
-                );
-                if self.windows.iter().any(|w| w.window.id() == id) {
+                if is_winit_window {
                     continue;
                 }
 
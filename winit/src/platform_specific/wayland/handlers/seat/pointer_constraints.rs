@@ -0,0 +1,49 @@
+use cctk::sctk;
+use sctk::reexports::{
+    client::{Connection, Dispatch, Proxy},
+    protocols::wp::pointer_constraints::zv1::client::{
+        zwp_confined_pointer_v1::ZwpConfinedPointerV1,
+        zwp_locked_pointer_v1::ZwpLockedPointerV1,
+        zwp_pointer_constraints_v1::ZwpPointerConstraintsV1,
+    },
+};
+
+use crate::event_loop::state::SctkState;
+
+impl Dispatch<ZwpPointerConstraintsV1, ()> for SctkState {
+    fn event(
+        _state: &mut Self,
+        _proxy: &ZwpPointerConstraintsV1,
+        _event: <ZwpPointerConstraintsV1 as Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &sctk::reexports::client::QueueHandle<Self>,
+    ) {
+    }
+}
+
+impl Dispatch<ZwpLockedPointerV1, ()> for SctkState {
+    fn event(
+        _state: &mut Self,
+        _proxy: &ZwpLockedPointerV1,
+        _event: <ZwpLockedPointerV1 as Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &sctk::reexports::client::QueueHandle<Self>,
+    ) {
+        // The constraint is persistent, so it is reactivated by the
+        // compositor when the pointer enters the surface again.
+    }
+}
+
+impl Dispatch<ZwpConfinedPointerV1, ()> for SctkState {
+    fn event(
+        _state: &mut Self,
+        _proxy: &ZwpConfinedPointerV1,
+        _event: <ZwpConfinedPointerV1 as Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &sctk::reexports::client::QueueHandle<Self>,
+    ) {
+    }
+}
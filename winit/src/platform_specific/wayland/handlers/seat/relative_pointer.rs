@@ -0,0 +1,46 @@
+use cctk::sctk;
+use sctk::reexports::{
+    client::{protocol::wl_surface::WlSurface, Connection, Dispatch, Proxy},
+    protocols::wp::relative_pointer::zv1::client::{
+        zwp_relative_pointer_manager_v1::ZwpRelativePointerManagerV1,
+        zwp_relative_pointer_v1::{self, ZwpRelativePointerV1},
+    },
+};
+
+use crate::event_loop::state::SctkState;
+use crate::platform_specific::wayland::SctkEvent;
+
+impl Dispatch<ZwpRelativePointerManagerV1, ()> for SctkState {
+    fn event(
+        _state: &mut Self,
+        _proxy: &ZwpRelativePointerManagerV1,
+        _event: <ZwpRelativePointerManagerV1 as Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &sctk::reexports::client::QueueHandle<Self>,
+    ) {
+    }
+}
+
+impl Dispatch<ZwpRelativePointerV1, WlSurface> for SctkState {
+    fn event(
+        state: &mut Self,
+        _proxy: &ZwpRelativePointerV1,
+        event: <ZwpRelativePointerV1 as Proxy>::Event,
+        surface: &WlSurface,
+        _conn: &Connection,
+        _qhandle: &sctk::reexports::client::QueueHandle<Self>,
+    ) {
+        if let zwp_relative_pointer_v1::Event::RelativeMotion {
+            dx_unaccel,
+            dy_unaccel,
+            ..
+        } = event
+        {
+            state.sctk_events.push(SctkEvent::RelativeMotion {
+                surface: surface.clone(),
+                delta: (dx_unaccel, dy_unaccel),
+            });
+        }
+    }
+}
@@ -38,6 +38,8 @@ impl SeatHandler for SctkState {
             last_touch_down: None,
             icon: None,
             active_icon: None,
+            cursor_hidden: false,
+            custom_cursor: None,
         });
     }
 
@@ -65,6 +67,8 @@ impl SeatHandler for SctkState {
                     last_touch_down: None,
                     icon: None,
                     active_icon: None,
+                    cursor_hidden: false,
+                    custom_cursor: None,
                 });
                 self.seats.last_mut().unwrap()
             }
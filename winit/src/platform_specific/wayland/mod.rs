@@ -15,7 +15,7 @@ use cctk::sctk::reexports::client::protocol::wl_surface::WlSurface;
 use cctk::sctk::seat::keyboard::Modifiers;
 use iced_futures::futures::channel::mpsc;
 use iced_graphics::Compositor;
-use iced_runtime::core::{window, Vector};
+use iced_runtime::core::{mouse, window, Vector};
 use iced_runtime::Debug;
 use raw_window_handle::{DisplayHandle, HasDisplayHandle, HasWindowHandle};
 use raw_window_handle::{HasRawDisplayHandle, RawWindowHandle};
@@ -26,11 +26,14 @@ use wayland_backend::client::ObjectId;
 use wayland_client::{Connection, Proxy};
 use winit::dpi::Size;
 use winit::event_loop::OwnedDisplayHandle;
-use winit::window::CursorIcon;
+use winit::window::{CursorGrabMode, CursorIcon};
 
 pub(crate) enum Action {
     Action(iced_runtime::platform_specific::wayland::Action),
     SetCursor(CursorIcon),
+    SetCustomCursor(mouse::CustomCursor),
+    SetCursorVisible(bool),
+    SetCursorGrab(WlSurface, CursorGrabMode),
     RequestRedraw(ObjectId),
     TrackWindow(Arc<dyn winit::window::Window>, window::Id),
     ResizeWindow(window::Id),
@@ -46,6 +49,17 @@ impl std::fmt::Debug for Action {
             Self::SetCursor(arg0) => {
                 f.debug_tuple("SetCursor").field(arg0).finish()
             }
+            Self::SetCustomCursor(arg0) => {
+                f.debug_tuple("SetCustomCursor").field(arg0).finish()
+            }
+            Self::SetCursorVisible(arg0) => {
+                f.debug_tuple("SetCursorVisible").field(arg0).finish()
+            }
+            Self::SetCursorGrab(arg0, arg1) => f
+                .debug_tuple("SetCursorGrab")
+                .field(arg0)
+                .field(arg1)
+                .finish(),
             Self::RequestRedraw(arg0) => {
                 f.debug_tuple("RequestRedraw").field(arg0).finish()
             }
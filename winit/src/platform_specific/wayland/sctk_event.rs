@@ -2,8 +2,8 @@ use crate::{
     platform_specific::{
         wayland::{
             conversion::{
-                modifiers_to_native, pointer_axis_to_native,
-                pointer_button_to_native,
+                modifiers_to_native, output_info_to_monitor,
+                pointer_axis_to_native, pointer_button_to_native,
             },
             keymap::{self, keysym_to_key},
             subsurface_widget::SubsurfaceState,
@@ -34,7 +34,7 @@ use iced_runtime::{
         event::wayland,
         keyboard, mouse, touch,
         window::{self, Id as SurfaceId},
-        Point,
+        Point, Vector,
     },
     keyboard::{key, Key, Location},
     user_interface, Debug,
@@ -110,6 +110,10 @@ pub enum SctkEvent {
         ptr_id: WlPointer,
         seat_id: WlSeat,
     },
+    RelativeMotion {
+        surface: WlSurface,
+        delta: (f64, f64),
+    },
     KeyboardEvent {
         variant: KeyboardEventVariant,
         kbd_id: WlKeyboard,
@@ -180,6 +184,11 @@ pub enum SctkEvent {
         info: OutputInfo,
     },
     RemovedOutput(WlOutput),
+    /// The output a surface is on changed, or was updated.
+    SurfaceOutput {
+        surface: ObjectId,
+        info: Option<OutputInfo>,
+    },
     //
     // compositor events
     //
@@ -1189,6 +1198,41 @@ impl SctkEvent {
                     )),
                 ),
             )),
+            SctkEvent::RelativeMotion { surface, delta } => {
+                if let Some(id) = surface_ids.get(&surface.id()) {
+                    events.push((
+                        Some(id.inner()),
+                        iced_runtime::core::Event::Mouse(
+                            mouse::Event::RawMotion {
+                                delta: Vector::new(
+                                    delta.0 as f32,
+                                    delta.1 as f32,
+                                ),
+                            },
+                        ),
+                    ));
+                }
+            }
+            SctkEvent::SurfaceOutput { surface, info } => {
+                let Some(id) = surface_ids.get(&surface).map(|id| id.inner())
+                else {
+                    return;
+                };
+
+                let monitor = window_manager.get_mut(id).and_then(|window| {
+                    window
+                        .set_monitor(info.as_ref().map(output_info_to_monitor))
+                });
+
+                if let Some(monitor) = monitor {
+                    events.push((
+                        Some(id),
+                        iced_runtime::core::Event::Window(
+                            window::Event::MonitorChanged(monitor),
+                        ),
+                    ));
+                }
+            }
             SctkEvent::ScaleFactorChanged {
                 factor: _,
                 id: _,
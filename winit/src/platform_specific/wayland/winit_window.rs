@@ -78,13 +78,14 @@ impl winit::window::Window for SctkWinitWindow {
                 _ = self.tx.send(Action::SetCursor(icon));
             }
             winit::window::Cursor::Custom(_) => {
-                // TODO
+                // Custom cursors are uploaded by the event loop with
+                // `Action::SetCustomCursor`, since their pixels are opaque
             }
         }
     }
 
     fn set_cursor_visible(&self, visible: bool) {
-        // TODO
+        _ = self.tx.send(Action::SetCursorVisible(visible));
     }
 
     fn surface_size(&self) -> winit::dpi::PhysicalSize<u32> {
@@ -416,7 +417,11 @@ impl winit::window::Window for SctkWinitWindow {
         &self,
         mode: winit::window::CursorGrabMode,
     ) -> Result<(), winit::error::RequestError> {
-        todo!()
+        _ = self.tx.send(Action::SetCursorGrab(
+            self.surface.wl_surface().clone(),
+            mode,
+        ));
+        Ok(())
     }
 }
 
@@ -1,6 +1,8 @@
 //! Create interactive, native cross-platform applications for WGPU.
 #[path = "application/drag_resize.rs"]
 mod drag_resize;
+#[cfg(feature = "replay")]
+mod replay;
 mod state;
 pub(crate) mod window_manager;
 
@@ -32,6 +34,7 @@ use crate::futures::subscription::{self, Subscription};
 use crate::futures::{Executor, Runtime};
 use crate::graphics;
 use crate::graphics::{compositor, Compositor};
+use crate::instance;
 use crate::platform_specific;
 use crate::runtime::user_interface::{self, UserInterface};
 use crate::runtime::Debug;
@@ -67,7 +70,20 @@ use log::trace;
 /// [`Task`] in some of its methods.
 ///
 /// When using a [`Program`] with the `debug` feature enabled, a debug view
-/// can be toggled by pressing `F12`.
+/// can be toggled by pressing `F12`. The debug view includes a widget
+/// inspector, which highlights the widget under the cursor; the bounds of
+/// every widget can be outlined by pressing `Shift+F12`.
+///
+/// The `debug` feature also enables tracing the messages, tasks,
+/// subscriptions and redraws of a [`Program`], either with
+/// [`Settings::trace`] or the `ICED_TRACE` environment variable. See
+/// [`Trace`](runtime::Trace) to learn more.
+///
+/// With the `replay` feature enabled, the input delivered to the windows of
+/// a [`Program`] is recorded to the session file at the path in the
+/// `ICED_RECORD` environment variable, and the session file at the path in
+/// the `ICED_REPLAY` environment variable is replayed in place of any live
+/// input. Both sessions start when the first window opens.
 pub trait Program
 where
     Self: Sized,
@@ -182,7 +198,32 @@ where
 {
     use winit::event_loop::EventLoop;
 
+    if settings.single_instance {
+        if let Some(id) = &settings.id {
+            let arguments = std::env::args_os()
+                .skip(1)
+                .map(|argument| argument.to_string_lossy().into_owned());
+
+            match instance::acquire(id, arguments) {
+                Ok(instance::Instance::Forwarded) => return Ok(()),
+                Ok(instance::Instance::Primary) => {}
+                Err(error) => {
+                    log::warn!(
+                        "Failed to acquire the single instance: {error}"
+                    );
+                }
+            }
+        } else {
+            log::warn!("Single-instance mode requires an application id");
+        }
+    }
+
     let mut debug = Debug::new();
+
+    if let Some(trace) = runtime::Trace::from_env().or(settings.trace) {
+        debug.trace(&trace);
+    }
+
     debug.startup_started();
 
     let event_loop = EventLoop::new().expect("Create event loop");
@@ -230,6 +271,7 @@ where
     runtime.track(subscription::into_recipes(
         runtime.enter(|| program.subscription().map(Action::Output)),
     ));
+    debug.log_subscriptions(runtime.subscriptions());
 
     let (boot_sender, boot_receiver) = oneshot::channel();
     let (control_sender, control_receiver) = mpsc::unbounded();
@@ -257,6 +299,8 @@ where
         receiver: mpsc::UnboundedReceiver<Control>,
         error: Option<Error>,
         proxy: Proxy<Message>,
+        custom_cursors:
+            FxHashMap<mouse::custom_cursor::Id, winit::window::CustomCursor>,
 
         #[cfg(target_arch = "wasm32")]
         is_booted: std::rc::Rc<std::cell::RefCell<bool>>,
@@ -287,6 +331,7 @@ where
         receiver: control_receiver,
         error: None,
         proxy: proxy.clone(),
+        custom_cursors: FxHashMap::default(),
 
         #[cfg(target_arch = "wasm32")]
         is_booted: std::rc::Rc::new(std::cell::RefCell::new(false)),
@@ -372,6 +417,17 @@ where
             }
         }
 
+        fn device_event(
+            &mut self,
+            event_loop: &dyn winit::event_loop::ActiveEventLoop,
+            _device_id: Option<winit::event::DeviceId>,
+            event: winit::event::DeviceEvent,
+        ) {
+            if matches!(event, winit::event::DeviceEvent::MouseMotion { .. }) {
+                self.process_event(event_loop, Some(Event::Device(event)));
+            }
+        }
+
         fn about_to_wait(
             &mut self,
             event_loop: &dyn winit::event_loop::ActiveEventLoop,
@@ -513,6 +569,16 @@ where
                                 monitor,
                                 on_open,
                             } => {
+                                let mut settings = settings;
+
+                                let (restored_monitor, restored_position) =
+                                    conversion::restore_geometry(
+                                        &mut settings,
+                                        event_loop.available_monitors(),
+                                    );
+
+                                let monitor = restored_monitor.or(monitor);
+
                                 let exit_on_close_request =
                                     settings.exit_on_close_request;
                                 let resize_border = settings.resize_border;
@@ -533,6 +599,13 @@ where
                                     )
                                     .with_visible(false);
 
+                                let window_attributes = match restored_position
+                                {
+                                    Some(position) => window_attributes
+                                        .with_position(position),
+                                    None => window_attributes,
+                                };
+
                                 #[cfg(target_arch = "wasm32")]
                                 let window_attributes = {
                                     use winit::platform::web::WindowAttributesExtWebSys;
@@ -670,6 +743,62 @@ where
                                     .start_send(Event::StartDnd)
                                     .expect("Send event");
                             }
+                            Control::GetMonitors(channel) => {
+                                let _ = channel.send(
+                                    event_loop
+                                        .available_monitors()
+                                        .map(|monitor| {
+                                            conversion::monitor(&monitor)
+                                        })
+                                        .collect(),
+                                );
+                            }
+                            Control::SetCustomCursor { window, cursor } => {
+                                let custom_cursor = match self
+                                    .custom_cursors
+                                    .get(&cursor.id())
+                                {
+                                    Some(custom_cursor) => {
+                                        custom_cursor.clone()
+                                    }
+                                    None => {
+                                        let Ok(source) =
+                                            conversion::custom_cursor(&cursor)
+                                        else {
+                                            log::warn!(
+                                                "Invalid custom cursor: \
+                                                {cursor:?}"
+                                            );
+                                            continue;
+                                        };
+
+                                        let Ok(custom_cursor) = event_loop
+                                            .create_custom_cursor(source)
+                                        else {
+                                            log::warn!(
+                                                "Failed to create custom \
+                                                cursor: {cursor:?}"
+                                            );
+                                            continue;
+                                        };
+
+                                        // Forget the cursors that were
+                                        // dropped by the application
+                                        self.custom_cursors.retain(|id, _| {
+                                            id.cursor().is_some()
+                                        });
+
+                                        let _ = self.custom_cursors.insert(
+                                            cursor.id(),
+                                            custom_cursor.clone(),
+                                        );
+
+                                        custom_cursor
+                                    }
+                                };
+
+                                window.set_cursor(custom_cursor.into());
+                            }
                         },
                         _ => {
                             break;
@@ -722,6 +851,7 @@ pub(crate) enum Event<Message: 'static> {
     #[cfg(feature = "a11y")]
     AccessibilityEnabled(bool),
     Winit(winit::window::WindowId, winit::event::WindowEvent),
+    Device(winit::event::DeviceEvent),
     AboutToWait,
     UserEvent(Action<Message>),
     NewEvents(winit::event::StartCause),
@@ -747,7 +877,12 @@ pub(crate) enum Control {
     PlatformSpecific(crate::platform_specific::Event),
     AboutToWait,
     Winit(winit::window::WindowId, winit::event::WindowEvent),
+    SetCustomCursor {
+        window: Arc<dyn winit::window::Window>,
+        cursor: mouse::CustomCursor,
+    },
     StartDnd,
+    GetMonitors(oneshot::Sender<Vec<window::Monitor>>),
 }
 
 async fn run_instance<'a, P, C>(
@@ -792,6 +927,9 @@ async fn run_instance<'a, P, C>(
     let mut messages = Vec::new();
     let mut actions = 0;
 
+    #[cfg(feature = "replay")]
+    let mut replay = replay::Replay::from_env();
+
     #[cfg(feature = "a11y")]
     let (mut adapters, mut a11y_enabled) = if let Some((main_id, title, raw)) =
         window_manager.ids().next().and_then(|id| {
@@ -1224,7 +1362,8 @@ async fn run_instance<'a, P, C>(
                     _ = control_sender
                         .start_send(Control::ChangeFlow(ControlFlow::Wait));
                 }
-                for (_id, window) in window_manager.iter_mut() {
+                for (id, window) in window_manager.iter_mut() {
+                    debug.log_redraw(id, "scheduled wake up");
                     window.request_redraw();
                 }
             }
@@ -1293,6 +1432,7 @@ async fn run_instance<'a, P, C>(
                         );
 
                         debug.draw_started();
+                        let draw_start = Instant::now();
                         let new_mouse_interaction = ui.draw(
                             &mut window.renderer,
                             window.state.theme(),
@@ -1303,31 +1443,19 @@ async fn run_instance<'a, P, C>(
                             },
                             cursor,
                         );
+                        window.stats.draw = draw_start.elapsed();
                         platform_specific_handler.update_subsurfaces(
                             id,
                             window.raw.rwh_06_window_handle(),
                         );
                         debug.draw_finished();
 
-                        if new_mouse_interaction != window.mouse_interaction {
-                            if let Some(interaction) =
-                                conversion::mouse_interaction(
-                                    new_mouse_interaction,
-                                )
-                            {
-                                if matches!(
-                                    window.mouse_interaction,
-                                    mouse::Interaction::Hide
-                                ) {
-                                    window.raw.set_cursor_visible(true);
-                                }
-                                window.raw.set_cursor(interaction.into())
-                            } else {
-                                window.raw.set_cursor_visible(false);
-                            }
-
-                            window.mouse_interaction = new_mouse_interaction;
-                        }
+                        update_mouse_interaction(
+                            window,
+                            new_mouse_interaction,
+                            &mut control_sender,
+                            &mut platform_specific_handler,
+                        );
 
                         runtime.broadcast(subscription::Event::Interaction {
                             window: id,
@@ -1341,6 +1469,10 @@ async fn run_instance<'a, P, C>(
                                     redraw_request: Some(redraw_request),
                                 } => match redraw_request {
                                     window::RedrawRequest::NextFrame => {
+                                        debug.log_redraw(
+                                            id,
+                                            "widget requested next frame",
+                                        );
                                         window.request_redraw();
 
                                         ControlFlow::Wait
@@ -1366,15 +1498,18 @@ async fn run_instance<'a, P, C>(
                         {
                             let logical_size = window.state.logical_size();
                             debug.layout_started();
+                            let layout_start = Instant::now();
                             let mut ui = user_interfaces
                                 .remove(&id)
                                 .expect("Remove user interface")
                                 .relayout(logical_size, &mut window.renderer);
 
                             let _ = user_interfaces.insert(id, ui);
+                            window.stats.layout = layout_start.elapsed();
                             debug.layout_finished();
 
                             debug.draw_started();
+                            let draw_start = Instant::now();
                             let new_mouse_interaction = user_interfaces
                                 .get_mut(&id)
                                 .expect("Get user interface")
@@ -1390,33 +1525,19 @@ async fn run_instance<'a, P, C>(
                                     },
                                     window.state.cursor(),
                                 );
+                            window.stats.draw = draw_start.elapsed();
                             platform_specific_handler.update_subsurfaces(
                                 id,
                                 window.raw.rwh_06_window_handle(),
                             );
                             debug.draw_finished();
 
-                            if new_mouse_interaction != window.mouse_interaction
-                            {
-                                if let Some(interaction) =
-                                    conversion::mouse_interaction(
-                                        new_mouse_interaction,
-                                    )
-                                {
-                                    if matches!(
-                                        window.mouse_interaction,
-                                        mouse::Interaction::Hide
-                                    ) {
-                                        window.raw.set_cursor_visible(true);
-                                    }
-                                    window.raw.set_cursor(interaction.into())
-                                } else {
-                                    window.raw.set_cursor_visible(false);
-                                }
-
-                                window.mouse_interaction =
-                                    new_mouse_interaction;
-                            }
+                            update_mouse_interaction(
+                                window,
+                                new_mouse_interaction,
+                                &mut control_sender,
+                                &mut platform_specific_handler,
+                            );
                             compositor.configure_surface(
                                 &mut window.surface,
                                 physical_size.width,
@@ -1427,8 +1548,22 @@ async fn run_instance<'a, P, C>(
                                 window.state.viewport_version();
                         }
 
+                        if debug.is_enabled() {
+                            let ui = user_interfaces
+                                .get(&id)
+                                .expect("Get user interface");
+
+                            debug.draw_inspector(
+                                &ui.inspect(),
+                                window.state.cursor(),
+                                &mut window.renderer,
+                                window.state.logical_size(),
+                            );
+                        }
+
                         window.raw.pre_present_notify();
                         debug.render_started();
+                        let present_start = Instant::now();
                         match compositor.present(
                             &mut window.renderer,
                             &mut window.surface,
@@ -1437,7 +1572,19 @@ async fn run_instance<'a, P, C>(
                             &debug.overlay(),
                         ) {
                             Ok(()) => {
+                                window.stats.present = present_start.elapsed();
                                 debug.render_finished();
+
+                                if runtime::window::stats::is_listened() {
+                                    runtime::window::stats::notify(
+                                        id,
+                                        runtime::window::Stats {
+                                            renderer: compositor
+                                                .stats(&window.renderer),
+                                            ..window.stats.clone()
+                                        },
+                                    );
+                                }
                             }
                             Err(error) => {
                                 match error {
@@ -1467,9 +1614,13 @@ async fn run_instance<'a, P, C>(
                                         );
 
                                         // Try rendering all windows again next frame.
-                                        for (_id, window) in
+                                        for (id, window) in
                                             window_manager.iter_mut()
                                         {
+                                            debug.log_redraw(
+                                                id,
+                                                "presentation failed",
+                                            );
                                             window.request_redraw();
                                         }
                                     }
@@ -1537,6 +1688,47 @@ async fn run_instance<'a, P, C>(
                                 &window_event,
                                 &mut debug,
                             );
+
+                            if matches!(
+                                window_event,
+                                winit::event::WindowEvent::Moved(_)
+                                    | winit::event::WindowEvent::ScaleFactorChanged { .. }
+                            ) {
+                                if let Some(monitor) = window.update_monitor() {
+                                    events.push((
+                                        Some(id),
+                                        core::Event::Window(
+                                            window::Event::MonitorChanged(
+                                                monitor,
+                                            ),
+                                        ),
+                                    ));
+                                }
+                            }
+
+                            if matches!(
+                                window_event,
+                                winit::event::WindowEvent::SurfaceResized(_)
+                            ) {
+                                if let Some(deadline) = window.resized() {
+                                    let _ = control_sender.start_send(
+                                        Control::ChangeFlow(
+                                            ControlFlow::WaitUntil(deadline),
+                                        ),
+                                    );
+                                }
+                            }
+
+                            if matches!(
+                                window_event,
+                                winit::event::WindowEvent::Moved(_)
+                                    | winit::event::WindowEvent::SurfaceResized(
+                                        _
+                                    )
+                            ) {
+                                window.update_normal_geometry();
+                            }
+
                             if let Some(event) = conversion::window_event(
                                 window_event,
                                 window.state.scale_factor(),
@@ -1550,8 +1742,47 @@ async fn run_instance<'a, P, C>(
                     _ => {}
                 }
             }
+            Event::Device(winit::event::DeviceEvent::MouseMotion {
+                delta: (x, y),
+            }) => {
+                // Raw motion is only delivered to the focused window, and
+                // only if it grabs the cursor
+                let grabbing =
+                    window_manager.iter_mut().find_map(|(id, window)| {
+                        (window.cursor_grab != window::CursorGrab::None
+                            && window.raw.has_focus())
+                        .then_some(id)
+                    });
+
+                if let Some(id) = grabbing {
+                    events.push((
+                        Some(id),
+                        core::Event::Mouse(mouse::Event::RawMotion {
+                            delta: core::Vector::new(x as f32, y as f32),
+                        }),
+                    ));
+                }
+            }
             Event::AboutToWait => {
-                let skip = events.is_empty() && messages.is_empty();
+                for (_, window) in window_manager.iter_mut() {
+                    window.settle_aspect_ratio();
+                }
+
+                // Windows and SCTK surfaces are both managed here, so the
+                // sessions start as soon as any of them opens
+                #[cfg(feature = "replay")]
+                if window_manager.ids().next().is_some() {
+                    replay.track(window_manager.ids());
+                    replay.start(&proxy.raw);
+                }
+
+                #[cfg(feature = "replay")]
+                let replayed = replay.poll();
+                #[cfg(not(feature = "replay"))]
+                let replayed = false;
+
+                let skip =
+                    events.is_empty() && messages.is_empty() && !replayed;
                 if skip
                     && window_manager.iter_mut().all(|(_, w)| !w.resize_enabled)
                 {
@@ -1579,6 +1810,13 @@ async fn run_instance<'a, P, C>(
                             true
                         }
                     });
+
+                    #[cfg(feature = "replay")]
+                    {
+                        replay.deliver(id, window, &mut window_events);
+                        replay.record(id, &window.state, &window_events);
+                    }
+
                     let no_window_events = window_events.is_empty();
                     #[cfg(feature = "wayland")]
                     window_events.push(core::Event::PlatformSpecific(
@@ -1633,6 +1871,16 @@ async fn run_instance<'a, P, C>(
                         }
                     }
                     if needs_redraw {
+                        debug.log_redraw(
+                            id,
+                            if !no_window_events {
+                                "window events"
+                            } else if !messages.is_empty() {
+                                "messages produced"
+                            } else {
+                                "resize requested"
+                            },
+                        );
                         window.request_redraw();
                     } else {
                         _ = control_sender
@@ -1656,6 +1904,9 @@ async fn run_instance<'a, P, C>(
                     }
                 }
 
+                #[cfg(feature = "replay")]
+                replay.finish();
+
                 if !resized && skip {
                     _ = control_sender
                         .start_send(Control::ChangeFlow(ControlFlow::Wait));
@@ -1707,6 +1958,7 @@ async fn run_instance<'a, P, C>(
                             window.raw.as_ref(),
                         );
 
+                        debug.log_redraw(id, "application updated");
                         window.request_redraw();
                     }
                     rebuild_a11y_tree = true;
@@ -1772,26 +2024,16 @@ async fn run_instance<'a, P, C>(
                     };
                     platform_specific_handler.clear_subsurface_list();
 
-                    if new_mouse_interaction != window.mouse_interaction {
-                        if let Some(interaction) =
-                            conversion::mouse_interaction(new_mouse_interaction)
-                        {
-                            if matches!(
-                                window.mouse_interaction,
-                                mouse::Interaction::Hide
-                            ) {
-                                window.raw.set_cursor_visible(true);
-                            }
-                            window.raw.set_cursor(interaction.into())
-                        } else {
-                            window.raw.set_cursor_visible(false);
-                        }
-
-                        window.mouse_interaction = new_mouse_interaction;
-                    }
+                    update_mouse_interaction(
+                        window,
+                        new_mouse_interaction,
+                        &mut control_sender,
+                        &mut platform_specific_handler,
+                    );
 
                     // TODO once widgets can request to be redrawn, we can avoid always requesting a
                     // redraw
+                    debug.log_redraw(id, "application updated");
                     window.request_redraw();
                     runtime.broadcast(subscription::Event::Interaction {
                         window: id,
@@ -1805,6 +2047,10 @@ async fn run_instance<'a, P, C>(
                                 redraw_request: Some(redraw_request),
                             } => match redraw_request {
                                 window::RedrawRequest::NextFrame => {
+                                    debug.log_redraw(
+                                        id,
+                                        "widget requested next frame",
+                                    );
                                     window.request_redraw();
 
                                     ControlFlow::Wait
@@ -1929,7 +2175,7 @@ async fn run_instance<'a, P, C>(
                 // TODO cleanup duplication
                 let child_tree = interface.a11y_nodes(window.state.cursor());
                 let mut root = NodeBuilder::new(Role::Window);
-                root.set_name(window.state.title.to_string());
+                root.set_name(window.state.title().to_owned());
                 let window_tree = A11yTree::node_with_child_tree(
                     A11yNode::new(root, *a11y_id),
                     child_tree,
@@ -2047,12 +2293,99 @@ fn update<P: Program, E: Executor>(
         debug.update_finished();
 
         if let Some(stream) = runtime::task::into_stream(task) {
+            debug.log_task();
             runtime.run(stream);
         }
     }
 
     let subscription = runtime.enter(|| program.subscription());
     runtime.track(subscription::into_recipes(subscription.map(Action::Output)));
+    debug.log_subscriptions(runtime.subscriptions());
+}
+
+/// Updates the mouse cursor of the window to match the given
+/// [`mouse::Interaction`].
+#[allow(unused_variables)]
+fn update_mouse_interaction<P, C>(
+    window: &mut window_manager::Window<P, C>,
+    interaction: mouse::Interaction,
+    control_sender: &mut mpsc::UnboundedSender<Control>,
+    platform_specific_handler: &mut platform_specific::PlatformSpecific,
+) where
+    P: Program,
+    C: Compositor<Renderer = P::Renderer>,
+    P::Theme: DefaultStyle,
+{
+    if interaction == window.mouse_interaction {
+        return;
+    }
+
+    if matches!(window.mouse_interaction, mouse::Interaction::Hide) {
+        window.raw.set_cursor_visible(true);
+    }
+
+    match interaction {
+        mouse::Interaction::Hide => {
+            window.raw.set_cursor_visible(false);
+        }
+        mouse::Interaction::Custom(id) => {
+            let Some(cursor) = id.cursor() else {
+                log::warn!("Custom cursor {id:?} was dropped");
+
+                window
+                    .raw
+                    .set_cursor(winit::window::CursorIcon::Default.into());
+                window.mouse_interaction = interaction;
+
+                return;
+            };
+
+            // SCTK surfaces upload the pixels of the cursor themselves
+            #[cfg(feature = "wayland")]
+            platform_specific_handler.send_wayland(
+                platform_specific::Action::SetCustomCursor(cursor.clone()),
+            );
+
+            let _ = control_sender.start_send(Control::SetCustomCursor {
+                window: window.raw.clone(),
+                cursor,
+            });
+        }
+        _ => {
+            if let Some(icon) = conversion::mouse_interaction(interaction) {
+                window.raw.set_cursor(icon.into());
+            }
+        }
+    }
+
+    window.mouse_interaction = interaction;
+}
+
+/// Lays out every user interface again and requests a redraw of its window.
+///
+/// Existing paragraphs keep their shaping until they are laid out again; so
+/// this must be called whenever the available fonts change.
+fn relayout_all<P, C>(
+    interfaces: &mut FxHashMap<
+        window::Id,
+        UserInterface<'_, P::Message, P::Theme, P::Renderer>,
+    >,
+    window_manager: &mut WindowManager<P, C>,
+) where
+    P: Program,
+    C: Compositor<Renderer = P::Renderer> + 'static,
+    P::Theme: DefaultStyle,
+{
+    for (id, window) in window_manager.iter_mut() {
+        let Some(ui) = interfaces.remove(&id) else {
+            continue;
+        };
+
+        let ui = ui.relayout(window.state.logical_size(), &mut window.renderer);
+        let _ = interfaces.insert(id, ui);
+
+        window.request_redraw();
+    }
 }
 
 fn run_action<P, C>(
@@ -2082,6 +2415,8 @@ where
     use crate::runtime::system;
     use crate::runtime::window;
 
+    debug.log_action(&action);
+
     match action {
         Action::Output(message) => {
             messages.push(message);
@@ -2100,6 +2435,9 @@ where
                 let contents = clipboard.read_data(kind, allowed);
                 _ = tx.send(contents);
             }
+            clipboard::Action::ReadMimeTypes { target, channel } => {
+                let _ = channel.send(clipboard.read_mime_types(target));
+            }
         },
         Action::Window(action) => match action {
             window::Action::Open(id, settings, channel) => {
@@ -2181,6 +2519,61 @@ where
                     );
                 }
             }
+            window::Action::SetMinSize(id, size) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.raw.set_min_surface_size(size.map(|size| {
+                        winit::dpi::LogicalSize {
+                            width: size.width,
+                            height: size.height,
+                        }
+                        .into()
+                    }));
+                }
+            }
+            window::Action::SetMaxSize(id, size) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.raw.set_max_surface_size(size.map(|size| {
+                        winit::dpi::LogicalSize {
+                            width: size.width,
+                            height: size.height,
+                        }
+                        .into()
+                    }));
+                }
+            }
+            window::Action::SetResizeIncrements(id, increments) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.raw.set_surface_resize_increments(increments.map(
+                        |increments| {
+                            winit::dpi::LogicalSize {
+                                width: increments.width,
+                                height: increments.height,
+                            }
+                            .into()
+                        },
+                    ));
+                }
+            }
+            window::Action::SetResizable(id, resizable) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.raw.set_resizable(resizable);
+                }
+            }
+            window::Action::SetAspectRatio(id, aspect_ratio) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.set_aspect_ratio(aspect_ratio);
+                }
+            }
+            window::Action::SetContentProtected(id, protected) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.raw.set_content_protected(protected);
+                }
+            }
+            window::Action::SetTitle(id, title) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.state.set_title(title, window.raw.as_ref());
+                }
+            }
             window::Action::GetSize(id, channel) => {
                 if let Some(window) = window_manager.get_mut(id) {
                     let size = window
@@ -2227,6 +2620,11 @@ where
                     let _ = channel.send(position);
                 }
             }
+            window::Action::GetGeometry(id, channel) => {
+                if let Some(window) = window_manager.get(id) {
+                    let _ = channel.send(window.geometry());
+                }
+            }
             window::Action::GetScaleFactor(id, channel) => {
                 if let Some(window) = window_manager.get_mut(id) {
                     let scale_factor = window.raw.scale_factor();
@@ -2345,6 +2743,14 @@ where
                     ));
                 }
             }
+            window::Action::Stats(id, channel) => {
+                if let Some(window) = window_manager.get(id) {
+                    let _ = channel.send(window::Stats {
+                        renderer: compositor.stats(&window.renderer),
+                        ..window.stats.clone()
+                    });
+                }
+            }
             window::Action::EnableMousePassthrough(id) => {
                 if let Some(window) = window_manager.get_mut(id) {
                     let _ = window.raw.set_cursor_hittest(false);
@@ -2355,6 +2761,35 @@ where
                     let _ = window.raw.set_cursor_hittest(true);
                 }
             }
+            window::Action::SetCursorGrab(id, mut mode) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    let mut result = window
+                        .raw
+                        .set_cursor_grab(conversion::cursor_grab(mode));
+
+                    // Not every platform can lock the cursor in place
+                    if result.is_err() && mode == window::CursorGrab::Locked {
+                        mode = window::CursorGrab::Confined;
+                        result = window
+                            .raw
+                            .set_cursor_grab(conversion::cursor_grab(mode));
+                    }
+
+                    match result {
+                        Ok(()) => {
+                            window.cursor_grab = mode;
+                        }
+                        Err(error) => {
+                            log::warn!("Failed to grab cursor: {error}");
+                        }
+                    }
+                }
+            }
+            window::Action::SetCursorVisible(id, visible) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.raw.set_cursor_visible(visible);
+                }
+            }
             window::Action::EnableBlur(id) => {
                 if let Some(window) = window_manager.get_mut(id) {
                     window.raw.set_blur(true);
@@ -2365,6 +2800,16 @@ where
                     window.raw.set_blur(false);
                 }
             }
+            window::Action::GetMonitors(channel) => {
+                control_sender
+                    .start_send(Control::GetMonitors(channel))
+                    .expect("Send control action");
+            }
+            window::Action::GetCurrentMonitor(id, channel) => {
+                let _ = channel.send(
+                    window_manager.get(id).and_then(|window| window.monitor()),
+                );
+            }
         },
         Action::System(action) => match action {
             system::Action::QueryInformation(_channel) => {
@@ -2381,6 +2826,17 @@ where
                 }
             }
         },
+        Action::Dialog(action) => {
+            let parent = window_manager
+                .get(action.window())
+                .map(|window| crate::dialog::parent(&action, &window.raw))
+                .unwrap_or_default();
+
+            crate::dialog::run(action, parent);
+        }
+        Action::Notification(action) => {
+            crate::notification::run(action);
+        }
         Action::Widget(operation) => {
             let mut current_operation = Some(operation);
 
@@ -2405,6 +2861,47 @@ where
             compositor.load_font(bytes.clone());
 
             let _ = channel.send(Ok(()));
+
+            relayout_all(interfaces, window_manager);
+        }
+        Action::Font(action) => {
+            let mut font_system = graphics::text::font_system()
+                .write()
+                .expect("Write to font system");
+
+            let fonts_changed = match action {
+                runtime::font::Action::LoadFile { path, channel } => {
+                    let result = font_system
+                        .load_font_file(&path)
+                        .map_err(runtime::font::Error::from);
+                    let is_ok = result.is_ok();
+
+                    let _ = channel.send(result);
+
+                    is_ok
+                }
+                runtime::font::Action::ListFamilies(channel) => {
+                    let _ = channel.send(font_system.families());
+
+                    false
+                }
+                runtime::font::Action::HasFamily { name, channel } => {
+                    let _ = channel.send(font_system.has_family(&name));
+
+                    false
+                }
+                runtime::font::Action::SetFallback { script, families } => {
+                    font_system.set_fallback(script, families);
+
+                    true
+                }
+            };
+
+            drop(font_system);
+
+            if fonts_changed {
+                relayout_all(interfaces, window_manager);
+            }
         }
         Action::Exit => {
             control_sender
@@ -2453,6 +2950,7 @@ where
         .drain()
         .filter_map(|(id, cache)| {
             let window = window_manager.get_mut(id)?;
+            let layout_start = Instant::now();
             let interface = build_user_interface(
                 program,
                 cache,
@@ -2464,6 +2962,7 @@ where
                 window.prev_dnd_destination_rectangles_count,
                 clipboard,
             );
+            window.stats.layout = layout_start.elapsed();
 
             let dnd_rectangles = interface.dnd_rectangles(
                 window.prev_dnd_destination_rectangles_count,
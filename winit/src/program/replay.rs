@@ -0,0 +1,261 @@
+//! Record and replay the input of a program.
+use crate::core::mouse;
+use crate::core::time::Instant;
+use crate::core::window;
+use crate::core::Event;
+use crate::graphics::Compositor;
+use crate::program::window_manager::Window;
+use crate::program::{DefaultStyle, Program, State};
+use crate::runtime::replay::{self, Record, Recorder, Session};
+
+use std::collections::{HashMap, VecDeque};
+use std::path::PathBuf;
+use std::thread;
+
+/// Records and replays the input delivered to the windows of a program.
+///
+/// It is configured with the `ICED_RECORD` and `ICED_REPLAY` environment
+/// variables, which contain the path of the session file to write and to
+/// read, respectively. Both sessions start when the first window opens.
+///
+/// Windows are recorded by the order in which they were opened, since their
+/// [`window::Id`] depends on any other surfaces created before them.
+#[derive(Debug, Default)]
+pub(crate) struct Replay {
+    windows: Vec<window::Id>,
+    record: Option<PathBuf>,
+    recorder: Option<Recorder>,
+    session: Option<Session>,
+    player: Option<Player>,
+}
+
+#[derive(Debug)]
+struct Player {
+    origin: Instant,
+    records: VecDeque<Record>,
+    due: Vec<Record>,
+    cursors: HashMap<window::Id, mouse::Cursor>,
+    warned_scale_factor: bool,
+}
+
+impl Replay {
+    pub fn from_env() -> Self {
+        let record = std::env::var_os("ICED_RECORD")
+            .filter(|path| !path.is_empty())
+            .map(PathBuf::from);
+
+        let session = std::env::var_os("ICED_REPLAY")
+            .filter(|path| !path.is_empty())
+            .and_then(|path| match Session::open(&path) {
+                Ok(session) => Some(session),
+                Err(error) => {
+                    log::warn!("Could not open session {path:?}: {error:?}");
+
+                    None
+                }
+            });
+
+        Self {
+            record,
+            session,
+            ..Self::default()
+        }
+    }
+
+    /// Tracks the opening order of the given windows.
+    pub fn track(&mut self, ids: impl Iterator<Item = window::Id>) {
+        let mut opened: Vec<_> =
+            ids.filter(|id| !self.windows.contains(id)).collect();
+
+        // Identifiers are increasing, so they follow the opening order
+        opened.sort_unstable();
+
+        self.windows.extend(opened);
+    }
+
+    /// Returns the number of windows opened before the given one.
+    fn index(&self, id: window::Id) -> Option<usize> {
+        self.windows.iter().position(|window| *window == id)
+    }
+
+    /// Starts recording and replaying, if configured and not started yet.
+    ///
+    /// Calling it again after the sessions have started does nothing.
+    ///
+    /// The event loop is woken up whenever a replayed record is due.
+    pub fn start(&mut self, proxy: &winit::event_loop::EventLoopProxy) {
+        if let Some(path) = self.record.take() {
+            match Recorder::create(&path) {
+                Ok(recorder) => {
+                    log::info!("Recording session to {path:?}");
+
+                    self.recorder = Some(recorder);
+                }
+                Err(error) => {
+                    log::warn!("Could not create session {path:?}: {error}");
+                }
+            }
+        }
+
+        let Some(session) = self.session.take() else {
+            return;
+        };
+
+        let origin = Instant::now();
+        let times: Vec<_> =
+            session.records().iter().map(|record| record.time).collect();
+        let proxy = proxy.clone();
+
+        let _ = thread::spawn(move || {
+            for time in times {
+                thread::sleep(
+                    (origin + time).saturating_duration_since(Instant::now()),
+                );
+
+                let _ = proxy.wake_up();
+            }
+        });
+
+        log::info!("Replaying session of {:?}", session.duration());
+
+        self.player = Some(Player {
+            origin,
+            records: session.into_iter().collect(),
+            due: Vec::new(),
+            cursors: HashMap::new(),
+            warned_scale_factor: false,
+        });
+    }
+
+    /// Collects the replayed records that are due, returning true if any.
+    pub fn poll(&mut self) -> bool {
+        let Some(player) = &mut self.player else {
+            return false;
+        };
+
+        let elapsed = player.origin.elapsed();
+
+        while player
+            .records
+            .front()
+            .is_some_and(|record| record.time <= elapsed)
+        {
+            player.due.extend(player.records.pop_front());
+        }
+
+        !player.due.is_empty()
+    }
+
+    /// Replaces the live input of the given window with the replayed input
+    /// that is due, if replaying.
+    pub fn deliver<P, C>(
+        &mut self,
+        id: window::Id,
+        window: &mut Window<P, C>,
+        events: &mut Vec<Event>,
+    ) where
+        P: Program,
+        C: Compositor<Renderer = P::Renderer>,
+        P::Theme: DefaultStyle,
+    {
+        let index = self.index(id);
+
+        let Some(player) = &mut self.player else {
+            return;
+        };
+
+        // Live input would make the replay diverge
+        events.retain(|event| !replay::is_input(event));
+
+        let cursor = player
+            .cursors
+            .entry(id)
+            .or_insert(mouse::Cursor::Unavailable);
+
+        for record in player
+            .due
+            .iter()
+            .filter(|record| Some(record.window) == index)
+        {
+            if record.size != window.state.logical_size() {
+                let _ = window.raw.request_surface_size(
+                    winit::dpi::LogicalSize::new(
+                        record.size.width,
+                        record.size.height,
+                    )
+                    .into(),
+                );
+            }
+
+            if record.scale_factor != window.state.scale_factor()
+                && !player.warned_scale_factor
+            {
+                log::warn!(
+                    "Replaying session recorded with scale factor {} \
+                    in window {id} with scale factor {}",
+                    record.scale_factor,
+                    window.state.scale_factor()
+                );
+
+                player.warned_scale_factor = true;
+            }
+
+            *cursor = record.cursor(*cursor);
+            events.extend(record.events.iter().cloned());
+        }
+
+        player.due.retain(|record| Some(record.window) != index);
+        window.state.set_cursor(*cursor);
+    }
+
+    /// Discards the due records of windows that do not exist, and stops
+    /// replaying once the whole session has been delivered.
+    pub fn finish(&mut self) {
+        let Some(player) = &mut self.player else {
+            return;
+        };
+
+        for record in player.due.drain(..) {
+            log::warn!(
+                "Could not replay input of missing window #{}",
+                record.window
+            );
+        }
+
+        if player.records.is_empty() {
+            log::info!("Session replayed");
+
+            self.player = None;
+        }
+    }
+
+    /// Records the input delivered to a window, if recording.
+    pub fn record<P>(
+        &mut self,
+        id: window::Id,
+        state: &State<P>,
+        events: &[Event],
+    ) where
+        P: Program,
+        P::Theme: DefaultStyle,
+    {
+        let Some(index) = self.index(id) else {
+            return;
+        };
+
+        let Some(recorder) = &mut self.recorder else {
+            return;
+        };
+
+        if let Err(error) = recorder.record(
+            index,
+            state.logical_size(),
+            state.scale_factor(),
+            events,
+        ) {
+            log::warn!("Could not record session: {error}");
+
+            self.recorder = None;
+        }
+    }
+}
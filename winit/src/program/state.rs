@@ -15,6 +15,7 @@ where
     P::Theme: program::DefaultStyle,
 {
     pub(crate) title: String,
+    title_override: Option<String>,
     scale_factor: f64,
     viewport: Viewport,
     viewport_version: u64,
@@ -31,6 +32,7 @@ where
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("multi_window::State")
             .field("title", &self.title)
+            .field("title_override", &self.title_override)
             .field("scale_factor", &self.scale_factor)
             .field("viewport", &self.viewport)
             .field("viewport_version", &self.viewport_version)
@@ -66,6 +68,7 @@ where
 
         Self {
             title,
+            title_override: None,
             scale_factor,
             viewport,
             viewport_version: 0,
@@ -108,6 +111,14 @@ where
         self.cursor_position = Some(physical);
     }
 
+    /// Overrides the current cursor position of the [`State`].
+    pub fn set_cursor(&mut self, cursor: mouse::Cursor) {
+        self.cursor_position = cursor.position().map(|position| {
+            LogicalPosition::new(f64::from(position.x), f64::from(position.y))
+                .to_physical(self.scale_factor())
+        });
+    }
+
     /// Returns the current cursor position of the [`State`].
     pub fn cursor(&self) -> mouse::Cursor {
         self.cursor_position
@@ -206,11 +217,31 @@ where
                         ..
                     },
                 ..
-            } => _debug.toggle(),
+            } => {
+                if self.modifiers.shift_key() {
+                    _debug.toggle_outlines();
+                } else {
+                    _debug.toggle();
+                }
+            }
             _ => {}
         }
     }
 
+    /// Returns the title of the window; either set explicitly or the
+    /// title of the [`Program`].
+    pub fn title(&self) -> &str {
+        self.title_override.as_deref().unwrap_or(&self.title)
+    }
+
+    /// Overrides the title of the [`Program`] for the window, until `None`
+    /// is given.
+    pub fn set_title(&mut self, title: Option<String>, window: &dyn Window) {
+        self.title_override = title;
+
+        window.set_title(self.title());
+    }
+
     /// Synchronizes the [`State`] with its [`Program`] and its respective
     /// window.
     ///
@@ -226,7 +257,10 @@ where
         let new_title = application.title(window_id);
 
         if self.title != new_title {
-            window.set_title(&new_title);
+            if self.title_override.is_none() {
+                window.set_title(&new_title);
+            }
+
             self.title = new_title;
         }
 
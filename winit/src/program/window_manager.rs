@@ -1,8 +1,11 @@
+use crate::conversion;
 use crate::core::mouse;
-use crate::core::window::Id;
+use crate::core::time::{Duration, Instant};
+use crate::core::window::{CursorGrab, Geometry, Id, Monitor};
 use crate::core::{Point, Size};
 use crate::graphics::Compositor;
 use crate::program::{DefaultStyle, Program, State};
+use crate::runtime::window::Stats;
 
 use iced_futures::core::Element;
 use std::collections::BTreeMap;
@@ -20,6 +23,10 @@ where
     entries: BTreeMap<Id, Window<P, C>>,
 }
 
+/// The time a [`Window`] must go without being resized before its aspect
+/// ratio is enforced, so interactive resizes are not interrupted.
+const RESIZE_SETTLE_TIME: Duration = Duration::from_millis(200);
+
 impl<P, C> WindowManager<P, C>
 where
     P: Program,
@@ -54,6 +61,14 @@ where
 
         let _ = self.aliases.insert(window.id(), id);
 
+        let normal_size = window
+            .surface_size()
+            .to_logical::<f32>(window.scale_factor());
+        let normal_position = window
+            .outer_position()
+            .ok()
+            .map(|position| Point::new(position.x, position.y));
+
         let drag_resize_window_func = super::drag_resize::event_func(
             window.as_ref(),
             resize_border as f64 * window.scale_factor(),
@@ -73,6 +88,13 @@ where
                 prev_dnd_destination_rectangles_count: 0,
                 resize_enabled: false,
                 redraw_requested: false,
+                monitor: None,
+                cursor_grab: CursorGrab::None,
+                aspect_ratio: None,
+                aspect_ratio_deadline: None,
+                stats: Stats::default(),
+                normal_size: Size::new(normal_size.width, normal_size.height),
+                normal_position,
             },
         );
 
@@ -117,7 +139,7 @@ where
     }
 
     pub fn last_monitor(&self) -> Option<MonitorHandle> {
-        self.entries.values().last()?.raw.current_monitor()
+        self.entries.values().last()?.current_monitor()
     }
 
     pub fn remove(&mut self, id: Id) -> Option<Window<P, C>> {
@@ -174,6 +196,13 @@ where
     pub renderer: P::Renderer,
     pub resize_enabled: bool,
     pub(crate) redraw_requested: bool,
+    monitor: Option<Monitor>,
+    pub cursor_grab: CursorGrab,
+    aspect_ratio: Option<f32>,
+    aspect_ratio_deadline: Option<Instant>,
+    pub stats: Stats,
+    normal_size: Size,
+    normal_position: Option<Point<i32>>,
 }
 
 impl<P, C> Window<P, C>
@@ -199,6 +228,124 @@ where
         Size::new(size.width, size.height)
     }
 
+    /// Returns the handle of the monitor the [`Window`] is on, if the
+    /// platform reports it.
+    pub fn current_monitor(&self) -> Option<MonitorHandle> {
+        self.raw.current_monitor()
+    }
+
+    /// Returns the [`Monitor`] the [`Window`] is on, if known.
+    pub fn monitor(&self) -> Option<Monitor> {
+        self.monitor.clone().or_else(|| {
+            self.current_monitor().as_ref().map(conversion::monitor)
+        })
+    }
+
+    /// Queries the current [`Monitor`] of the [`Window`]; returning it if it
+    /// changed since the last time it was known.
+    pub fn update_monitor(&mut self) -> Option<Monitor> {
+        let monitor = self.current_monitor().as_ref().map(conversion::monitor);
+
+        self.set_monitor(Some(monitor?))
+    }
+
+    /// Sets the [`Monitor`] the [`Window`] is on, if known; returning it if
+    /// it changed.
+    pub fn set_monitor(&mut self, monitor: Option<Monitor>) -> Option<Monitor> {
+        if monitor == self.monitor {
+            return None;
+        }
+
+        self.monitor.clone_from(&monitor);
+
+        monitor
+    }
+
+    /// Remembers the current size and position of the [`Window`], unless it
+    /// is maximized or in fullscreen.
+    pub fn update_normal_geometry(&mut self) {
+        if self.raw.is_maximized() || self.raw.fullscreen().is_some() {
+            return;
+        }
+
+        self.normal_size = self.size();
+
+        if let Ok(position) = self.raw.outer_position() {
+            self.normal_position = Some(Point::new(position.x, position.y));
+        }
+    }
+
+    /// Returns a [`Geometry`] snapshot of the [`Window`].
+    ///
+    /// The size and position are the ones it had before being maximized or
+    /// made fullscreen, so it can be restored and un-maximized later.
+    pub fn geometry(&self) -> Geometry {
+        Geometry {
+            size: self.normal_size,
+            position: self.normal_position,
+            maximized: self.raw.is_maximized(),
+            monitor: self.monitor().and_then(|monitor| monitor.name),
+        }
+    }
+
+    /// Sets the aspect ratio the [`Window`] is kept at, resizing it if
+    /// necessary.
+    pub fn set_aspect_ratio(&mut self, aspect_ratio: Option<f32>) {
+        self.aspect_ratio = aspect_ratio;
+        self.aspect_ratio_deadline = None;
+        self.constrain_aspect_ratio();
+    }
+
+    /// Notes that the [`Window`] was resized, returning the time its aspect
+    /// ratio must be enforced at, if it has one.
+    ///
+    /// The aspect ratio is only enforced once the resizes settle, since
+    /// requesting a new size in the middle of an interactive resize fights
+    /// the compositor.
+    pub fn resized(&mut self) -> Option<Instant> {
+        let _ = self.aspect_ratio?;
+        let deadline = Instant::now() + RESIZE_SETTLE_TIME;
+
+        self.aspect_ratio_deadline = Some(deadline);
+
+        Some(deadline)
+    }
+
+    /// Enforces the aspect ratio of the [`Window`] if it has not been
+    /// resized since the deadline returned by [`Window::resized`].
+    pub fn settle_aspect_ratio(&mut self) {
+        if self
+            .aspect_ratio_deadline
+            .is_some_and(|deadline| deadline <= Instant::now())
+        {
+            self.aspect_ratio_deadline = None;
+            self.constrain_aspect_ratio();
+        }
+    }
+
+    /// Requests a new height for the [`Window`] if its size does not match
+    /// its aspect ratio.
+    fn constrain_aspect_ratio(&self) {
+        let Some(aspect_ratio) = self.aspect_ratio else {
+            return;
+        };
+
+        let size = self.raw.surface_size();
+
+        if size.width == 0 || size.height == 0 || aspect_ratio <= 0.0 {
+            return;
+        }
+
+        let height = (size.width as f32 / aspect_ratio).round() as u32;
+
+        // Allow an error of a pixel to avoid resize loops due to rounding
+        if height.abs_diff(size.height) > 1 {
+            let _ = self.raw.request_surface_size(
+                winit::dpi::PhysicalSize::new(size.width, height).into(),
+            );
+        }
+    }
+
     pub fn request_redraw(&mut self) {
         if !self.redraw_requested {
             self.redraw_requested = true;
@@ -1,4 +1,6 @@
 //! Configure your application.
+use crate::runtime::Trace;
+
 use std::borrow::Cow;
 
 /// The settings of an application.
@@ -15,4 +17,22 @@ pub struct Settings {
 
     /// Whether the application should exit when no windows are left
     pub is_daemon: bool,
+
+    /// Whether only a single instance of the application may run at a time.
+    ///
+    /// If enabled, launching the application while it is already running
+    /// forwards the command-line arguments to the running instance, which
+    /// can receive them with [`instance::listen`], and exits.
+    ///
+    /// It requires an [`id`](Self::id).
+    ///
+    /// [`instance::listen`]: crate::instance::listen
+    pub single_instance: bool,
+
+    /// Where to record the traces of the application, if anywhere.
+    ///
+    /// The `ICED_TRACE` environment variable takes precedence over this
+    /// setting. Traces are only recorded when the `debug` feature is
+    /// enabled.
+    pub trace: Option<Trace>,
 }
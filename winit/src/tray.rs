@@ -0,0 +1,30 @@
+//! Show an icon in the system tray.
+#[cfg(all(feature = "tray", target_os = "linux"))]
+mod status_notifier;
+
+use crate::futures::Subscription;
+use crate::runtime::tray::{Event, Tray};
+
+/// Shows the given [`Tray`] while the [`Subscription`] is active, producing
+/// its [`Event`]s.
+///
+/// The icon is exposed through the StatusNotifierItem D-Bus specification.
+/// The [`Subscription`] is identified by the `id` of the [`Tray`]; any other
+/// change is applied to the icon that is already shown.
+pub fn listen(tray: Tray) -> Subscription<Event> {
+    #[cfg(all(feature = "tray", target_os = "linux"))]
+    {
+        let id = tray.id.clone();
+
+        status_notifier::update(tray);
+
+        Subscription::run_with_id(id.clone(), status_notifier::run(id))
+    }
+
+    #[cfg(not(all(feature = "tray", target_os = "linux")))]
+    {
+        let _ = tray;
+
+        Subscription::none()
+    }
+}
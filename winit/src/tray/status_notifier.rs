@@ -0,0 +1,668 @@
+use crate::futures::futures::channel::mpsc;
+use crate::futures::futures::{Stream, StreamExt};
+use crate::runtime::tray::{Event, Item, Tray};
+
+use zbus::object_server::SignalContext;
+use zbus::zvariant::{ObjectPath, OwnedValue, Value};
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{self, AtomicU64};
+use std::sync::Mutex;
+
+const ITEM_PATH: &str = "/StatusNotifierItem";
+const MENU_PATH: &str = "/MenuBar";
+
+#[zbus::proxy(
+    interface = "org.kde.StatusNotifierWatcher",
+    default_service = "org.kde.StatusNotifierWatcher",
+    default_path = "/StatusNotifierWatcher"
+)]
+trait Watcher {
+    fn register_status_notifier_item(&self, service: &str) -> zbus::Result<()>;
+}
+
+/// The latest [`Tray`] of every identifier, and the channel of the icon
+/// that currently shows it.
+static TRAYS: Mutex<BTreeMap<String, Shown>> = Mutex::new(BTreeMap::new());
+
+struct Shown {
+    tray: Tray,
+    updates: Option<mpsc::UnboundedSender<Tray>>,
+}
+
+/// Stores the given [`Tray`] as the latest one with its identifier, and
+/// pushes it to its icon if it changed.
+pub(super) fn update(tray: Tray) {
+    let mut trays = TRAYS.lock().expect("Lock trays");
+
+    match trays.get_mut(&tray.id) {
+        Some(shown) if shown.tray == tray => {}
+        Some(shown) => {
+            if let Some(updates) = &shown.updates {
+                let _ = updates.unbounded_send(tray.clone());
+            }
+
+            shown.tray = tray;
+        }
+        None => {
+            let _ = trays.insert(
+                tray.id.clone(),
+                Shown {
+                    tray,
+                    updates: None,
+                },
+            );
+        }
+    }
+}
+
+/// Serves the latest [`Tray`] with the given identifier in the session bus
+/// until the resulting [`Stream`] is dropped.
+pub(super) fn run(id: String) -> impl Stream<Item = Event> {
+    crate::futures::stream::channel(100, |output| async move {
+        let (sender, updates) = mpsc::unbounded();
+
+        let tray = {
+            let mut trays = TRAYS.lock().expect("Lock trays");
+
+            let Some(shown) = trays.get_mut(&id) else {
+                return;
+            };
+
+            shown.updates = Some(sender);
+            shown.tray.clone()
+        };
+
+        if let Err(error) = serve(tray, output, updates).await {
+            log::warn!("Failed to show tray icon: {error}");
+        }
+    })
+}
+
+async fn serve(
+    tray: Tray,
+    output: mpsc::Sender<Event>,
+    mut updates: mpsc::UnboundedReceiver<Tray>,
+) -> zbus::Result<()> {
+    static NEXT_INSTANCE: AtomicU64 = AtomicU64::new(0);
+
+    let name = format!(
+        "org.kde.StatusNotifierItem-{}-{}",
+        std::process::id(),
+        NEXT_INSTANCE.fetch_add(1, atomic::Ordering::Relaxed)
+    );
+
+    let connection = zbus::connection::Builder::session()?
+        .name(name.as_str())?
+        .build()
+        .await?;
+
+    export(&connection, tray, output).await?;
+
+    WatcherProxy::new(&connection)
+        .await?
+        .register_status_notifier_item(&name)
+        .await?;
+
+    while let Some(tray) = updates.next().await {
+        apply(&connection, tray).await?;
+    }
+
+    // The icon is removed once the connection is dropped
+    std::future::pending::<()>().await;
+
+    Ok(())
+}
+
+/// Exports the icon and the menu of the [`Tray`] in the given connection.
+async fn export(
+    connection: &zbus::Connection,
+    tray: Tray,
+    output: mpsc::Sender<Event>,
+) -> zbus::Result<()> {
+    let object_server = connection.object_server();
+
+    let menu = Menu::new(&tray.menu, output.clone());
+
+    let _ = object_server
+        .at(ITEM_PATH, StatusNotifierItem { tray, output })
+        .await?;
+    let _ = object_server.at(MENU_PATH, menu).await?;
+
+    Ok(())
+}
+
+/// Replaces the exported [`Tray`], notifying the host of what changed.
+async fn apply(connection: &zbus::Connection, tray: Tray) -> zbus::Result<()> {
+    let object_server = connection.object_server();
+
+    let item = object_server
+        .interface::<_, StatusNotifierItem>(ITEM_PATH)
+        .await?;
+    let menu = object_server.interface::<_, Menu>(MENU_PATH).await?;
+
+    let old = std::mem::replace(&mut item.get_mut().await.tray, tray.clone());
+    let context = item.signal_context();
+
+    if old.title != tray.title {
+        StatusNotifierItem::new_title(context).await?;
+    }
+
+    if old.icon != tray.icon {
+        StatusNotifierItem::new_icon(context).await?;
+    }
+
+    if old.tooltip != tray.tooltip {
+        StatusNotifierItem::new_tool_tip(context).await?;
+    }
+
+    if old.menu != tray.menu {
+        let revision = {
+            let mut menu = menu.get_mut().await;
+            menu.set_items(&tray.menu);
+            menu.revision
+        };
+
+        Menu::layout_updated(menu.signal_context(), revision, 0).await?;
+    }
+
+    Ok(())
+}
+
+struct StatusNotifierItem {
+    tray: Tray,
+    output: mpsc::Sender<Event>,
+}
+
+/// An ARGB32 image in network byte order.
+type Pixmap = (i32, i32, Vec<u8>);
+
+impl StatusNotifierItem {
+    fn pixmaps(&self) -> Vec<Pixmap> {
+        let Some(icon) = self.tray.icon.clone() else {
+            return Vec::new();
+        };
+
+        let (rgba, size) = icon.into_raw();
+
+        let argb = rgba
+            .chunks_exact(4)
+            .flat_map(|pixel| [pixel[3], pixel[0], pixel[1], pixel[2]])
+            .collect();
+
+        vec![(size.width as i32, size.height as i32, argb)]
+    }
+}
+
+#[zbus::interface(name = "org.kde.StatusNotifierItem")]
+impl StatusNotifierItem {
+    #[zbus(property)]
+    fn category(&self) -> &str {
+        "ApplicationStatus"
+    }
+
+    #[zbus(property)]
+    fn id(&self) -> &str {
+        &self.tray.id
+    }
+
+    #[zbus(property)]
+    fn title(&self) -> &str {
+        &self.tray.title
+    }
+
+    #[zbus(property)]
+    fn status(&self) -> &str {
+        "Active"
+    }
+
+    #[zbus(property)]
+    fn icon_name(&self) -> &str {
+        ""
+    }
+
+    #[zbus(property)]
+    fn icon_pixmap(&self) -> Vec<Pixmap> {
+        self.pixmaps()
+    }
+
+    #[zbus(property)]
+    fn tool_tip(&self) -> (String, Vec<Pixmap>, String, String) {
+        (
+            String::new(),
+            Vec::new(),
+            self.tray.tooltip.clone(),
+            String::new(),
+        )
+    }
+
+    #[zbus(property)]
+    fn item_is_menu(&self) -> bool {
+        false
+    }
+
+    #[zbus(property)]
+    fn menu(&self) -> ObjectPath<'_> {
+        ObjectPath::from_static_str_unchecked(MENU_PATH)
+    }
+
+    fn activate(&mut self, _x: i32, _y: i32) {
+        let _ = self.output.try_send(Event::Activated);
+    }
+
+    fn secondary_activate(&mut self, _x: i32, _y: i32) {
+        let _ = self.output.try_send(Event::SecondaryActivated);
+    }
+
+    fn context_menu(&self, _x: i32, _y: i32) {
+        // The host shows the menu exported at `MENU_PATH`
+    }
+
+    fn scroll(&self, _delta: i32, _orientation: &str) {}
+
+    #[zbus(signal)]
+    async fn new_title(context: &SignalContext<'_>) -> zbus::Result<()>;
+
+    #[zbus(signal)]
+    async fn new_icon(context: &SignalContext<'_>) -> zbus::Result<()>;
+
+    #[zbus(signal)]
+    async fn new_tool_tip(context: &SignalContext<'_>) -> zbus::Result<()>;
+}
+
+/// A menu exported through the `com.canonical.dbusmenu` interface.
+struct Menu {
+    root: Node,
+    revision: u32,
+    output: mpsc::Sender<Event>,
+}
+
+/// A menu item and its children, identified by its depth-first order.
+struct Node {
+    id: i32,
+    item: Option<Item>,
+    children: Vec<Node>,
+}
+
+type Properties = HashMap<&'static str, Value<'static>>;
+type Layout = (i32, Properties, Vec<Value<'static>>);
+
+impl Menu {
+    fn new(items: &[Item], output: mpsc::Sender<Event>) -> Self {
+        Self {
+            root: Node::root(items),
+            revision: 0,
+            output,
+        }
+    }
+
+    fn set_items(&mut self, items: &[Item]) {
+        self.root = Node::root(items);
+        self.revision += 1;
+    }
+
+    fn invoke(&mut self, id: i32, event: &str) {
+        if event != "clicked" {
+            return;
+        }
+
+        let key = match self.root.find(id).and_then(|node| node.item.as_ref()) {
+            Some(Item::Button { key, .. } | Item::Toggle { key, .. }) => {
+                key.clone()
+            }
+            _ => return,
+        };
+
+        let _ = self.output.try_send(Event::MenuItemInvoked(key));
+    }
+}
+
+impl Node {
+    fn root(items: &[Item]) -> Self {
+        fn nodes(items: &[Item], next_id: &mut i32) -> Vec<Node> {
+            items
+                .iter()
+                .map(|item| {
+                    *next_id += 1;
+
+                    let id = *next_id;
+                    let children = match item {
+                        Item::Submenu { items, .. } => nodes(items, next_id),
+                        _ => Vec::new(),
+                    };
+
+                    Node {
+                        id,
+                        item: Some(item.clone()),
+                        children,
+                    }
+                })
+                .collect()
+        }
+
+        Self {
+            id: 0,
+            item: None,
+            children: nodes(items, &mut 0),
+        }
+    }
+
+    fn find(&self, id: i32) -> Option<&Node> {
+        if self.id == id {
+            return Some(self);
+        }
+
+        self.children.iter().find_map(|child| child.find(id))
+    }
+
+    fn nodes(&self) -> Box<dyn Iterator<Item = &Node> + '_> {
+        Box::new(
+            std::iter::once(self)
+                .chain(self.children.iter().flat_map(Node::nodes)),
+        )
+    }
+
+    fn layout(&self, depth: i32) -> Layout {
+        let children = if depth == 0 {
+            Vec::new()
+        } else {
+            self.children
+                .iter()
+                .map(|child| Value::from(child.layout(depth - 1)))
+                .collect()
+        };
+
+        (self.id, self.properties(), children)
+    }
+
+    fn properties(&self) -> Properties {
+        let mut properties = Properties::new();
+
+        let mut insert = |name, value: Value<'static>| {
+            let _ = properties.insert(name, value);
+        };
+
+        match &self.item {
+            None => {
+                insert("children-display", Value::from("submenu"));
+            }
+            Some(Item::Button { label, enabled, .. }) => {
+                insert("label", Value::from(label.clone()));
+                insert("enabled", Value::from(*enabled));
+            }
+            Some(Item::Toggle { label, checked, .. }) => {
+                insert("label", Value::from(label.clone()));
+                insert("toggle-type", Value::from("checkmark"));
+                insert("toggle-state", Value::from(i32::from(*checked)));
+            }
+            Some(Item::Separator) => {
+                insert("type", Value::from("separator"));
+            }
+            Some(Item::Submenu { label, .. }) => {
+                insert("label", Value::from(label.clone()));
+                insert("children-display", Value::from("submenu"));
+            }
+        }
+
+        properties
+    }
+}
+
+#[zbus::interface(name = "com.canonical.dbusmenu")]
+impl Menu {
+    #[zbus(property)]
+    fn version(&self) -> u32 {
+        3
+    }
+
+    #[zbus(property)]
+    fn text_direction(&self) -> &str {
+        "ltr"
+    }
+
+    #[zbus(property)]
+    fn status(&self) -> &str {
+        "normal"
+    }
+
+    #[zbus(property)]
+    fn icon_theme_path(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    fn get_layout(
+        &self,
+        parent_id: i32,
+        recursion_depth: i32,
+        _property_names: Vec<String>,
+    ) -> zbus::fdo::Result<(u32, Layout)> {
+        let node = self.root.find(parent_id).ok_or_else(|| {
+            zbus::fdo::Error::InvalidArgs(format!("Unknown item {parent_id}"))
+        })?;
+
+        Ok((self.revision, node.layout(recursion_depth)))
+    }
+
+    fn get_group_properties(
+        &self,
+        ids: Vec<i32>,
+        _property_names: Vec<String>,
+    ) -> Vec<(i32, Properties)> {
+        self.root
+            .nodes()
+            .filter(|node| ids.is_empty() || ids.contains(&node.id))
+            .map(|node| (node.id, node.properties()))
+            .collect()
+    }
+
+    fn get_property(
+        &self,
+        id: i32,
+        name: &str,
+    ) -> zbus::fdo::Result<Value<'static>> {
+        self.root
+            .find(id)
+            .and_then(|node| node.properties().remove(name))
+            .ok_or_else(|| {
+                zbus::fdo::Error::InvalidArgs(format!(
+                    "Unknown property {name} of item {id}"
+                ))
+            })
+    }
+
+    fn event(
+        &mut self,
+        id: i32,
+        event_id: &str,
+        _data: OwnedValue,
+        _timestamp: u32,
+    ) {
+        self.invoke(id, event_id);
+    }
+
+    fn event_group(
+        &mut self,
+        events: Vec<(i32, String, OwnedValue, u32)>,
+    ) -> Vec<i32> {
+        for (id, event_id, _data, _timestamp) in events {
+            self.invoke(id, &event_id);
+        }
+
+        Vec::new()
+    }
+
+    fn about_to_show(&self, _id: i32) -> bool {
+        false
+    }
+
+    fn about_to_show_group(&self, _ids: Vec<i32>) -> (Vec<i32>, Vec<i32>) {
+        (Vec::new(), Vec::new())
+    }
+
+    #[zbus(signal)]
+    async fn layout_updated(
+        context: &SignalContext<'_>,
+        revision: u32,
+        parent: i32,
+    ) -> zbus::Result<()>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::futures::futures::executor::block_on;
+    use crate::futures::futures::join;
+
+    use zbus::connection;
+    use zbus::message;
+
+    use std::os::unix::net::UnixStream;
+
+    async fn connect() -> (zbus::Connection, zbus::Connection) {
+        let (server, client) = UnixStream::pair().expect("Create socket pair");
+
+        let server = async {
+            connection::Builder::unix_stream(server)
+                .server(zbus::Guid::generate())
+                .expect("Create server")
+                .p2p()
+                .build()
+                .await
+                .expect("Connect server")
+        };
+
+        let client = async {
+            connection::Builder::unix_stream(client)
+                .p2p()
+                .build()
+                .await
+                .expect("Connect client")
+        };
+
+        join!(server, client)
+    }
+
+    async fn signals(
+        stream: &mut zbus::MessageStream,
+        count: usize,
+    ) -> Vec<String> {
+        let mut signals = Vec::new();
+
+        while signals.len() < count {
+            let message = stream
+                .next()
+                .await
+                .expect("Receive message")
+                .expect("Valid message");
+
+            if message.message_type() == message::Type::Signal {
+                signals
+                    .extend(message.header().member().map(ToString::to_string));
+            }
+        }
+
+        signals
+    }
+
+    type Reply = (u32, (i32, HashMap<String, OwnedValue>, Vec<OwnedValue>));
+
+    async fn layout(client: &zbus::Connection) -> Reply {
+        client
+            .call_method(
+                None::<&str>,
+                MENU_PATH,
+                Some("com.canonical.dbusmenu"),
+                "GetLayout",
+                &(0, -1, Vec::<String>::new()),
+            )
+            .await
+            .expect("Get layout")
+            .body()
+            .deserialize()
+            .expect("Valid layout")
+    }
+
+    #[test]
+    fn updates_are_applied_to_the_exported_tray() {
+        block_on(async {
+            let (server, client) = connect().await;
+            let (output, mut events) = mpsc::channel(10);
+
+            let tray = Tray::new("tray")
+                .tooltip("Idle")
+                .item(Item::button("quit", "Quit"));
+
+            export(&server, tray.clone(), output)
+                .await
+                .expect("Export tray");
+
+            let (revision, (_id, _properties, children)) =
+                layout(&client).await;
+
+            assert_eq!(revision, 0);
+            assert_eq!(children.len(), 1);
+
+            let mut stream = zbus::MessageStream::from(&client);
+
+            apply(
+                &server,
+                tray.tooltip("Busy")
+                    .item(Item::toggle("mute", "Mute", true)),
+            )
+            .await
+            .expect("Apply update");
+
+            assert_eq!(
+                signals(&mut stream, 2).await,
+                ["NewToolTip", "LayoutUpdated"]
+            );
+
+            let (revision, (_id, _properties, children)) =
+                layout(&client).await;
+
+            assert_eq!(revision, 1);
+            assert_eq!(children.len(), 2);
+
+            let _ = client
+                .call_method(
+                    None::<&str>,
+                    MENU_PATH,
+                    Some("com.canonical.dbusmenu"),
+                    "Event",
+                    &(2, "clicked", Value::from(0), 0_u32),
+                )
+                .await
+                .expect("Invoke item");
+
+            assert_eq!(
+                events.next().await,
+                Some(Event::MenuItemInvoked(String::from("mute")))
+            );
+        });
+    }
+
+    #[test]
+    fn unchanged_trays_are_not_pushed() {
+        let (sender, mut updates) = mpsc::unbounded();
+        let tray = Tray::new("unchanged").title("Title");
+
+        update(tray.clone());
+
+        TRAYS
+            .lock()
+            .expect("Lock trays")
+            .get_mut("unchanged")
+            .expect("Stored tray")
+            .updates = Some(sender);
+
+        update(tray.clone());
+        update(tray.clone().title("Other"));
+
+        assert_eq!(
+            updates.try_next().expect("Pushed tray"),
+            Some(tray.title("Other"))
+        );
+        assert!(updates.try_next().is_err());
+    }
+}